@@ -0,0 +1,358 @@
+use crate::constants::ButtonStatus;
+use crate::packets::event::EventDetails;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const DEFAULT_HOLD_TIME: f32 = 0.5;
+const DEFAULT_DOUBLE_PRESS_WINDOW: f32 = 0.3;
+
+/// Gesture a [`ButtonBinding`] reacts to.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ButtonTrigger {
+    /// The button gets pressed.
+    Press,
+    /// The button gets released.
+    Release,
+    /// The button has been held down for the given number of seconds.
+    Hold(f32),
+    /// The button gets pressed twice within the double press window.
+    DoublePress,
+    /// All of the bound buttons are down at the same time.
+    Chord,
+}
+
+/// Entry of a [`ButtonTracker`]'s binding table.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ButtonBinding<A> {
+    /// Button to react to, or a combination of buttons for [`ButtonTrigger::Chord`].
+    pub buttons: ButtonStatus,
+    /// Gesture to react to.
+    pub trigger: ButtonTrigger,
+    /// Action attached to matching events.
+    pub action: A,
+}
+
+impl<A> ButtonBinding<A> {
+    /// Creates a binding.
+    pub fn new(buttons: ButtonStatus, trigger: ButtonTrigger, action: A) -> Self {
+        Self { buttons, trigger, action }
+    }
+
+    fn matches(&self, buttons: ButtonStatus, kind: ButtonEventKind) -> bool {
+        self.buttons == buttons
+            && match (self.trigger, kind) {
+                (ButtonTrigger::Press, ButtonEventKind::Pressed) => true,
+                (ButtonTrigger::Release, ButtonEventKind::Released { .. }) => true,
+                (ButtonTrigger::Hold(hold_time), ButtonEventKind::Held { duration }) => {
+                    hold_time == duration
+                }
+                (ButtonTrigger::DoublePress, ButtonEventKind::DoublePressed) => true,
+                (ButtonTrigger::Chord, ButtonEventKind::Chord) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Gesture recognised by a [`ButtonTracker`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ButtonEventKind {
+    /// The button has been pressed.
+    Pressed,
+    /// The button has been released.
+    Released {
+        /// Number of seconds the button has been held down for.
+        held_for: f32,
+    },
+    /// The button has been held down for a hold threshold.
+    Held {
+        /// Crossed hold threshold in seconds.
+        duration: f32,
+    },
+    /// The button has been pressed for the second time within the double press window.
+    /// Follows a [`Pressed`](Self::Pressed) event of the same button.
+    DoublePressed,
+    /// All buttons of a bound chord have been pressed.
+    Chord,
+}
+
+/// Event emitted by [`ButtonTracker::update`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ButtonEvent<A = ()> {
+    /// Session timestamp of the packet the event was detected in.
+    pub session_time: f32,
+    /// Button the event refers to, or all buttons of a chord.
+    pub buttons: ButtonStatus,
+    /// Recognised gesture.
+    pub kind: ButtonEventKind,
+    /// Actions of all bindings matching this event.
+    pub actions: Vec<A>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+struct PressState {
+    pressed_at: f32,
+    held_for: f32,
+}
+
+/// Diffs successive [`ButtonStatus`] values from
+/// [`Buttons` events](variant@EventDetails::Buttons) to recognise
+/// presses, releases, holds, double presses and chords,
+/// and maps them to actions through a table of [`ButtonBinding`]s.
+///
+/// Buttons events only get sent when the status changes, so holds are
+/// detected using the session time of every packet passed to [`update`](Self::update).
+///
+/// ## Example
+///
+/// ```
+/// use f1_game_packet_parser::buttons::{ButtonBinding, ButtonTracker, ButtonTrigger};
+/// use f1_game_packet_parser::constants::ButtonStatus;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Macro {
+///     MarkLap,
+///     ToggleRecording,
+///     CycleOverlay,
+/// }
+///
+/// let tracker = ButtonTracker::new().with_bindings([
+///     ButtonBinding::new(ButtonStatus::UDP_ACTION_1, ButtonTrigger::Press, Macro::MarkLap),
+///     ButtonBinding::new(
+///         ButtonStatus::UDP_ACTION_2,
+///         ButtonTrigger::Hold(1.0),
+///         Macro::ToggleRecording,
+///     ),
+///     ButtonBinding::new(
+///         ButtonStatus::UDP_ACTION_1 | ButtonStatus::UDP_ACTION_2,
+///         ButtonTrigger::Chord,
+///         Macro::CycleOverlay,
+///     ),
+/// ]);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct ButtonTracker<A = ()> {
+    hold_time: f32,
+    double_press_window: f32,
+    bindings: Vec<ButtonBinding<A>>,
+    status: ButtonStatus,
+    pressed: BTreeMap<u32, PressState>,
+    last_presses: BTreeMap<u32, f32>,
+    last_session_time: Option<f32>,
+}
+
+impl<A> Default for ButtonTracker<A> {
+    fn default() -> Self {
+        Self {
+            hold_time: DEFAULT_HOLD_TIME,
+            double_press_window: DEFAULT_DOUBLE_PRESS_WINDOW,
+            bindings: Vec::new(),
+            status: ButtonStatus::empty(),
+            pressed: BTreeMap::new(),
+            last_presses: BTreeMap::new(),
+            last_session_time: None,
+        }
+    }
+}
+
+impl<A: Clone> ButtonTracker<A> {
+    /// Creates a tracker with no bindings, a 0.5 s hold threshold
+    /// and a 0.3 s double press window.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the hold threshold in seconds that every button emits
+    /// a [`ButtonEventKind::Held`] event at.
+    /// Bindings with [`ButtonTrigger::Hold`] add their own thresholds.
+    pub fn with_hold_time(mut self, seconds: f32) -> Self {
+        self.hold_time = seconds;
+        self
+    }
+
+    /// Sets the maximum number of seconds between two presses of a double press.
+    pub fn with_double_press_window(mut self, seconds: f32) -> Self {
+        self.double_press_window = seconds;
+        self
+    }
+
+    /// Adds a binding to the table.
+    pub fn with_binding(
+        mut self,
+        buttons: ButtonStatus,
+        trigger: ButtonTrigger,
+        action: A,
+    ) -> Self {
+        self.bindings.push(ButtonBinding::new(buttons, trigger, action));
+        self
+    }
+
+    /// Adds multiple bindings to the table.
+    pub fn with_bindings(
+        mut self,
+        bindings: impl IntoIterator<Item = ButtonBinding<A>>,
+    ) -> Self {
+        self.bindings.extend(bindings);
+        self
+    }
+
+    /// Returns the binding table.
+    pub fn bindings(&self) -> &[ButtonBinding<A>] {
+        &self.bindings
+    }
+
+    /// Returns the most recently reported button status.
+    pub fn status(&self) -> ButtonStatus {
+        self.status
+    }
+
+    /// Processes a packet and returns recognised button events.
+    /// Packets of all types should be passed in, so that holds get detected in time.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<ButtonEvent<A>> {
+        let session_time = packet.header.session_time;
+        let mut events = Vec::new();
+
+        // Flashbacks move the session time backwards.
+        if self.last_session_time.is_some_and(|last| session_time < last) {
+            for state in self.pressed.values_mut() {
+                state.pressed_at = session_time;
+                state.held_for = 0.0;
+            }
+
+            self.last_presses.clear();
+        }
+
+        self.last_session_time = Some(session_time);
+        self.detect_holds(session_time, &mut events);
+
+        let Some(EventDetails::Buttons { button_status }) =
+            packet.event.as_ref().map(|event| event.details)
+        else {
+            return events;
+        };
+        let previous = self.status;
+
+        for bit in 0..u32::BITS {
+            let flag = 1 << bit;
+            let button = ButtonStatus::from_bits_retain(flag);
+
+            match (previous.contains(button), button_status.contains(button)) {
+                (false, true) => {
+                    events.push(self.event(
+                        session_time,
+                        button,
+                        ButtonEventKind::Pressed,
+                    ));
+                    self.pressed.insert(
+                        flag,
+                        PressState { pressed_at: session_time, held_for: 0.0 },
+                    );
+
+                    match self.last_presses.remove(&flag) {
+                        Some(last) if session_time - last <= self.double_press_window => {
+                            events.push(self.event(
+                                session_time,
+                                button,
+                                ButtonEventKind::DoublePressed,
+                            ));
+                        }
+                        _ => {
+                            self.last_presses.insert(flag, session_time);
+                        }
+                    }
+                }
+                (true, false) => {
+                    let held_for = self
+                        .pressed
+                        .remove(&flag)
+                        .map_or(0.0, |state| session_time - state.pressed_at);
+
+                    events.push(self.event(
+                        session_time,
+                        button,
+                        ButtonEventKind::Released { held_for },
+                    ));
+                }
+                _ => (),
+            }
+        }
+
+        let mut chords: Vec<ButtonStatus> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.trigger == ButtonTrigger::Chord)
+            .map(|binding| binding.buttons)
+            .filter(|&chord| button_status.contains(chord) && !previous.contains(chord))
+            .collect();
+
+        chords.sort();
+        chords.dedup();
+
+        for chord in chords {
+            events.push(self.event(session_time, chord, ButtonEventKind::Chord));
+        }
+
+        self.status = button_status;
+        events
+    }
+
+    fn detect_holds(&mut self, session_time: f32, events: &mut Vec<ButtonEvent<A>>) {
+        let mut held = Vec::new();
+
+        for (&flag, state) in &mut self.pressed {
+            let button = ButtonStatus::from_bits_retain(flag);
+            let held_for = session_time - state.pressed_at;
+            let mut thresholds: Vec<f32> = self
+                .bindings
+                .iter()
+                .filter_map(|binding| match binding.trigger {
+                    ButtonTrigger::Hold(hold_time) if binding.buttons == button => {
+                        Some(hold_time)
+                    }
+                    _ => None,
+                })
+                .chain([self.hold_time])
+                .filter(|&threshold| state.held_for < threshold && threshold <= held_for)
+                .collect();
+
+            thresholds.sort_by(f32::total_cmp);
+            thresholds.dedup();
+            held.extend(thresholds.into_iter().map(|duration| (button, duration)));
+            state.held_for = held_for;
+        }
+
+        for (button, duration) in held {
+            events.push(self.event(
+                session_time,
+                button,
+                ButtonEventKind::Held { duration },
+            ));
+        }
+    }
+
+    fn event(
+        &self,
+        session_time: f32,
+        buttons: ButtonStatus,
+        kind: ButtonEventKind,
+    ) -> ButtonEvent<A> {
+        let actions = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.matches(buttons, kind))
+            .map(|binding| binding.action.clone())
+            .collect();
+
+        ButtonEvent { session_time, buttons, kind, actions }
+    }
+}
@@ -0,0 +1,602 @@
+use crate::constants::PacketId;
+use crate::{parse, F1Packet, F1PacketHeader};
+
+use binrw::io::Cursor;
+use binrw::{BinRead, BinReaderExt};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
+
+const MAGIC: &[u8; 4] = b"F1DC";
+const FORMAT_VERSION: u8 = 1;
+const KEYFRAME: u8 = 0;
+const DELTA: u8 = 1;
+
+/// Size of the largest packet the supported formats have (2022 motion packet).
+pub const MAX_PACKET_LEN: usize = 1464;
+/// Upper bound of an encoded frame's size. Runs of zeros can't make a packet
+/// more than 1.5 times bigger, and the frame header takes 4 bytes.
+const MAX_FRAME_LEN: usize = 2 * MAX_PACKET_LEN;
+
+/// Error returned when encoding or decoding packets fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum CodecError {
+    /// The raw packet's header couldn't be read,
+    /// or the decoded bytes couldn't be parsed into an [`F1Packet`].
+    InvalidPacket(binrw::Error),
+    /// The stream doesn't start with the expected magic bytes and format version.
+    InvalidMagic,
+    /// A frame, or the magic bytes at the start of a stream,
+    /// end before all of their bytes have been read.
+    Truncated,
+    /// A frame has an unknown kind byte.
+    UnknownFrameKind(u8),
+    /// A delta frame refers to a packet type that hasn't been decoded yet.
+    MissingReference(u8),
+    /// A packet or frame length exceeds the largest one a valid packet can have.
+    TooLarge {
+        /// The offending length.
+        len: usize,
+        /// Largest allowed length.
+        max: usize,
+    },
+    /// Decoded packet's length differs from the one stored in its frame.
+    LengthMismatch {
+        /// Length stored in the frame.
+        expected: usize,
+        /// Length of the decoded bytes.
+        actual: usize,
+    },
+    /// Reading or writing the underlying stream has failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::InvalidPacket(error) => write!(f, "Invalid packet: {}", error),
+            CodecError::InvalidMagic => {
+                f.write_str("Invalid magic bytes or format version")
+            }
+            CodecError::Truncated => f.write_str("Frame is truncated"),
+            CodecError::UnknownFrameKind(kind) => {
+                write!(f, "Unknown frame kind: {}", kind)
+            }
+            CodecError::MissingReference(packet_id) => {
+                write!(f, "Delta frame without a reference packet of ID {}", packet_id)
+            }
+            CodecError::TooLarge { len, max } => {
+                write!(f, "Length of {} bytes exceeds the maximum of {}", len, max)
+            }
+            CodecError::LengthMismatch { expected, actual } => {
+                write!(f, "Decoded packet has {} bytes, expected {}", actual, expected)
+            }
+            CodecError::Io(error) => write!(f, "I/O error: {}", error),
+        }
+    }
+}
+
+impl Error for CodecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CodecError::InvalidPacket(error) => Some(error),
+            CodecError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<binrw::Error> for CodecError {
+    fn from(error: binrw::Error) -> Self {
+        CodecError::InvalidPacket(error)
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(error: io::Error) -> Self {
+        CodecError::Io(error)
+    }
+}
+
+/// Encodes raw packets into compact frames.
+///
+/// Each packet is stored as field-wise deltas against the previous packet
+/// of the same [`PacketId`], so fields that haven't changed (most of car setups
+/// and participants, for instance) turn into runs of zeros.
+/// These runs are then stored as their lengths. Packets without
+/// a reference of the same length (e.g. the first packet of each type)
+/// are stored as keyframes, which only have their runs of zeros compressed.
+///
+/// Field boundaries are the ones [`parse`] reads from the reference packet,
+/// so they follow the layout of every packet type in every supported format.
+/// Packet types whose layout only depends on the format and length,
+/// such as motion and car telemetry, only get parsed for it once.
+/// Each field's delta is the wrapping difference of its little-endian bits,
+/// floats included, which restores the exact bytes. Bytes the parser skips,
+/// and packets it can't parse, are treated as single-byte fields.
+///
+/// Frames have to be decoded in the same order by a [`DeltaDecoder`].
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct DeltaEncoder {
+    keyframe_interval: Option<usize>,
+    references: BTreeMap<u8, (usize, Reference)>,
+    layouts: Layouts,
+}
+
+impl DeltaEncoder {
+    /// Creates an encoder that only emits keyframes when there's no matching reference.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores every n-th packet of each type as a keyframe,
+    /// which limits how much data gets lost if a frame gets corrupted.
+    pub fn with_keyframe_interval(mut self, packets: usize) -> Self {
+        self.keyframe_interval = Some(packets).filter(|&packets| packets > 0);
+        self
+    }
+
+    /// Encodes a raw packet into a frame.
+    ///
+    /// ## Errors
+    ///
+    /// [`CodecError::InvalidPacket`] if the packet's header can't be read,
+    /// [`CodecError::TooLarge`] if it's longer than [`MAX_PACKET_LEN`].
+    pub fn encode(&mut self, raw: &[u8]) -> Result<Vec<u8>, CodecError> {
+        check_len(raw.len(), MAX_PACKET_LEN)?;
+
+        let header = F1PacketHeader::read(&mut Cursor::new(raw))?;
+        let packet_id = header.packet_id as u8;
+        let mut frame = Vec::with_capacity(raw.len() / 4);
+        let keyframe_interval = self.keyframe_interval;
+
+        let reference =
+            self.references.get_mut(&packet_id).filter(|(count, previous)| {
+                previous.raw.len() == raw.len()
+                    && keyframe_interval.map_or(true, |interval| count % interval != 0)
+            });
+
+        match reference {
+            Some((count, previous)) => {
+                frame.extend([DELTA, packet_id]);
+                write_varint(&mut frame, raw.len());
+
+                let deltas = previous.map_fields(raw, u64::wrapping_sub);
+
+                write_zero_runs(&mut frame, &deltas);
+                *previous = self.layouts.reference(raw.to_vec());
+                *count += 1;
+            }
+            None => {
+                frame.extend([KEYFRAME, packet_id]);
+                write_varint(&mut frame, raw.len());
+                write_zero_runs(&mut frame, raw);
+                let reference = self.layouts.reference(raw.to_vec());
+
+                self.references.insert(packet_id, (1, reference));
+            }
+        }
+
+        Ok(frame)
+    }
+}
+
+/// Decodes frames produced by a [`DeltaEncoder`] back into byte-exact raw packets.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct DeltaDecoder {
+    references: BTreeMap<u8, Reference>,
+    layouts: Layouts,
+}
+
+impl DeltaDecoder {
+    /// Creates a decoder with no reference packets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decodes a frame into the raw packet it was encoded from.
+    pub fn decode(&mut self, frame: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let [kind, packet_id, rest @ ..] = frame else {
+            return Err(CodecError::Truncated);
+        };
+        let mut input = rest;
+        let len = check_len(read_varint(&mut input)?, MAX_PACKET_LEN)?;
+        let bytes = read_zero_runs(input, len)?;
+
+        let raw = match *kind {
+            KEYFRAME => bytes,
+            DELTA => {
+                let reference = self
+                    .references
+                    .get(packet_id)
+                    .ok_or(CodecError::MissingReference(*packet_id))?;
+
+                if reference.raw.len() != len {
+                    return Err(CodecError::LengthMismatch {
+                        expected: len,
+                        actual: reference.raw.len(),
+                    });
+                }
+
+                reference.map_fields(&bytes, u64::wrapping_add)
+            }
+            kind => return Err(CodecError::UnknownFrameKind(kind)),
+        };
+
+        self.references.insert(*packet_id, self.layouts.reference(raw.clone()));
+        Ok(raw)
+    }
+
+    /// Decodes a frame and parses the resulting raw packet.
+    pub fn decode_packet(&mut self, frame: &[u8]) -> Result<F1Packet, CodecError> {
+        Ok(parse(self.decode(frame)?)?)
+    }
+}
+
+/// Writes raw packets into a stream of length-prefixed [`DeltaEncoder`] frames,
+/// preceded by magic bytes and a format version.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::codec::CodecWriter;
+/// use std::fs::File;
+/// use std::io::BufWriter;
+/// use std::net::UdpSocket;
+///
+/// let file = BufWriter::new(File::create("session.f1dc")?);
+/// let mut writer = CodecWriter::new(file);
+/// let socket = UdpSocket::bind("0.0.0.0:20777")?;
+/// let mut buf = [0u8; 2048];
+///
+/// loop {
+///     let len = socket.recv(&mut buf)?;
+///     writer.write_packet(&buf[..len])?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct CodecWriter<W: Write> {
+    writer: W,
+    encoder: DeltaEncoder,
+    started: bool,
+}
+
+impl<W: Write> CodecWriter<W> {
+    /// Creates a writer with a default [`DeltaEncoder`].
+    pub fn new(writer: W) -> Self {
+        Self::with_encoder(writer, DeltaEncoder::new())
+    }
+
+    /// Creates a writer with a custom encoder, e.g. one with a keyframe interval.
+    pub fn with_encoder(writer: W, encoder: DeltaEncoder) -> Self {
+        Self { writer, encoder, started: false }
+    }
+
+    /// Encodes a raw packet and writes its frame.
+    /// Returns the number of bytes written.
+    pub fn write_packet(&mut self, raw: &[u8]) -> Result<usize, CodecError> {
+        let frame = self.encoder.encode(raw)?;
+        let mut bytes = Vec::with_capacity(frame.len() + 10);
+
+        if !self.started {
+            bytes.extend(MAGIC);
+            bytes.push(FORMAT_VERSION);
+            self.started = true;
+        }
+
+        write_varint(&mut bytes, frame.len());
+        bytes.extend(frame);
+        self.writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads raw packets from a stream written by a [`CodecWriter`].
+#[derive(Debug)]
+pub struct CodecReader<R: Read> {
+    reader: R,
+    decoder: DeltaDecoder,
+    started: bool,
+}
+
+impl<R: Read> CodecReader<R> {
+    /// Creates a reader at the start of a stream.
+    pub fn new(reader: R) -> Self {
+        Self { reader, decoder: DeltaDecoder::new(), started: false }
+    }
+
+    /// Reads the next raw packet. Returns [`None`] at the end of the stream.
+    pub fn read_raw(&mut self) -> Result<Option<Vec<u8>>, CodecError> {
+        if !self.started {
+            let mut magic = [0u8; 5];
+            let mut magic_len = 0;
+
+            // Only a stream without a single byte is empty.
+            while magic_len < magic.len() {
+                match self.reader.read(&mut magic[magic_len..]) {
+                    Ok(0) if magic_len == 0 => return Ok(None),
+                    Ok(0) => return Err(CodecError::Truncated),
+                    Ok(len) => magic_len += len,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            if magic[..4] != MAGIC[..] || magic[4] != FORMAT_VERSION {
+                return Err(CodecError::InvalidMagic);
+            }
+
+            self.started = true;
+        }
+
+        let Some(len) = self.read_frame_len()? else {
+            return Ok(None);
+        };
+        let mut frame = vec![0u8; check_len(len, MAX_FRAME_LEN)?];
+
+        self.reader.read_exact(&mut frame).map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => CodecError::Truncated,
+            _ => e.into(),
+        })?;
+
+        self.decoder.decode(&frame).map(Some)
+    }
+
+    /// Reads and parses the next packet. Returns [`None`] at the end of the stream.
+    pub fn read_packet(&mut self) -> Result<Option<F1Packet>, CodecError> {
+        match self.read_raw()? {
+            Some(raw) => Ok(Some(parse(raw)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn read_frame_len(&mut self) -> Result<Option<usize>, CodecError> {
+        let mut value = 0usize;
+        let mut byte = [0u8; 1];
+
+        for shift in (0..usize::BITS).step_by(7) {
+            match self.reader.read_exact(&mut byte) {
+                Ok(()) => (),
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof && shift == 0 => {
+                    return Ok(None)
+                }
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+                    return Err(CodecError::Truncated)
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            value |= usize::from(byte[0] & 0x7f) << shift;
+
+            if byte[0] & 0x80 == 0 {
+                return Ok(Some(value));
+            }
+        }
+
+        Err(CodecError::Truncated)
+    }
+}
+
+impl<R: Read> Iterator for CodecReader<R> {
+    type Item = Result<Vec<u8>, CodecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_raw().transpose()
+    }
+}
+
+/// Field widths of packet types with a fixed layout,
+/// keyed by packet ID, packet format and length.
+#[derive(PartialEq, Clone, Debug, Default)]
+struct Layouts(BTreeMap<(u8, u16, usize), Arc<[usize]>>);
+
+impl Layouts {
+    fn reference(&mut self, raw: Vec<u8>) -> Reference {
+        let Some(key) = fixed_layout_key(&raw) else {
+            return Reference { widths: field_widths(&raw).into(), raw };
+        };
+        let widths = self.0.entry(key).or_insert_with(|| field_widths(&raw).into());
+
+        Reference { widths: Arc::clone(widths), raw }
+    }
+}
+
+/// Returns [`None`] for packets whose layout depends on their contents,
+/// e.g. the number of marshal zones in a session packet or an event's code.
+fn fixed_layout_key(raw: &[u8]) -> Option<(u8, u16, usize)> {
+    let header = F1PacketHeader::read(&mut Cursor::new(raw)).ok()?;
+
+    match header.packet_id {
+        PacketId::Session
+        | PacketId::Event
+        | PacketId::Participants
+        | PacketId::FinalClassification
+        | PacketId::LobbyInfo
+        | PacketId::SessionHistory => None,
+        packet_id => Some((packet_id as u8, header.packet_format, raw.len())),
+    }
+}
+
+/// Previous packet of a type, along with the widths of the fields it's made of.
+#[derive(PartialEq, Clone, Debug)]
+struct Reference {
+    raw: Vec<u8>,
+    widths: Arc<[usize]>,
+}
+
+impl Reference {
+    /// Combines each field of `bytes` with the same field of the reference.
+    fn map_fields(&self, bytes: &[u8], op: fn(u64, u64) -> u64) -> Vec<u8> {
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut offset = 0;
+
+        for &width in self.widths.iter() {
+            let range = offset..offset + width;
+            let value =
+                op(read_field(&bytes[range.clone()]), read_field(&self.raw[range]));
+
+            out.extend(&value.to_le_bytes()[..width]);
+            offset += width;
+        }
+
+        out
+    }
+}
+
+/// Returns the widths of the fields [`parse`] reads from a raw packet.
+fn field_widths(raw: &[u8]) -> Vec<usize> {
+    let mut recorder = FieldRecorder { cursor: Cursor::new(raw), fields: Vec::new() };
+    // Whatever the parser has read before failing still counts as fields.
+    let _ = recorder.read_le::<F1Packet>();
+    let mut fields = recorder.fields;
+    let mut widths = Vec::with_capacity(raw.len());
+    let mut offset = 0;
+
+    fields.sort_unstable();
+
+    for (start, len) in fields {
+        // Fields read with `restore_position` get read more than once.
+        if start < offset {
+            continue;
+        }
+
+        widths.resize(widths.len() + start - offset, 1);
+
+        match len {
+            2 | 4 | 8 => widths.push(len),
+            _ => widths.resize(widths.len() + len, 1),
+        }
+
+        offset = start + len;
+    }
+
+    widths.resize(widths.len() + raw.len() - offset, 1);
+    widths
+}
+
+fn read_field(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+
+    buf[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+/// Reader that records the offset and length of every read the parser makes.
+struct FieldRecorder<'a> {
+    cursor: Cursor<&'a [u8]>,
+    fields: Vec<(usize, usize)>,
+}
+
+impl Read for FieldRecorder<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let start = self.cursor.position() as usize;
+        let len = self.cursor.read(buf)?;
+
+        self.fields.push((start, len));
+        Ok(len)
+    }
+}
+
+impl Seek for FieldRecorder<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.cursor.seek(pos)
+    }
+}
+
+fn check_len(len: usize, max: usize) -> Result<usize, CodecError> {
+    if len > max {
+        return Err(CodecError::TooLarge { len, max });
+    }
+
+    Ok(len)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    out.push(value as u8);
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<usize, CodecError> {
+    let mut value = 0usize;
+
+    for shift in (0..usize::BITS).step_by(7) {
+        let (&byte, rest) = input.split_first().ok_or(CodecError::Truncated)?;
+
+        *input = rest;
+        value |= usize::from(byte & 0x7f) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(CodecError::Truncated)
+}
+
+/// Stores bytes as pairs of a literal run (its length followed by its bytes)
+/// and the length of the run of zeros that comes after it.
+fn write_zero_runs(out: &mut Vec<u8>, bytes: &[u8]) {
+    let mut rest = bytes;
+
+    while !rest.is_empty() {
+        let literal_len = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+        let (literal, after) = rest.split_at(literal_len);
+        let zeros_len = after.iter().position(|&b| b != 0).unwrap_or(after.len());
+
+        write_varint(out, literal_len);
+        out.extend(literal);
+        write_varint(out, zeros_len);
+        rest = &after[zeros_len..];
+    }
+}
+
+fn read_zero_runs(mut input: &[u8], len: usize) -> Result<Vec<u8>, CodecError> {
+    let mut bytes = Vec::with_capacity(len);
+
+    while bytes.len() < len {
+        let literal_len = read_varint(&mut input)?;
+        let literal = input.get(..literal_len).ok_or(CodecError::Truncated)?;
+
+        bytes.extend(literal);
+        input = &input[literal_len..];
+
+        let zeros_len = read_varint(&mut input)?;
+
+        if literal_len == 0 && zeros_len == 0 {
+            return Err(CodecError::Truncated);
+        }
+
+        if bytes.len() + zeros_len > len {
+            return Err(CodecError::LengthMismatch {
+                expected: len,
+                actual: bytes.len() + zeros_len,
+            });
+        }
+
+        bytes.resize(bytes.len() + zeros_len, 0);
+    }
+
+    if bytes.len() != len {
+        return Err(CodecError::LengthMismatch { expected: len, actual: bytes.len() });
+    }
+
+    Ok(bytes)
+}
@@ -165,3 +165,180 @@ pub const RITOMO_MIYATA: u8 = 166;
 pub const RAFAEL_VILLAGOMEZ: u8 = 167;
 pub const ZAK_O_SULLIVAN: u8 = 168;
 pub const JOSEP_MARIA_MARTI: u8 = 169;
+
+/// Returns the full name of a driver, or [`None`] if the ID is unknown.
+/// Some IDs refer to different drivers in the 2022 format.
+pub fn driver_name(driver_id: u8, packet_format: u16) -> Option<&'static str> {
+    let name = match (driver_id, packet_format) {
+        (JAKE_HUGHES_2022, 2022) => "Jake Hughes",
+        (FREDERIK_VESTI_2022, 2022) => "Frederik Vesti",
+        (OLLI_CALDWELL_2022, 2022) => "Olli Caldwell",
+        (LOGAN_SARGEANT_2022, 2022) => "Logan Sargeant",
+        (CEM_BOLUKBASI_2022, 2022) => "Cem Bolukbasi",
+        (AYUMU_IWASA_2022, 2022) => "Ayumu Iwasa",
+        (CLEMENT_NOVALAK_2022, 2022) => "Clement Novalak",
+        (DENNIS_HAUGER_2022, 2022) => "Dennis Hauger",
+        (CALAN_WILLIAMS_2022, 2022) => "Calan Williams",
+        (MIKA_HAKKINEN_2022, 2022) => "Mika Hakkinen",
+        (CALLIE_MAYER, _) => "Callie Mayer",
+        (NOAH_BELL, _) => "Noah Bell",
+        (JAKE_HUGHES, _) => "Jake Hughes",
+        (FREDERIK_VESTI, _) => "Frederik Vesti",
+        (OLLI_CALDWELL, _) => "Olli Caldwell",
+        (LOGAN_SARGEANT, _) => "Logan Sargeant",
+        (CEM_BOLUKBASI, _) => "Cem Bolukbasi",
+        (AYUMU_IWASA, _) => "Ayumu Iwasa",
+        (CLEMENT_NOVALAK, _) => "Clement Novalak",
+        (DENNIS_HAUGER, _) => "Dennis Hauger",
+        (CARLOS_SAINZ, _) => "Carlos Sainz",
+        (DANILL_KVYAT, _) => "Daniil Kvyat",
+        (DANIEL_RICCIARDO, _) => "Daniel Ricciardo",
+        (FERNANDO_ALONSO, _) => "Fernando Alonso",
+        (FELIPE_MASSA, _) => "Felipe Massa",
+        (KIMI_RAIKKONEN, _) => "Kimi Raikkonen",
+        (LEWIS_HAMILTON, _) => "Lewis Hamilton",
+        (MARCUS_ERICSSON, _) => "Marcus Ericsson",
+        (MAX_VERSTAPPEN, _) => "Max Verstappen",
+        (NICO_HULKENBERG, _) => "Nico Hulkenberg",
+        (KEVIN_MAGNUSSEN, _) => "Kevin Magnussen",
+        (ROMAIN_GROSJEAN, _) => "Romain Grosjean",
+        (SEBASTIAN_VETTEL, _) => "Sebastian Vettel",
+        (SERGIO_PEREZ, _) => "Sergio Perez",
+        (VALTTERI_BOTTAS, _) => "Valtteri Bottas",
+        (ESTEBAN_OCON, _) => "Esteban Ocon",
+        (STOFFEL_VANDOORNE, _) => "Stoffel Vandoorne",
+        (LANCE_STROLL, _) => "Lance Stroll",
+        (ARRON_BARNES, _) => "Arron Barnes",
+        (MARTIN_GILES, _) => "Martin Giles",
+        (ALEX_MURRAY, _) => "Alex Murray",
+        (LUCAS_ROTH, _) => "Lucas Roth",
+        (IGOR_CORREIA, _) => "Igor Correia",
+        (SOPHIE_LEVASSEUR, _) => "Sophie Levasseur",
+        (JONAS_SCHIFFER, _) => "Jonas Schiffer",
+        (ALAIN_FORREST, _) => "Alain Forrest",
+        (JAY_LETOURNEAU, _) => "Jay Letourneau",
+        (ESTO_SAARI, _) => "Esto Saari",
+        (YASAR_ATIEH, _) => "Yasar Atieh",
+        (CALLISTO_CALABRESI, _) => "Callisto Calabresi",
+        (NAOTA_IZUMI, _) => "Naota Izumi",
+        (HOWARD_CLARKE, _) => "Howard Clarke",
+        (WILHELM_KAUFMANN, _) => "Wilhelm Kaufmann",
+        (MARIE_LAURSEN, _) => "Marie Laursen",
+        (FLAVIO_NIEVES, _) => "Flavio Nieves",
+        (PETER_BELOUSOV, _) => "Peter Belousov",
+        (KLIMEK_MICHALSKI, _) => "Klimek Michalski",
+        (SANTIAGO_MORENO, _) => "Santiago Moreno",
+        (BENJAMIN_COPPENS, _) => "Benjamin Coppens",
+        (NOAH_VISSER, _) => "Noah Visser",
+        (GERT_WALDMULLER, _) => "Gert Waldmuller",
+        (JULIAN_QUESADA, _) => "Julian Quesada",
+        (DANIEL_JONES, _) => "Daniel Jones",
+        (ARTEM_MARKELOV, _) => "Artem Markelov",
+        (TADASUKE_MAKINO, _) => "Tadasuke Makino",
+        (SEAN_GELAEL, _) => "Sean Gelael",
+        (NYCK_DE_VRIES, _) => "Nyck de Vries",
+        (JACK_AITKEN, _) => "Jack Aitken",
+        (GEORGE_RUSSELL, _) => "George Russell",
+        (MAXIMILIAN_GUNTHER, _) => "Maximilian Gunther",
+        (NIREI_FUKUZUMI, _) => "Nirei Fukuzumi",
+        (LUCA_GHIOTTO, _) => "Luca Ghiotto",
+        (LANDO_NORRIS, _) => "Lando Norris",
+        (SERGIO_SETTE_CAMARA, _) => "Sergio Sette Camara",
+        (LOUIS_DELETRAZ, _) => "Louis Deletraz",
+        (ANTONIO_FUOCCO, _) => "Antonio Fuocco",
+        (CHARLES_LECLERC, _) => "Charles Leclerc",
+        (PIERRE_GASLY, _) => "Pierre Gasly",
+        (BRENDON_HARTLEY, _) => "Brendon Hartley",
+        (SERGEY_SIROTKIN, _) => "Sergey Sirotkin",
+        (ALEXANDER_ALBON, _) => "Alexander Albon",
+        (NICHOLAS_LATIFI, _) => "Nicholas Latifi",
+        (DORIAN_BOCCOLACCI, _) => "Dorian Boccolacci",
+        (NIKO_KARI, _) => "Niko Kari",
+        (ROBERTO_MEHRI, _) => "Roberto Mehri",
+        (ARJUN_MAINI, _) => "Arjun Maini",
+        (ALESSIO_LORANDI, _) => "Alessio Lorandi",
+        (RUBEN_MEIJER, _) => "Ruben Meijer",
+        (RASHID_NAIR, _) => "Rashid Nair",
+        (JACK_TREMBLAY, _) => "Jack Tremblay",
+        (ANTONIO_GIOVINAZZI, _) => "Antonio Giovinazzi",
+        (ROBERT_KUBICA, _) => "Robert Kubica",
+        (ALAIN_PROST, _) => "Alain Prost",
+        (AYRTON_SENNA, _) => "Ayrton Senna",
+        (NOBUHARU_MATSUSHITA, _) => "Nobuharu Matsushita",
+        (NIKITA_MAZEPIN, _) => "Nikita Mazepin",
+        (GUANYU_ZHOU, _) => "Guanyu Zhou",
+        (MICK_SCHUMACHER, _) => "Mick Schumacher",
+        (CALLUM_ILOTT, _) => "Callum Ilott",
+        (JUAN_MANUEL_CORREA, _) => "Juan Manuel Correa",
+        (JORDAN_KING, _) => "Jordan King",
+        (MAHAVEER_RAGUNATHAN, _) => "Mahaveer Ragunathan",
+        (TATIANA_CALDERON, _) => "Tatiana Calderon",
+        (ANTHOINE_HUBERT, _) => "Anthoine Hubert",
+        (GUILIANO_ALESI, _) => "Guiliano Alesi",
+        (RALPH_BOSCHUNG, _) => "Ralph Boschung",
+        (MICHAEL_SCHUMACHER, _) => "Michael Schumacher",
+        (DAN_TICKTUM, _) => "Dan Ticktum",
+        (MARCUS_ARMSTRONG, _) => "Marcus Armstrong",
+        (CHRISTIAN_LUNDGAARD, _) => "Christian Lundgaard",
+        (YUKI_TSUNODA, _) => "Yuki Tsunoda",
+        (JEHAN_DARUVALA, _) => "Jehan Daruvala",
+        (GULHERME_SAMAIA, _) => "Gulherme Samaia",
+        (PEDRO_PIQUET, _) => "Pedro Piquet",
+        (FELIPE_DRUGOVICH, _) => "Felipe Drugovich",
+        (ROBERT_SCHWARTZMAN, _) => "Robert Schwartzman",
+        (ROY_NISSANY, _) => "Roy Nissany",
+        (MARINO_SATO, _) => "Marino Sato",
+        (AIDAN_JACKSON, _) => "Aidan Jackson",
+        (CASPER_AKKERMAN, _) => "Casper Akkerman",
+        (JENSON_BUTTON, _) => "Jenson Button",
+        (DAVID_COULTHARD, _) => "David Coulthard",
+        (NICO_ROSBERG, _) => "Nico Rosberg",
+        (OSCAR_PIASTRI, _) => "Oscar Piastri",
+        (LIAM_LAWSON, _) => "Liam Lawson",
+        (JURI_VIPS, _) => "Juri Vips",
+        (THEO_POURCHAIRE, _) => "Theo Pourchaire",
+        (RICHARD_VERSCHOOR, _) => "Richard Verschoor",
+        (LIRIM_ZENDELI, _) => "Lirim Zendeli",
+        (DAVID_BECKMANN, _) => "David Beckmann",
+        (ALESSIO_DELEDDA, _) => "Alessio Deledda",
+        (BENT_VISCAAL, _) => "Bent Viscaal",
+        (ENZO_FITTIPALDI, _) => "Enzo Fittipaldi",
+        (MARK_WEBBER, _) => "Mark Webber",
+        (JACQUES_VILLENEUVE, _) => "Jacques Villeneuve",
+        (JACK_DOOHAN, _) => "Jack Doohan",
+        (AMAURY_CORDEEL, _) => "Amaury Cordeel",
+        (CALAN_WILLIAMS, _) => "Calan Williams",
+        (JAMIE_CHADWICK, _) => "Jamie Chadwick",
+        (KAMUI_KOBAYASHI, _) => "Kamui Kobayashi",
+        (PASTOR_MALDONADO, _) => "Pastor Maldonado",
+        (MIKA_HAKKINEN, _) => "Mika Hakkinen",
+        (NIGEL_MANSELL, _) => "Nigel Mansell",
+        (ZANE_MALONEY, _) => "Zane Maloney",
+        (VICTOR_MARTINS, _) => "Victor Martins",
+        (OLIVER_BEARMAN, _) => "Oliver Bearman",
+        (JAK_CRAWFORD, _) => "Jak Crawford",
+        (ISACK_HADJAR, _) => "Isack Hadjar",
+        (ARTHUR_LECLERC, _) => "Arthur Leclerc",
+        (BRAD_BENAVIDES, _) => "Brad Benavides",
+        (ROMAN_STANEK, _) => "Roman Stanek",
+        (KUSH_MAINI, _) => "Kush Maini",
+        (JAMES_HUNT, _) => "James Hunt",
+        (JUAN_PABLO_MONTOYA, _) => "Juan Pablo Montoya",
+        (BRENDON_LEIGH, _) => "Brendon Leigh",
+        (DAVID_TONIZZA, _) => "David Tonizza",
+        (JARNO_OPMEER, _) => "Jarno Opmeer",
+        (LUCAS_BLAKELEY, _) => "Lucas Blakeley",
+        (PAUL_ARON, _) => "Paul Aron",
+        (GABRIEL_BORTOLERO, _) => "Gabriel Bortolero",
+        (TAYLOR_BARNARD, _) => "Taylor Barnard",
+        (JOSHUA_DUERKSEN, _) => "Joshua Duerksen",
+        (ANDREA_KIMI_ANTONELLI, _) => "Andrea Kimi Antonelli",
+        (RITOMO_MIYATA, _) => "Ritomo Miyata",
+        (RAFAEL_VILLAGOMEZ, _) => "Rafael Villagomez",
+        (ZAK_O_SULLIVAN, _) => "Zak O'Sullivan",
+        (JOSEP_MARIA_MARTI, _) => "Josep Maria Marti",
+        _ => return None,
+    };
+
+    Some(name)
+}
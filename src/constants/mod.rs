@@ -14,6 +14,7 @@ pub mod wheel_index;
 
 use binrw::BinRead;
 use bitflags::bitflags;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub(crate) const MAX_NUM_CARS: usize = 22;
@@ -21,19 +22,8 @@ pub(crate) const MAX_NUM_CARS: usize = 22;
 /// Unique identifier of the type of this packet.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum PacketId {
     Motion = 0,
@@ -57,19 +47,8 @@ pub enum PacketId {
 /// a [`MarshalZone`](crate::packets::session::MarshalZone).
 /// Represents an [`i8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(i8))]
 pub enum MarshalZoneFlag {
     Unknown = -1,
@@ -82,19 +61,8 @@ pub enum MarshalZoneFlag {
 
 /// Session/forecast weather type. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Weather {
     Clear = 0,
@@ -107,19 +75,8 @@ pub enum Weather {
 
 /// Temperature change direction. Represents an [`i8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(i8))]
 pub enum TemperatureChange {
     Up = 0,
@@ -129,19 +86,8 @@ pub enum TemperatureChange {
 
 /// Unique circuit ID. Represents an [`i8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(i8))]
 pub enum TrackId {
     /// Unknown circuit.
@@ -218,19 +164,8 @@ pub enum TrackId {
 /// [`F1PacketSession`](struct@crate::F1PacketSession).
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Formula {
     F1Modern = 0,
@@ -248,19 +183,8 @@ pub enum Formula {
 /// Safety car deployment status in [`F1PacketSession`](struct@crate::F1PacketSession).
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SafetyCarStatus {
     None = 0,
@@ -273,19 +197,8 @@ pub enum SafetyCarStatus {
 /// [`WeatherForecastSample`](struct@crate::packets::session::WeatherForecastSample).
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum ForecastAccuracy {
     Perfect = 0,
@@ -294,19 +207,8 @@ pub enum ForecastAccuracy {
 
 /// Type of enabled braking assist. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum BrakingAssist {
     Off = 0,
@@ -317,19 +219,8 @@ pub enum BrakingAssist {
 
 /// Type of enabled gearbox assist. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum GearboxAssist {
     Unknown = 0,
@@ -340,19 +231,8 @@ pub enum GearboxAssist {
 
 /// Type of enabled racing line assist. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum DynamicRacingLine {
     Off = 0,
@@ -362,19 +242,8 @@ pub enum DynamicRacingLine {
 
 /// Shape of the racing line. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum DynamicRacingLineType {
     TwoDimensional = 0,
@@ -383,19 +252,8 @@ pub enum DynamicRacingLineType {
 
 /// Game mode that's currently in use. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum GameMode {
     EventMode = 0,
@@ -424,19 +282,8 @@ pub enum GameMode {
 
 /// Set of rules that's in use for this session. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum RuleSet {
     PracticeAndQualifying = 0,
@@ -452,19 +299,8 @@ pub enum RuleSet {
 
 /// Length of the ongoing session. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SessionLength {
     None = 0,
@@ -478,19 +314,8 @@ pub enum SessionLength {
 
 /// Whether the car is outside/entering/in the pit lane. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum PitStatus {
     None = 0,
@@ -500,19 +325,8 @@ pub enum PitStatus {
 
 /// Zero-based sector number. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Sector {
     First = 0,
@@ -522,19 +336,8 @@ pub enum Sector {
 
 /// Status of a driver in the current session. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum DriverStatus {
     InGarage = 0,
@@ -547,19 +350,8 @@ pub enum DriverStatus {
 /// Status of a driver's result in the current session and final classification.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum ResultStatus {
     Unknown = 0,
@@ -574,19 +366,8 @@ pub enum ResultStatus {
 
 /// Type of penalty awarded to a driver. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum PenaltyType {
     DriveThrough = 0,
@@ -611,19 +392,8 @@ pub enum PenaltyType {
 
 /// Type of offence commited by a driver. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum InfringementType {
     BlockingBySlowDriving = 0,
@@ -686,9 +456,8 @@ pub enum InfringementType {
 /// Bit flags of specific controller buttons being pressed
 /// in a [`Buttons` event](variant@crate::packets::event::EventDetails::Buttons).
 /// Represents a [`u32`].
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ButtonStatus(u32);
 
 bitflags! {
@@ -772,19 +541,8 @@ bitflags! {
 
 /// Unique identifier of a driver's nationality. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Nationality {
     Unknown = 0,
@@ -882,19 +640,8 @@ pub enum Nationality {
 
 /// "Your telemetry" UDP setting value. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum YourTelemetry {
     Restricted = 0,
@@ -903,19 +650,8 @@ pub enum YourTelemetry {
 
 /// Type of surface a tyre is on. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Surface {
     Tarmac = 0,
@@ -934,9 +670,8 @@ pub enum Surface {
 
 /// Bit flags of lit rev lights on a steering wheel.
 /// Represents a [`u16`].
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RevLights(u16);
 
 bitflags! {
@@ -976,19 +711,8 @@ bitflags! {
 
 /// Index of currently open multi-function display panel. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum MfdPanelIndex {
     CarSetup = 0,
@@ -1001,19 +725,8 @@ pub enum MfdPanelIndex {
 
 /// Type of enabled traction control assist. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum TractionControl {
     Off = 0,
@@ -1023,19 +736,8 @@ pub enum TractionControl {
 
 /// Type of fuel mix that's currently in use. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum FuelMix {
     Lean = 0,
@@ -1046,19 +748,8 @@ pub enum FuelMix {
 
 /// ERS deployment mode that's currently in use. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum ErsDeployMode {
     None = 0,
@@ -1069,19 +760,8 @@ pub enum ErsDeployMode {
 
 /// Flag the driver is currently being shown. Represents an [`i8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(i8))]
 pub enum VehicleFiaFlag {
     Unknown = -1,
@@ -1094,19 +774,8 @@ pub enum VehicleFiaFlag {
 
 /// Global DRS activation permission status. Represents an [`i8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(i8))]
 pub enum DrsAllowed {
     Unknown = -1,
@@ -1116,19 +785,8 @@ pub enum DrsAllowed {
 
 /// Session-independent tyre compound type. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum ActualTyreCompound {
     Unknown = 0,
@@ -1152,19 +810,8 @@ pub enum ActualTyreCompound {
 /// Visual indicator of a tyre compound's type in a given session.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum VisualTyreCompound {
     Unknown = 0,
@@ -1184,19 +831,8 @@ pub enum VisualTyreCompound {
 
 /// Readiness of a player in an online lobby. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum ReadyStatus {
     NotReady = 0,
@@ -1206,9 +842,8 @@ pub enum ReadyStatus {
 
 /// Bit flags of lap validity across all three sectors and overall.
 /// Represents a [`u8`].
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LapValid(u8);
 
 bitflags! {
@@ -1226,19 +861,8 @@ bitflags! {
 
 /// Speed unit used by a player. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SpeedUnit {
     MilesPerHour = 0,
@@ -1247,19 +871,8 @@ pub enum SpeedUnit {
 
 /// Temperature unit used by a player. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum TemperatureUnit {
     Celsius = 0,
@@ -1269,19 +882,8 @@ pub enum TemperatureUnit {
 /// Console or PC game distribution platform used by a player.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Platform {
     Invalid = 0,
@@ -1295,19 +897,8 @@ pub enum Platform {
 /// Recovery mode assist that's currently enabled.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum RecoveryMode {
     None = 0,
@@ -1318,19 +909,8 @@ pub enum RecoveryMode {
 /// Flashback usage limit that's currently enabled.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum FlashbackLimit {
     Low = 0,
@@ -1342,19 +922,8 @@ pub enum FlashbackLimit {
 /// Type of surface simulation that's currently enabled.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SurfaceSimType {
     Simplified = 0,
@@ -1363,19 +932,8 @@ pub enum SurfaceSimType {
 
 /// Difficulty of driving with low fuel. Represent a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum LowFuelMode {
     Easy = 0,
@@ -1385,19 +943,8 @@ pub enum LowFuelMode {
 /// Race starts assist that's currently in use.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum RaceStarts {
     Manual = 0,
@@ -1407,19 +954,8 @@ pub enum RaceStarts {
 /// Type of tyre temperature simulation that's currently in use.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum TyreTemperature {
     SurfaceOnly = 0,
@@ -1429,19 +965,8 @@ pub enum TyreTemperature {
 /// Type of car damage simulation that's currently in use.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum CarDamage {
     Off = 0,
@@ -1452,19 +977,8 @@ pub enum CarDamage {
 
 /// Car damage severity. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum CarDamageRate {
     Reduced = 0,
@@ -1475,19 +989,8 @@ pub enum CarDamageRate {
 /// Type of collision simulation that's currently enabled.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum Collisions {
     Off = 0,
@@ -1498,19 +1001,8 @@ pub enum Collisions {
 /// Type of corner cutting and track limits punishability.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum CornerCuttingStringency {
     Regular = 0,
@@ -1519,19 +1011,8 @@ pub enum CornerCuttingStringency {
 
 /// The way the game handles pit stops. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum PitStopExperience {
     Automatic = 0,
@@ -1542,19 +1023,8 @@ pub enum PitStopExperience {
 /// The likelihood of safety car getting deployed with hazard on track.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SafetyCarIntensity {
     Off = 0,
@@ -1565,19 +1035,8 @@ pub enum SafetyCarIntensity {
 
 /// The way the game handles safety car periods. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SafetyCarExperience {
     Broadcast = 0,
@@ -1587,19 +1046,8 @@ pub enum SafetyCarExperience {
 
 /// The way the game handles formation laps. Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum FormationLapExperience {
     Broadcast = 0,
@@ -1610,19 +1058,8 @@ pub enum FormationLapExperience {
 /// The likelihood of the game using a red flag after a serious incident.
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum RedFlagIntensity {
     Off = 0,
@@ -1635,19 +1072,8 @@ pub enum RedFlagIntensity {
 /// [`SafetyCar` event](variant@crate::packets::event::EventDetails::SafetyCar).
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SafetyCarType {
     None = 0,
@@ -1659,19 +1085,8 @@ pub enum SafetyCarType {
 /// Type of [`SafetyCar` event](variant@crate::packets::event::EventDetails::SafetyCar).
 /// Represents a [`u8`].
 #[non_exhaustive]
-#[derive(
-    BinRead,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Copy,
-    Clone,
-    Debug,
-    Hash,
-    Serialize,
-    Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, repr(u8))]
 pub enum SafetyCarEventType {
     Deployed = 0,
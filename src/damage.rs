@@ -0,0 +1,342 @@
+use crate::constants::{Sector, MAX_NUM_CARS};
+use crate::packets::car_damage::CarDamageData;
+use crate::packets::event::EventDetails;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+
+const DEFAULT_MIN_INCREASE: u8 = 1;
+const DEFAULT_COLLISION_WINDOW: f32 = 1.0;
+const WHEEL_NAMES: [&str; 4] = ["rear left", "rear right", "front left", "front right"];
+
+/// Car component that can get damaged or worn.
+/// Wheel indexes follow the [`wheel_index`](mod@crate::constants::wheel_index) order.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DamageComponent {
+    TyreDamage(usize),
+    BrakeDamage(usize),
+    FrontLeftWing,
+    FrontRightWing,
+    RearWing,
+    Floor,
+    Diffuser,
+    Sidepod,
+    Gearbox,
+    Engine,
+    EngineMguhWear,
+    EngineEsWear,
+    EngineCeWear,
+    EngineIceWear,
+    EngineMgukWear,
+    EngineTcWear,
+}
+
+impl fmt::Display for DamageComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TyreDamage(wheel) => {
+                write!(f, "{} tyre", WHEEL_NAMES.get(*wheel).unwrap_or(&"unknown"))
+            }
+            Self::BrakeDamage(wheel) => {
+                write!(f, "{} brake", WHEEL_NAMES.get(*wheel).unwrap_or(&"unknown"))
+            }
+            Self::FrontLeftWing => write!(f, "front-left wing"),
+            Self::FrontRightWing => write!(f, "front-right wing"),
+            Self::RearWing => write!(f, "rear wing"),
+            Self::Floor => write!(f, "floor"),
+            Self::Diffuser => write!(f, "diffuser"),
+            Self::Sidepod => write!(f, "sidepod"),
+            Self::Gearbox => write!(f, "gearbox"),
+            Self::Engine => write!(f, "engine"),
+            Self::EngineMguhWear => write!(f, "MGU-H wear"),
+            Self::EngineEsWear => write!(f, "ES wear"),
+            Self::EngineCeWear => write!(f, "CE wear"),
+            Self::EngineIceWear => write!(f, "ICE wear"),
+            Self::EngineMgukWear => write!(f, "MGU-K wear"),
+            Self::EngineTcWear => write!(f, "TC wear"),
+        }
+    }
+}
+
+/// Type of a [`DamageEvent`].
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DamageEventKind {
+    /// Damage or wear percentage of a component has gone up.
+    Increased {
+        /// Affected component.
+        component: DamageComponent,
+        /// Previous percentage.
+        from: u8,
+        /// New percentage.
+        to: u8,
+    },
+    /// DRS has failed.
+    DrsFault,
+    /// ERS has failed.
+    ErsFault,
+    /// Engine has blown.
+    EngineBlown,
+    /// Engine has seized.
+    EngineSeized,
+}
+
+impl fmt::Display for DamageEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Increased { component, from, to } => {
+                write!(f, "{} +{}%", component, to - from)
+            }
+            Self::DrsFault => write!(f, "DRS fault"),
+            Self::ErsFault => write!(f, "ERS fault"),
+            Self::EngineBlown => write!(f, "engine blown"),
+            Self::EngineSeized => write!(f, "engine seized"),
+        }
+    }
+}
+
+/// Change in a car's damage detected by a [`DamageMonitor`].
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DamageEvent {
+    /// Index of the damaged car.
+    pub vehicle_index: usize,
+    /// Session timestamp of the car damage packet the change was detected in.
+    pub session_time: f32,
+    /// Lap the car was on at the time.
+    pub lap_num: u8,
+    /// Sector the car was in at the time.
+    pub sector: Sector,
+    /// What has happened.
+    pub kind: DamageEventKind,
+    /// Index of the other car, if a collision involving the damaged car
+    /// was reported shortly before the change was detected.
+    pub collision_with: Option<usize>,
+}
+
+impl fmt::Display for DamageEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Car {}: {} (lap {}, sector {})",
+            self.vehicle_index,
+            self.kind,
+            self.lap_num,
+            self.sector as u8 + 1
+        )?;
+
+        if let Some(other) = self.collision_with {
+            write!(f, " after contact with car {}", other)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+struct CollisionRecord {
+    session_time: f32,
+    vehicle_index: usize,
+    other_vehicle_index: usize,
+}
+
+/// Detects damage events by diffing successive car damage packets.
+///
+/// Collisions are only reported by the 2024 format onwards,
+/// so [`DamageEvent::collision_with`] is always [`None`] in older formats.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DamageMonitor {
+    min_increase: u8,
+    collision_window: f32,
+    previous: Vec<Option<CarDamageData>>,
+    positions: Vec<(u8, Sector)>,
+    collisions: VecDeque<CollisionRecord>,
+    events: Vec<DamageEvent>,
+}
+
+impl Default for DamageMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DamageMonitor {
+    /// Creates a monitor that reports every 1% increase and links
+    /// damage to collisions reported up to 1 second earlier.
+    pub fn new() -> Self {
+        Self {
+            min_increase: DEFAULT_MIN_INCREASE,
+            collision_window: DEFAULT_COLLISION_WINDOW,
+            previous: vec![None; MAX_NUM_CARS],
+            positions: vec![(0, Sector::First); MAX_NUM_CARS],
+            collisions: VecDeque::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Sets the smallest percentage increase that gets reported.
+    pub fn with_min_increase(mut self, percentage: u8) -> Self {
+        self.min_increase = percentage.max(1);
+        self
+    }
+
+    /// Sets how many seconds after a collision damage still gets linked to it.
+    pub fn with_collision_window(mut self, seconds: f32) -> Self {
+        self.collision_window = seconds;
+        self
+    }
+
+    /// Feeds a parsed packet into the monitor and returns newly detected events.
+    /// Only lap data, event and car damage packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<DamageEvent> {
+        let session_time = packet.header.session_time;
+
+        if let Some(laps) = &packet.laps {
+            for (position, lap_data) in self.positions.iter_mut().zip(&laps.data) {
+                *position = (lap_data.current_lap_num, lap_data.sector);
+            }
+        } else if let Some(event) = &packet.event {
+            if let EventDetails::Collision { vehicle_index, other_vehicle_index } =
+                event.details
+            {
+                self.collisions.push_back(CollisionRecord {
+                    session_time,
+                    vehicle_index,
+                    other_vehicle_index,
+                });
+            }
+        } else if let Some(car_damage) = &packet.car_damage {
+            while self.collisions.front().is_some_and(|c| {
+                session_time - c.session_time > self.collision_window
+                    || c.session_time > session_time
+            }) {
+                self.collisions.pop_front();
+            }
+
+            let mut events = Vec::new();
+
+            for (vehicle_index, damage) in car_damage.data.iter().enumerate() {
+                let Some(previous) = self.previous.get_mut(vehicle_index) else {
+                    continue;
+                };
+
+                if let Some(previous) = previous.replace(*damage) {
+                    let (lap_num, sector) = self.positions[vehicle_index];
+                    let collision_with = self.collision_with(vehicle_index);
+
+                    events.extend(diff(&previous, damage, self.min_increase).map(
+                        |kind| DamageEvent {
+                            vehicle_index,
+                            session_time,
+                            lap_num,
+                            sector,
+                            kind,
+                            collision_with,
+                        },
+                    ));
+                }
+            }
+
+            self.events.extend_from_slice(&events);
+            return events;
+        }
+
+        Vec::new()
+    }
+
+    /// Returns all events detected so far.
+    pub fn events(&self) -> &[DamageEvent] {
+        &self.events
+    }
+
+    fn collision_with(&self, vehicle_index: usize) -> Option<usize> {
+        self.collisions.iter().rev().find_map(|c| {
+            if c.vehicle_index == vehicle_index {
+                Some(c.other_vehicle_index)
+            } else if c.other_vehicle_index == vehicle_index {
+                Some(c.vehicle_index)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+fn diff(
+    previous: &CarDamageData,
+    current: &CarDamageData,
+    min_increase: u8,
+) -> impl Iterator<Item = DamageEventKind> {
+    let mut percentages = Vec::with_capacity(22);
+
+    for wheel in 0..4 {
+        percentages.push((
+            DamageComponent::TyreDamage(wheel),
+            previous.tyres_damage[wheel],
+            current.tyres_damage[wheel],
+        ));
+        percentages.push((
+            DamageComponent::BrakeDamage(wheel),
+            previous.brakes_damage[wheel],
+            current.brakes_damage[wheel],
+        ));
+    }
+
+    percentages.extend([
+        (
+            DamageComponent::FrontLeftWing,
+            previous.front_left_wing_damage,
+            current.front_left_wing_damage,
+        ),
+        (
+            DamageComponent::FrontRightWing,
+            previous.front_right_wing_damage,
+            current.front_right_wing_damage,
+        ),
+        (DamageComponent::RearWing, previous.rear_wing_damage, current.rear_wing_damage),
+        (DamageComponent::Floor, previous.floor_damage, current.floor_damage),
+        (DamageComponent::Diffuser, previous.diffuser_damage, current.diffuser_damage),
+        (DamageComponent::Sidepod, previous.sidepod_damage, current.sidepod_damage),
+        (DamageComponent::Gearbox, previous.gearbox_damage, current.gearbox_damage),
+        (DamageComponent::Engine, previous.engine_damage, current.engine_damage),
+        (
+            DamageComponent::EngineMguhWear,
+            previous.engine_mguh_wear,
+            current.engine_mguh_wear,
+        ),
+        (DamageComponent::EngineEsWear, previous.engine_es_wear, current.engine_es_wear),
+        (DamageComponent::EngineCeWear, previous.engine_ce_wear, current.engine_ce_wear),
+        (
+            DamageComponent::EngineIceWear,
+            previous.engine_ice_wear,
+            current.engine_ice_wear,
+        ),
+        (
+            DamageComponent::EngineMgukWear,
+            previous.engine_mguk_wear,
+            current.engine_mguk_wear,
+        ),
+        (DamageComponent::EngineTcWear, previous.engine_tc_wear, current.engine_tc_wear),
+    ]);
+
+    let flags = [
+        (DamageEventKind::DrsFault, previous.drs_fault, current.drs_fault),
+        (DamageEventKind::ErsFault, previous.ers_fault, current.ers_fault),
+        (DamageEventKind::EngineBlown, previous.engine_blown, current.engine_blown),
+        (DamageEventKind::EngineSeized, previous.engine_seized, current.engine_seized),
+    ];
+
+    percentages
+        .into_iter()
+        .filter(move |&(_, from, to)| to >= from.saturating_add(min_increase))
+        .map(|(component, from, to)| DamageEventKind::Increased { component, from, to })
+        .chain(
+            flags.into_iter().filter(|&(_, was, is)| !was && is).map(|(kind, _, _)| kind),
+        )
+}
@@ -0,0 +1,285 @@
+use crate::constants::TrackId;
+use crate::packets::event::EventDetails;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Receiver of the packets a [`SessionDemux`] routes to a single session.
+pub trait PacketSink {
+    /// Receives a packet of the sink's session.
+    fn accept(&mut self, packet: &F1Packet);
+}
+
+impl PacketSink for Vec<F1Packet> {
+    fn accept(&mut self, packet: &F1Packet) {
+        self.push(packet.clone());
+    }
+}
+
+/// Identifiers linking a session to its weekend and season.
+/// They persist across saves, unlike `session_uid`.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionLinks {
+    /// Identifier for season.
+    pub season_link_identifier: u32,
+    /// Identifier for weekend.
+    pub weekend_link_identifier: u32,
+    /// Identifier for session.
+    pub session_link_identifier: u32,
+}
+
+/// What a [`SessionDemux`] knows about a session.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionInfo {
+    /// Unique identifier of the session.
+    pub session_uid: u64,
+    /// Format of the session's packets.
+    pub packet_format: u16,
+    /// Session type, available once a session packet has been received.
+    /// See [`session_type`](mod@crate::constants::session_type) for possible values.
+    pub session_type: Option<u8>,
+    /// Track, available once a session packet has been received.
+    pub track_id: Option<TrackId>,
+    /// Link identifiers, available once a session packet has been received.
+    pub links: Option<SessionLinks>,
+    /// Session timestamp of the [`SessionStarted`](EventDetails::SessionStarted) event.
+    pub started_at: Option<f32>,
+    /// Session timestamp of the [`SessionEnded`](EventDetails::SessionEnded) event.
+    pub ended_at: Option<f32>,
+    /// Session timestamp of the most recent packet.
+    pub last_session_time: f32,
+    /// Number of packets routed to the session.
+    pub num_packets: u64,
+}
+
+impl SessionInfo {
+    fn new(packet: &F1Packet) -> Self {
+        Self {
+            session_uid: packet.header.session_uid,
+            packet_format: packet.header.packet_format,
+            session_type: None,
+            track_id: None,
+            links: None,
+            started_at: None,
+            ended_at: None,
+            last_session_time: packet.header.session_time,
+            num_packets: 0,
+        }
+    }
+}
+
+/// Session tracked by a [`SessionDemux`], along with its sink.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DemuxedSession<S> {
+    /// Session's metadata.
+    pub info: SessionInfo,
+    /// Sink receiving the session's packets.
+    pub sink: S,
+}
+
+/// Session boundary detected by [`SessionDemux::update`].
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SessionBoundary {
+    /// The first packet of a previously unseen `session_uid` has been received,
+    /// e.g. because a session has been restarted or another rig started sending.
+    Discovered(u64),
+    /// The session has sent a [`SessionStarted`](EventDetails::SessionStarted) event.
+    Started(u64),
+    /// The session has sent a [`SessionEnded`](EventDetails::SessionEnded) event.
+    Ended(u64),
+}
+
+/// Node of the tree returned by [`SessionDemux::weekend_tree`]:
+/// all attempts at the same session of a weekend.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionLink {
+    /// Identifier shared by all attempts.
+    pub session_link_identifier: u32,
+    /// `session_uid`s of the attempts, in order of discovery.
+    pub session_uids: Vec<u64>,
+}
+
+/// Node of the tree returned by [`SessionDemux::weekend_tree`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeekendLink {
+    /// Identifier shared by the weekend's sessions.
+    pub weekend_link_identifier: u32,
+    /// Weekend's sessions, in order of discovery.
+    pub sessions: Vec<SessionLink>,
+}
+
+/// Root of the tree returned by [`SessionDemux::weekend_tree`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeasonLink {
+    /// Identifier shared by the season's weekends.
+    pub season_link_identifier: u32,
+    /// Season's weekends, in order of discovery.
+    pub weekends: Vec<WeekendLink>,
+}
+
+/// Routes packets into per-`session_uid` sinks, so that interleaved
+/// packets from multiple rigs sending to the same port, or from a restarted
+/// session, don't get mixed up.
+///
+/// A sink gets created with [`Default`] when a session is discovered.
+/// Finished sessions stay in the demultiplexer until [`remove`](Self::remove)d.
+///
+/// ## Example
+///
+/// ```
+/// use f1_game_packet_parser::demux::{SessionBoundary, SessionDemux};
+/// use f1_game_packet_parser::F1Packet;
+///
+/// fn on_packet(demux: &mut SessionDemux, packet: &F1Packet) {
+///     for boundary in demux.update(packet) {
+///         if let SessionBoundary::Ended(session_uid) = boundary {
+///             let session = demux.remove(session_uid).unwrap();
+///             println!("Session {} had {} packets", session_uid, session.sink.len());
+///         }
+///     }
+/// }
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct SessionDemux<S = Vec<F1Packet>> {
+    sessions: BTreeMap<u64, DemuxedSession<S>>,
+    discovery_order: Vec<u64>,
+}
+
+impl<S> Default for SessionDemux<S> {
+    fn default() -> Self {
+        Self { sessions: BTreeMap::new(), discovery_order: Vec::new() }
+    }
+}
+
+impl<S: PacketSink + Default> SessionDemux<S> {
+    /// Creates an empty demultiplexer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes a packet to its session's sink and returns detected boundaries.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<SessionBoundary> {
+        let session_uid = packet.header.session_uid;
+        let mut boundaries = Vec::new();
+        let discovery_order = &mut self.discovery_order;
+        let session = self.sessions.entry(session_uid).or_insert_with(|| {
+            boundaries.push(SessionBoundary::Discovered(session_uid));
+            discovery_order.push(session_uid);
+
+            DemuxedSession { info: SessionInfo::new(packet), sink: S::default() }
+        });
+        let info = &mut session.info;
+
+        info.last_session_time = packet.header.session_time;
+        info.num_packets += 1;
+
+        if let Some(session_packet) = &packet.session {
+            info.session_type = Some(session_packet.session_type);
+            info.track_id = Some(session_packet.track_id);
+            info.links = Some(SessionLinks {
+                season_link_identifier: session_packet.season_link_identifier,
+                weekend_link_identifier: session_packet.weekend_link_identifier,
+                session_link_identifier: session_packet.session_link_identifier,
+            });
+        } else if let Some(event) = &packet.event {
+            match event.details {
+                EventDetails::SessionStarted => {
+                    info.started_at = Some(packet.header.session_time);
+                    boundaries.push(SessionBoundary::Started(session_uid));
+                }
+                EventDetails::SessionEnded => {
+                    info.ended_at = Some(packet.header.session_time);
+                    boundaries.push(SessionBoundary::Ended(session_uid));
+                }
+                _ => (),
+            }
+        }
+
+        session.sink.accept(packet);
+        boundaries
+    }
+}
+
+impl<S> SessionDemux<S> {
+    /// Returns a session by its `session_uid`.
+    pub fn session(&self, session_uid: u64) -> Option<&DemuxedSession<S>> {
+        self.sessions.get(&session_uid)
+    }
+
+    /// Returns a mutable reference to a session by its `session_uid`.
+    pub fn session_mut(&mut self, session_uid: u64) -> Option<&mut DemuxedSession<S>> {
+        self.sessions.get_mut(&session_uid)
+    }
+
+    /// Returns all tracked sessions in order of discovery.
+    pub fn sessions(&self) -> impl Iterator<Item = &DemuxedSession<S>> {
+        self.discovery_order.iter().filter_map(|uid| self.sessions.get(uid))
+    }
+
+    /// Stops tracking a session and returns it along with its sink.
+    pub fn remove(&mut self, session_uid: u64) -> Option<DemuxedSession<S>> {
+        self.discovery_order.retain(|&uid| uid != session_uid);
+        self.sessions.remove(&session_uid)
+    }
+
+    /// Groups tracked sessions by their link identifiers into a tree of seasons,
+    /// weekends and sessions. Sessions that haven't sent a session packet yet
+    /// are left out.
+    pub fn weekend_tree(&self) -> Vec<SeasonLink> {
+        let mut seasons: Vec<SeasonLink> = Vec::new();
+
+        for info in self.sessions().map(|session| &session.info) {
+            let Some(links) = info.links else {
+                continue;
+            };
+
+            let season = match seasons.iter().position(|season| {
+                season.season_link_identifier == links.season_link_identifier
+            }) {
+                Some(index) => &mut seasons[index],
+                None => {
+                    seasons.push(SeasonLink {
+                        season_link_identifier: links.season_link_identifier,
+                        weekends: Vec::new(),
+                    });
+                    seasons.last_mut().unwrap()
+                }
+            };
+
+            let weekend = match season.weekends.iter().position(|weekend| {
+                weekend.weekend_link_identifier == links.weekend_link_identifier
+            }) {
+                Some(index) => &mut season.weekends[index],
+                None => {
+                    season.weekends.push(WeekendLink {
+                        weekend_link_identifier: links.weekend_link_identifier,
+                        sessions: Vec::new(),
+                    });
+                    season.weekends.last_mut().unwrap()
+                }
+            };
+
+            match weekend.sessions.iter_mut().find(|session| {
+                session.session_link_identifier == links.session_link_identifier
+            }) {
+                Some(session) => session.session_uids.push(info.session_uid),
+                None => weekend.sessions.push(SessionLink {
+                    session_link_identifier: links.session_link_identifier,
+                    session_uids: vec![info.session_uid],
+                }),
+            }
+        }
+
+        seasons
+    }
+}
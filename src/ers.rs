@@ -0,0 +1,241 @@
+use crate::constants::MAX_NUM_CARS;
+use crate::packets::car_status::CarStatusData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum amount of energy the ERS store can hold in Joules.
+pub const MAX_ERS_STORE_ENERGY: f32 = 4_000_000.0;
+
+const DEFAULT_BUCKET_SIZE: f32 = 100.0;
+const DEFAULT_UNUSED_ENERGY_THRESHOLD: f32 = 0.1 * MAX_ERS_STORE_ENERGY;
+
+/// End-of-lap ERS energy budget of a car.
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LapErsSummary {
+    /// Number of the lap.
+    pub lap_num: u8,
+    /// ERS energy store at the end of the lap in Joules.
+    pub store_energy: f32,
+    /// Energy harvested by the MGU-K during the lap in Joules.
+    pub harvested_mguk: f32,
+    /// Energy harvested by the MGU-H during the lap in Joules.
+    pub harvested_mguh: f32,
+    /// Energy deployed during the lap in Joules.
+    pub deployed: f32,
+    /// Energy harvested minus energy deployed during the lap in Joules.
+    pub net_balance: f32,
+    /// Energy deployed in each section of the lap in Joules.
+    /// Entry `i` covers lap distances from `i * bucket_size`
+    /// up to `(i + 1) * bucket_size` metres.
+    pub deployment_by_distance: Vec<f32>,
+    /// Whether the lap ended with more energy left in the store
+    /// than the unused energy threshold.
+    pub unused_energy: bool,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+struct ErsSnapshot {
+    store_energy: f32,
+    harvested_mguk: f32,
+    harvested_mguh: f32,
+    deployed: f32,
+}
+
+impl From<&CarStatusData> for ErsSnapshot {
+    fn from(status: &CarStatusData) -> Self {
+        Self {
+            store_energy: status.ers_store_energy,
+            harvested_mguk: status.ers_harvested_this_lap_mguk,
+            harvested_mguh: status.ers_harvested_this_lap_mguh,
+            deployed: status.ers_deployed_this_lap,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct CarErsState {
+    current_lap_num: u8,
+    lap_distance: f32,
+    latest: Option<ErsSnapshot>,
+    lap_end: Option<ErsSnapshot>,
+    reset_pending: bool,
+    awaiting_reset: bool,
+    deployment_by_distance: Vec<f32>,
+    laps: Vec<LapErsSummary>,
+}
+
+impl CarErsState {
+    fn close_lap(&mut self, unused_energy_threshold: f32) -> Option<LapErsSummary> {
+        let deployment_by_distance = std::mem::take(&mut self.deployment_by_distance);
+        let end = self.lap_end.take().filter(|_| self.current_lap_num > 0)?;
+        let summary = LapErsSummary {
+            lap_num: self.current_lap_num,
+            store_energy: end.store_energy,
+            harvested_mguk: end.harvested_mguk,
+            harvested_mguh: end.harvested_mguh,
+            deployed: end.deployed,
+            net_balance: end.harvested_mguk + end.harvested_mguh - end.deployed,
+            deployment_by_distance,
+            unused_energy: end.store_energy > unused_energy_threshold,
+        };
+
+        self.laps.push(summary.clone());
+        Some(summary)
+    }
+}
+
+/// Tracks ERS energy harvested and deployed by every car lap by lap,
+/// along with where on the lap the energy gets deployed.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ErsTracker {
+    bucket_size: f32,
+    unused_energy_threshold: f32,
+    cars: Vec<CarErsState>,
+    session_time: f32,
+    rewound: bool,
+}
+
+impl Default for ErsTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErsTracker {
+    /// Creates a tracker that splits laps into 100 metre sections
+    /// and flags laps ending with more than 10% of the store's capacity left.
+    pub fn new() -> Self {
+        Self {
+            bucket_size: DEFAULT_BUCKET_SIZE,
+            unused_energy_threshold: DEFAULT_UNUSED_ENERGY_THRESHOLD,
+            cars: vec![CarErsState::default(); MAX_NUM_CARS],
+            session_time: 0.0,
+            rewound: false,
+        }
+    }
+
+    /// Sets the length of lap sections deployment is grouped by in metres.
+    pub fn with_bucket_size(mut self, metres: f32) -> Self {
+        self.bucket_size = metres.max(1.0);
+        self
+    }
+
+    /// Sets the amount of energy (in Joules) a lap has to end with
+    /// to be flagged as having unused energy.
+    pub fn with_unused_energy_threshold(mut self, joules: f32) -> Self {
+        self.unused_energy_threshold = joules;
+        self
+    }
+
+    /// Returns the length of lap sections deployment is grouped by in metres.
+    pub fn bucket_size(&self) -> f32 {
+        self.bucket_size
+    }
+
+    /// Feeds a parsed packet into the tracker and returns summaries of laps
+    /// that have just been completed, along with the indexes of their cars.
+    /// Only lap data and car status packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<(usize, LapErsSummary)> {
+        let mut completed = Vec::new();
+
+        // Counters going back down after a flashback aren't lap changes.
+        if packet.header.session_time < self.session_time {
+            self.rewound = true;
+        }
+
+        self.session_time = packet.header.session_time;
+
+        if let Some(laps) = &packet.laps {
+            for (vehicle_index, lap_data) in laps.data.iter().enumerate() {
+                let Some(car) = self.cars.get_mut(vehicle_index) else {
+                    continue;
+                };
+
+                if lap_data.current_lap_num > car.current_lap_num {
+                    // A lap closed by a counter reset has already been reported.
+                    if car.reset_pending {
+                        car.reset_pending = false;
+                    } else {
+                        if let Some(summary) = car.close_lap(self.unused_energy_threshold)
+                        {
+                            completed.push((vehicle_index, summary));
+                        }
+
+                        car.awaiting_reset = car.current_lap_num > 0;
+                    }
+                } else if lap_data.current_lap_num < car.current_lap_num {
+                    car.laps.retain(|lap| lap.lap_num < lap_data.current_lap_num);
+                    car.deployment_by_distance.clear();
+                    car.lap_end = None;
+                    car.reset_pending = false;
+                    car.awaiting_reset = false;
+                }
+
+                car.current_lap_num = lap_data.current_lap_num;
+                car.lap_distance = lap_data.lap_distance;
+            }
+        } else if let Some(car_status) = &packet.car_status {
+            let rewound = std::mem::take(&mut self.rewound);
+
+            for (vehicle_index, (car, status)) in
+                self.cars.iter_mut().zip(&car_status.data).enumerate()
+            {
+                let snapshot = ErsSnapshot::from(status);
+                let previous = car.latest.unwrap_or_default();
+                let decreased = snapshot.deployed < previous.deployed
+                    || snapshot.harvested_mguk < previous.harvested_mguk
+                    || snapshot.harvested_mguh < previous.harvested_mguh;
+                let reset = decreased && !rewound;
+
+                // Counters reset as soon as the line is crossed, which may happen
+                // before or after the lap number changes. In the former case,
+                // the lap gets closed right away, so that energy deployed
+                // before the lap number changes is booked to the new lap.
+                if reset && car.awaiting_reset {
+                    car.awaiting_reset = false;
+                } else if reset && car.current_lap_num > 0 && !car.reset_pending {
+                    if let Some(summary) = car.close_lap(self.unused_energy_threshold) {
+                        completed.push((vehicle_index, summary));
+                    }
+
+                    car.reset_pending = true;
+                    car.lap_distance = 0.0;
+                }
+
+                let deployed = match (reset, decreased) {
+                    (true, _) => snapshot.deployed,
+                    (false, true) => 0.0,
+                    (false, false) => snapshot.deployed - previous.deployed,
+                };
+
+                if deployed > 0.0 && car.lap_distance >= 0.0 {
+                    let bucket = (car.lap_distance / self.bucket_size) as usize;
+
+                    if car.deployment_by_distance.len() <= bucket {
+                        car.deployment_by_distance.resize(bucket + 1, 0.0);
+                    }
+
+                    car.deployment_by_distance[bucket] += deployed;
+                }
+
+                car.lap_end = Some(snapshot);
+                car.latest = Some(snapshot);
+            }
+        }
+
+        completed
+    }
+
+    /// Returns ERS summaries of all laps completed by a car.
+    pub fn laps(&self, vehicle_index: usize) -> &[LapErsSummary] {
+        self.cars.get(vehicle_index).map_or(&[], |car| &car.laps)
+    }
+
+    /// Returns the laps completed by a car that ended with unused energy.
+    pub fn laps_with_unused_energy(&self, vehicle_index: usize) -> Vec<&LapErsSummary> {
+        self.laps(vehicle_index).iter().filter(|lap| lap.unused_energy).collect()
+    }
+}
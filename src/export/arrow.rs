@@ -0,0 +1,530 @@
+use super::flatten::{flatten, payload, payload_rows, Scalar};
+use crate::json::v1::F1PacketHeader as JsonHeader;
+use crate::F1Packet;
+
+use arrow_array::{
+    new_null_array, ArrayRef, BooleanArray, Float64Array, Int64Array, NullArray,
+    RecordBatch, StringArray, UInt64Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// Converts a stream of packets into Arrow record batches,
+/// with one table per packet type.
+///
+/// Table names match the [`F1Packet`] fields, except for event packets,
+/// which get one table per event code (e.g. `event_coll`).
+/// Columns follow the [versioned JSON representation](crate::json),
+/// so they stay the same within a [`SCHEMA_VERSION`](crate::json::SCHEMA_VERSION).
+/// Every row starts with the header fields, followed by the payload fields:
+///
+/// - Packets with a `data` vector get a row per entry, indexed by
+///   a `vehicle_index` column (`set_index` for tyre sets,
+///   `player_index` for lobby info). Other payload fields are repeated in each row.
+/// - Nested structs and lists of values get flattened into underscore-separated
+///   columns (e.g. `tyres_pressure_0`, `details_vehicle_index`).
+/// - Lists of structs (e.g. marshal zones) are stored as JSON text.
+/// - Enums are stored as their variant names.
+/// - Integers are stored as `Int64`, except for the `UInt64` session UID
+///   and index columns, and floating point numbers as `Float64`.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ArrowConverter {
+    tables: BTreeMap<String, Table>,
+}
+
+impl ArrowConverter {
+    /// Creates a converter with no buffered rows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the packet's rows to its table.
+    pub fn push(&mut self, packet: &F1Packet) -> Result<(), ArrowError> {
+        let Some(payload) = payload(packet).map_err(json_error)? else {
+            return Ok(());
+        };
+        let header = JsonHeader::from(packet.header.clone());
+        let mut header_columns = Vec::new();
+
+        flatten(
+            &serde_json::to_value(&header).map_err(json_error)?,
+            String::new(),
+            &mut header_columns,
+        );
+
+        // Session UIDs don't always fit in an `i64`, keep them in the same column type.
+        for (name, value) in &mut header_columns {
+            if name == "session_uid" {
+                *value = Some(Scalar::U64(packet.header.session_uid));
+            }
+        }
+
+        let table_name = match &payload.event_code {
+            Some(code) => format!("event_{}", code.to_lowercase()),
+            None => payload.packet_type.to_owned(),
+        };
+        let table = self.tables.entry(table_name).or_default();
+
+        for row in payload_rows(payload.packet_type, payload.value) {
+            let mut columns = header_columns.clone();
+
+            if let Some((name, index)) = row.index {
+                columns.push((name.to_owned(), Some(Scalar::U64(index as u64))));
+            }
+
+            columns.extend(row.columns);
+            table.push_row(columns);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of rows buffered for a table.
+    pub fn num_rows(&self, table: &str) -> usize {
+        self.tables.get(table).map_or(0, |table| table.num_rows)
+    }
+
+    /// Returns the names of tables that have buffered rows.
+    pub fn table_names(&self) -> Vec<&str> {
+        self.tables
+            .iter()
+            .filter(|(_, table)| table.num_rows > 0)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Turns rows buffered for a table into a record batch and clears the buffer.
+    /// Returns [`None`] if there are no buffered rows.
+    pub fn finish_table(
+        &mut self,
+        table: &str,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        match self.tables.get_mut(table) {
+            Some(table) if table.num_rows > 0 => {
+                std::mem::take(table).into_batch().map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Turns all buffered rows into record batches and clears the buffers.
+    pub fn finish(&mut self) -> Result<Vec<(String, RecordBatch)>, ArrowError> {
+        let mut batches = Vec::new();
+
+        for (name, table) in std::mem::take(&mut self.tables) {
+            if table.num_rows > 0 {
+                batches.push((name, table.into_batch()?));
+            }
+        }
+
+        Ok(batches)
+    }
+}
+
+/// Error returned when writing Parquet files fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ParquetExportError {
+    /// A batch has a column the table's file has been created without,
+    /// e.g. because an optional nested struct was missing from earlier packets.
+    UnknownColumn {
+        /// Name of the table.
+        table: String,
+        /// Name of the column.
+        column: String,
+    },
+    /// A batch has values of a different type than the table's file in a column.
+    ColumnTypeMismatch {
+        /// Name of the table.
+        table: String,
+        /// Name of the column.
+        column: String,
+        /// Type of the column in the table's file.
+        expected: DataType,
+        /// Type of the column in the batch.
+        actual: DataType,
+    },
+    /// Converting packets into record batches has failed.
+    Arrow(ArrowError),
+    /// Writing a file has failed.
+    Parquet(ParquetError),
+}
+
+impl fmt::Display for ParquetExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParquetExportError::UnknownColumn { table, column } => {
+                write!(f, "Column {} is not part of table {}", column, table)
+            }
+            ParquetExportError::ColumnTypeMismatch {
+                table,
+                column,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Column {} of table {} has type {}, expected {}",
+                    column, table, actual, expected
+                )
+            }
+            ParquetExportError::Arrow(error) => write!(f, "Arrow error: {}", error),
+            ParquetExportError::Parquet(error) => write!(f, "Parquet error: {}", error),
+        }
+    }
+}
+
+impl Error for ParquetExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParquetExportError::Arrow(error) => Some(error),
+            ParquetExportError::Parquet(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ArrowError> for ParquetExportError {
+    fn from(error: ArrowError) -> Self {
+        ParquetExportError::Arrow(error)
+    }
+}
+
+impl From<ParquetError> for ParquetExportError {
+    fn from(error: ParquetError) -> Self {
+        ParquetExportError::Parquet(error)
+    }
+}
+
+impl From<std::io::Error> for ParquetExportError {
+    fn from(error: std::io::Error) -> Self {
+        ParquetExportError::Parquet(error.into())
+    }
+}
+
+/// Writes a stream of packets into a directory of Parquet files,
+/// one per packet format and table produced by [`ArrowConverter`]
+/// (e.g. `2024/car_telemetry.parquet`).
+///
+/// Packet formats get separate files, as their payloads have different columns.
+/// The schema of each file gets fixed by its first batch of rows.
+/// Columns missing from later batches are filled with nulls.
+/// Columns that only had nulls so far get their type from the first batch
+/// with values in them, which means rewriting the rows written before.
+/// Later batches with columns the file doesn't have fail to be written
+/// with [`ParquetExportError::UnknownColumn`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::export::arrow::ParquetExporter;
+/// use f1_game_packet_parser::parse;
+///
+/// let mut exporter = ParquetExporter::new("session").with_batch_size(4096);
+///
+/// # let data = [0u8; 0];
+/// exporter.write_packet(&parse(&data).unwrap())?;
+/// exporter.finish()?;
+/// # Ok::<(), f1_game_packet_parser::export::arrow::ParquetExportError>(())
+/// ```
+pub struct ParquetExporter {
+    directory: PathBuf,
+    batch_size: usize,
+    properties: Option<WriterProperties>,
+    converters: BTreeMap<u16, ArrowConverter>,
+    writers: BTreeMap<(u16, String), (SchemaRef, ArrowWriter<File>)>,
+}
+
+impl ParquetExporter {
+    /// Creates an exporter that writes into the given directory
+    /// in batches of 8192 rows per table.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            properties: None,
+            converters: BTreeMap::new(),
+            writers: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the number of rows buffered for a table before they get written.
+    pub fn with_batch_size(mut self, rows: usize) -> Self {
+        self.batch_size = rows.max(1);
+        self
+    }
+
+    /// Sets Parquet writer properties, such as compression, used for every file.
+    pub fn with_writer_properties(mut self, properties: WriterProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Buffers the packet's rows and writes tables whose buffers are full.
+    pub fn write_packet(&mut self, packet: &F1Packet) -> Result<(), ParquetExportError> {
+        let packet_format = packet.header.packet_format;
+        let converter = self.converters.entry(packet_format).or_default();
+
+        converter.push(packet)?;
+
+        let full: Vec<String> = converter
+            .table_names()
+            .into_iter()
+            .filter(|&table| converter.num_rows(table) >= self.batch_size)
+            .map(str::to_owned)
+            .collect();
+
+        for table in full {
+            let batch =
+                self.converters.get_mut(&packet_format).unwrap().finish_table(&table)?;
+
+            if let Some(batch) = batch {
+                self.write_batch((packet_format, table), batch)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes all buffered rows, closes the files and returns their paths.
+    pub fn finish(mut self) -> Result<Vec<PathBuf>, ParquetExportError> {
+        for (packet_format, mut converter) in std::mem::take(&mut self.converters) {
+            for (table, batch) in converter.finish()? {
+                self.write_batch((packet_format, table), batch)?;
+            }
+        }
+
+        let mut paths = Vec::new();
+
+        for (key, (_, writer)) in self.writers {
+            writer.close()?;
+            paths.push(table_path(&self.directory, &key));
+        }
+
+        Ok(paths)
+    }
+
+    fn write_batch(
+        &mut self,
+        key: (u16, String),
+        batch: RecordBatch,
+    ) -> Result<(), ParquetExportError> {
+        if self.writers.contains_key(&key) {
+            self.upgrade_null_columns(&key, &batch)?;
+
+            let (schema, writer) = self.writers.get_mut(&key).unwrap();
+            let batch = align_batch(&key.1, schema.clone(), batch)?;
+
+            return Ok(writer.write(&batch)?);
+        }
+
+        let path = table_path(&self.directory, &key);
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = ArrowWriter::try_new(
+            File::create(path)?,
+            batch.schema(),
+            self.properties.clone(),
+        )?;
+
+        writer.write(&batch)?;
+        self.writers.insert(key, (batch.schema(), writer));
+        Ok(())
+    }
+
+    /// Gives the table's `Null` columns the type they have in the batch,
+    /// rewriting the file written so far with the upgraded schema.
+    fn upgrade_null_columns(
+        &mut self,
+        key: &(u16, String),
+        batch: &RecordBatch,
+    ) -> Result<(), ParquetExportError> {
+        let schema = &self.writers[key].0;
+        let fields: Vec<Field> = schema
+            .fields()
+            .iter()
+            .map(|field| match batch.column_by_name(field.name()) {
+                Some(column)
+                    if field.data_type().is_null() && !column.data_type().is_null() =>
+                {
+                    field.as_ref().clone().with_data_type(column.data_type().clone())
+                }
+                _ => field.as_ref().clone(),
+            })
+            .collect();
+
+        if fields.iter().zip(schema.fields()).all(|(new, old)| new == old.as_ref()) {
+            return Ok(());
+        }
+
+        let (_, writer) = self.writers.remove(key).unwrap();
+        let path = table_path(&self.directory, key);
+        let previous_path = path.with_extension("parquet.old");
+
+        writer.close()?;
+        std::fs::rename(&path, &previous_path)?;
+
+        let schema = Arc::new(Schema::new(fields));
+        let mut writer = ArrowWriter::try_new(
+            File::create(&path)?,
+            schema.clone(),
+            self.properties.clone(),
+        )?;
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new(File::open(&previous_path)?)?
+                .build()?;
+
+        for previous in reader {
+            writer.write(&align_batch(&key.1, schema.clone(), previous?)?)?;
+        }
+
+        std::fs::remove_file(&previous_path)?;
+        self.writers.insert(key.clone(), (schema, writer));
+        Ok(())
+    }
+}
+
+fn table_path(directory: &Path, (packet_format, table): &(u16, String)) -> PathBuf {
+    directory.join(packet_format.to_string()).join(format!("{}.parquet", table))
+}
+
+fn align_batch(
+    table: &str,
+    schema: SchemaRef,
+    batch: RecordBatch,
+) -> Result<RecordBatch, ParquetExportError> {
+    if let Some(extra) = batch
+        .schema()
+        .fields()
+        .iter()
+        .find(|field| schema.field_with_name(field.name()).is_err())
+    {
+        return Err(ParquetExportError::UnknownColumn {
+            table: table.to_owned(),
+            column: extra.name().clone(),
+        });
+    }
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match batch.column_by_name(field.name()) {
+            Some(column) if column.data_type() == field.data_type() => Ok(column.clone()),
+            Some(column)
+                if column.data_type().is_null()
+                    || column.null_count() == column.len() =>
+            {
+                Ok(new_null_array(field.data_type(), batch.num_rows()))
+            }
+            Some(column) => Err(ParquetExportError::ColumnTypeMismatch {
+                table: table.to_owned(),
+                column: field.name().clone(),
+                expected: field.data_type().clone(),
+                actual: column.data_type().clone(),
+            }),
+            None => Ok(new_null_array(field.data_type(), batch.num_rows())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(RecordBatch::try_new(schema, columns)?)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+struct Column {
+    name: String,
+    values: Vec<Option<Scalar>>,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct Table {
+    num_rows: usize,
+    columns: Vec<Column>,
+    column_indexes: BTreeMap<String, usize>,
+}
+
+impl Table {
+    fn push_row(&mut self, row: Vec<(String, Option<Scalar>)>) {
+        for (name, value) in row {
+            let index = *self.column_indexes.entry(name.clone()).or_insert_with(|| {
+                self.columns.push(Column { name, values: vec![None; self.num_rows] });
+                self.columns.len() - 1
+            });
+
+            self.columns[index].values.push(value);
+        }
+
+        self.num_rows += 1;
+
+        for column in &mut self.columns {
+            column.values.resize(self.num_rows, None);
+        }
+    }
+
+    fn into_batch(self) -> Result<RecordBatch, ArrowError> {
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut arrays = Vec::with_capacity(self.columns.len());
+
+        for column in self.columns {
+            let array = build_array(&column)?;
+
+            fields.push(Field::new(column.name, array.data_type().clone(), true));
+            arrays.push(array);
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+}
+
+fn build_array(column: &Column) -> Result<ArrayRef, ArrowError> {
+    let values = &column.values;
+    let mismatch = |value: &Scalar| {
+        ArrowError::InvalidArgumentError(format!(
+            "Column {} has values of mixed types: {:?}",
+            column.name, value
+        ))
+    };
+
+    macro_rules! typed_array {
+        ($array:ty, $variant:ident) => {{
+            let values = values
+                .iter()
+                .map(|value| match value {
+                    Some(Scalar::$variant(value)) => Ok(Some(value.clone())),
+                    Some(other) => Err(mismatch(other)),
+                    None => Ok(None),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Arc::new(<$array>::from(values)) as ArrayRef
+        }};
+    }
+
+    let array = match values.iter().flatten().next() {
+        None => Arc::new(NullArray::new(values.len())) as ArrayRef,
+        Some(Scalar::Bool(_)) => typed_array!(BooleanArray, Bool),
+        Some(Scalar::I64(_)) => typed_array!(Int64Array, I64),
+        Some(Scalar::U64(_)) => typed_array!(UInt64Array, U64),
+        Some(Scalar::F64(_)) => typed_array!(Float64Array, F64),
+        Some(Scalar::Str(_)) => typed_array!(StringArray, Str),
+    };
+
+    Ok(array)
+}
+
+fn json_error(error: serde_json::Error) -> ArrowError {
+    ArrowError::InvalidArgumentError(error.to_string())
+}
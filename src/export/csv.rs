@@ -0,0 +1,333 @@
+use crate::constants::wheel_index::{FRONT_LEFT, FRONT_RIGHT, REAR_LEFT, REAR_RIGHT};
+use crate::packets::car_status::CarStatusData;
+use crate::packets::car_telemetry::CarTelemetryData;
+use crate::packets::laps::LapData;
+use crate::packets::motion::CarMotionData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display};
+use std::io::{self, Write};
+
+const KEY_COLUMNS: [&str; 3] = ["session_time", "frame_identifier", "vehicle_index"];
+const WHEEL_SUFFIXES: [(usize, &str); 4] =
+    [(REAR_LEFT, "rl"), (REAR_RIGHT, "rr"), (FRONT_LEFT, "fl"), (FRONT_RIGHT, "fr")];
+
+/// Per-car data a [`CsvExporter`] turns into rows.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvSource {
+    /// [`CarTelemetryData`] from car telemetry packets.
+    CarTelemetry,
+    /// [`CarMotionData`] from motion packets.
+    CarMotion,
+    /// [`CarStatusData`] from car status packets.
+    CarStatus,
+    /// [`LapData`] from lap data packets.
+    Laps,
+}
+
+struct Field {
+    channel: &'static str,
+    column: String,
+    value: String,
+}
+
+#[derive(Default)]
+struct Row(Vec<Field>);
+
+impl Row {
+    fn value(&mut self, channel: &'static str, value: impl Display) {
+        self.0.push(Field {
+            channel,
+            column: channel.to_owned(),
+            value: value.to_string(),
+        });
+    }
+
+    fn variant(&mut self, channel: &'static str, value: impl Debug) {
+        self.value(channel, format!("{:?}", value));
+    }
+
+    fn wheels<T: Display>(&mut self, channel: &'static str, values: &[T; 4]) {
+        for (wheel, suffix) in WHEEL_SUFFIXES {
+            self.0.push(Field {
+                channel,
+                column: format!("{}_{}", channel, suffix),
+                value: values[wheel].to_string(),
+            });
+        }
+    }
+
+    fn wheel_variants<T: Debug>(&mut self, channel: &'static str, values: &[T; 4]) {
+        self.wheels(channel, &values.each_ref().map(|value| format!("{:?}", value)));
+    }
+}
+
+/// Writes per-car channels of one kind of packet as CSV rows
+/// keyed by `session_time`, `frame_identifier` and `vehicle_index`.
+///
+/// Wheel arrays are split into one column per wheel, suffixed with
+/// `rl`, `rr`, `fl` or `fr` (e.g. `brakes_temperature_rl`).
+/// Enum values are written as their variant names and booleans as `true`/`false`.
+/// The header row is written along with the first data row.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::export::csv::{CsvExporter, CsvSource};
+/// use f1_game_packet_parser::parse;
+///
+/// let file = std::fs::File::create("telemetry.csv")?;
+/// let mut exporter = CsvExporter::new(file, CsvSource::CarTelemetry)
+///     .with_channels(["speed", "throttle", "brakes_temperature"])
+///     .with_cars([0, 19]);
+///
+/// # let data = [0u8; 0];
+/// exporter.write_packet(&parse(&data).unwrap())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CsvExporter<W: Write> {
+    writer: W,
+    source: CsvSource,
+    channels: Option<Vec<String>>,
+    cars: Option<Vec<usize>>,
+    header_written: bool,
+}
+
+impl<W: Write> CsvExporter<W> {
+    /// Creates an exporter that writes every channel of every car.
+    pub fn new(writer: W, source: CsvSource) -> Self {
+        Self { writer, source, channels: None, cars: None, header_written: false }
+    }
+
+    /// Limits the output to the given channels. Channel names match
+    /// struct field names, so `tyres_pressure` selects all four wheel columns.
+    /// Names that don't match any channel are ignored.
+    pub fn with_channels<I, S>(mut self, channels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Limits the output to the cars with given indexes.
+    pub fn with_cars(mut self, vehicle_indexes: impl IntoIterator<Item = usize>) -> Self {
+        self.cars = Some(vehicle_indexes.into_iter().collect());
+        self
+    }
+
+    /// Writes a row for each selected car in the packet and returns the number of rows.
+    /// Packets of other kinds than the exporter's source are skipped.
+    pub fn write_packet(&mut self, packet: &F1Packet) -> io::Result<usize> {
+        let rows: Vec<Row> = match self.source {
+            CsvSource::CarTelemetry => packet
+                .car_telemetry
+                .as_ref()
+                .map(|p| p.data.iter().map(telemetry_row).collect()),
+            CsvSource::CarMotion => {
+                packet.motion.as_ref().map(|p| p.data.iter().map(motion_row).collect())
+            }
+            CsvSource::CarStatus => packet
+                .car_status
+                .as_ref()
+                .map(|p| p.data.iter().map(status_row).collect()),
+            CsvSource::Laps => {
+                packet.laps.as_ref().map(|p| p.data.iter().map(lap_row).collect())
+            }
+        }
+        .unwrap_or_default();
+
+        let mut written = 0;
+
+        for (vehicle_index, row) in rows.into_iter().enumerate() {
+            if self.cars.as_ref().is_some_and(|cars| !cars.contains(&vehicle_index)) {
+                continue;
+            }
+
+            let fields: Vec<Field> =
+                row.0.into_iter().filter(|field| self.is_selected(field)).collect();
+
+            if !self.header_written {
+                let columns = KEY_COLUMNS
+                    .into_iter()
+                    .chain(fields.iter().map(|field| field.column.as_str()));
+
+                write_line(&mut self.writer, columns)?;
+                self.header_written = true;
+            }
+
+            let keys = [
+                packet.header.session_time.to_string(),
+                packet.header.frame_identifier.to_string(),
+                vehicle_index.to_string(),
+            ];
+            let values = keys
+                .iter()
+                .map(String::as_str)
+                .chain(fields.iter().map(|field| field.value.as_str()));
+
+            write_line(&mut self.writer, values)?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn is_selected(&self, field: &Field) -> bool {
+        self.channels.as_ref().map_or(true, |channels| {
+            channels.iter().any(|c| *c == field.channel || *c == field.column)
+        })
+    }
+}
+
+fn write_line<'a>(
+    writer: &mut impl Write,
+    values: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+
+        writer.write_all(value.as_bytes())?;
+    }
+
+    writer.write_all(b"\n")
+}
+
+fn telemetry_row(data: &CarTelemetryData) -> Row {
+    let mut row = Row::default();
+
+    row.value("speed", data.speed);
+    row.value("throttle", data.throttle);
+    row.value("steer", data.steer);
+    row.value("brake", data.brake);
+    row.value("clutch", data.clutch);
+    row.value("gear", data.gear);
+    row.value("engine_rpm", data.engine_rpm);
+    row.value("drs_enabled", data.drs_enabled);
+    row.value("rev_lights_percent", data.rev_lights_percent);
+    row.value("rev_lights_bit_value", data.rev_lights_bit_value.bits());
+    row.wheels("brakes_temperature", &data.brakes_temperature);
+    row.wheels("tyres_surface_temperature", &data.tyres_surface_temperature);
+    row.wheels("tyres_inner_temperature", &data.tyres_inner_temperature);
+    row.value("engine_temperature", data.engine_temperature);
+    row.wheels("tyres_pressure", &data.tyres_pressure);
+    row.wheel_variants("surface_type", &data.surface_type);
+    row
+}
+
+fn motion_row(data: &CarMotionData) -> Row {
+    let mut row = Row::default();
+
+    row.value("world_position_x", data.world_position_x);
+    row.value("world_position_y", data.world_position_y);
+    row.value("world_position_z", data.world_position_z);
+    row.value("world_velocity_x", data.world_velocity_x);
+    row.value("world_velocity_y", data.world_velocity_y);
+    row.value("world_velocity_z", data.world_velocity_z);
+    row.value("world_forward_dir_x", data.world_forward_dir_x);
+    row.value("world_forward_dir_y", data.world_forward_dir_y);
+    row.value("world_forward_dir_z", data.world_forward_dir_z);
+    row.value("world_right_dir_x", data.world_right_dir_x);
+    row.value("world_right_dir_y", data.world_right_dir_y);
+    row.value("world_right_dir_z", data.world_right_dir_z);
+    row.value("g_force_lateral", data.g_force_lateral);
+    row.value("g_force_longitudinal", data.g_force_longitudinal);
+    row.value("g_force_vertical", data.g_force_vertical);
+    row.value("yaw", data.yaw);
+    row.value("pitch", data.pitch);
+    row.value("roll", data.roll);
+    row
+}
+
+fn status_row(data: &CarStatusData) -> Row {
+    let mut row = Row::default();
+
+    row.variant("traction_control", data.traction_control);
+    row.value("anti_lock_brakes", data.anti_lock_brakes);
+    row.variant("fuel_mix", data.fuel_mix);
+    row.value("front_brake_bias", data.front_brake_bias);
+    row.value("pit_limiter_enabled", data.pit_limiter_enabled);
+    row.value("fuel_in_tank", data.fuel_in_tank);
+    row.value("fuel_capacity", data.fuel_capacity);
+    row.value("fuel_remaining_laps", data.fuel_remaining_laps);
+    row.value("max_rpm", data.max_rpm);
+    row.value("idle_rpm", data.idle_rpm);
+    row.value("max_gears", data.max_gears);
+    row.variant("drs_allowed", data.drs_allowed);
+    row.value("drs_activation_distance", data.drs_activation_distance);
+    row.variant("actual_tyre_compound", data.actual_tyre_compound);
+    row.variant("visual_tyre_compound", data.visual_tyre_compound);
+    row.value("tyres_age_laps", data.tyres_age_laps);
+    row.variant("vehicle_fia_flag", data.vehicle_fia_flag);
+    row.value("engine_power_ice", data.engine_power_ice);
+    row.value("engine_power_mguk", data.engine_power_mguk);
+    row.value("ers_store_energy", data.ers_store_energy);
+    row.variant("ers_deploy_mode", data.ers_deploy_mode);
+    row.value("ers_harvested_this_lap_mguk", data.ers_harvested_this_lap_mguk);
+    row.value("ers_harvested_this_lap_mguh", data.ers_harvested_this_lap_mguh);
+    row.value("ers_deployed_this_lap", data.ers_deployed_this_lap);
+    row.value("network_paused", data.network_paused);
+    row
+}
+
+fn lap_row(data: &LapData) -> Row {
+    let mut row = Row::default();
+
+    row.value("last_lap_time_ms", data.last_lap_time_ms);
+    row.value("current_lap_time_ms", data.current_lap_time_ms);
+    row.value("sector1_time_ms_part", data.sector1_time_ms_part);
+    row.value("sector1_time_minutes_part", data.sector1_time_minutes_part);
+    row.value("sector2_time_ms_part", data.sector2_time_ms_part);
+    row.value("sector2_time_minutes_part", data.sector2_time_minutes_part);
+    row.value("delta_to_car_in_front_ms_part", data.delta_to_car_in_front_ms_part);
+    row.value(
+        "delta_to_car_in_front_minutes_part",
+        data.delta_to_car_in_front_minutes_part,
+    );
+    row.value("delta_to_race_leader_ms", data.delta_to_race_leader_ms);
+    row.value(
+        "delta_to_race_leader_minutes_part",
+        data.delta_to_race_leader_minutes_part,
+    );
+    row.value("lap_distance", data.lap_distance);
+    row.value("total_distance", data.total_distance);
+    row.value("safety_car_delta", data.safety_car_delta);
+    row.value("car_position", data.car_position);
+    row.value("current_lap_num", data.current_lap_num);
+    row.variant("pit_status", data.pit_status);
+    row.value("num_pit_stops", data.num_pit_stops);
+    row.variant("sector", data.sector);
+    row.value("current_lap_invalid", data.current_lap_invalid);
+    row.value("penalties", data.penalties);
+    row.value("total_warnings", data.total_warnings);
+    row.value("corner_cutting_warnings", data.corner_cutting_warnings);
+    row.value("num_unserved_drive_through_pens", data.num_unserved_drive_through_pens);
+    row.value("num_unserved_stop_go_pens", data.num_unserved_stop_go_pens);
+    row.value("grid_position", data.grid_position);
+    row.variant("driver_status", data.driver_status);
+    row.variant("result_status", data.result_status);
+    row.value("pit_lane_timer_active", data.pit_lane_timer_active);
+    row.value("pit_lane_time_in_lane_ms", data.pit_lane_time_in_lane_ms);
+    row.value("pit_stop_timer_ms", data.pit_stop_timer_ms);
+    row.value("pit_stop_should_serve_pen", data.pit_stop_should_serve_pen);
+    row.value("speed_trap_fastest_speed", data.speed_trap_fastest_speed);
+    row.value("speed_trap_fastest_lap", data.speed_trap_fastest_lap);
+    row
+}
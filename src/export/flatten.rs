@@ -0,0 +1,182 @@
+use crate::json::JsonPacket;
+use crate::F1Packet;
+
+use serde_json::Value;
+use std::fmt;
+
+/// Payload of a packet in its [versioned JSON representation](crate::json),
+/// so column names don't depend on the crate's packet types,
+/// along with the name of the payload's [`F1Packet`] field.
+pub(crate) struct Payload {
+    pub(crate) packet_type: &'static str,
+    /// Set only for event packets.
+    pub(crate) event_code: Option<String>,
+    pub(crate) value: Value,
+}
+
+/// Single row of a flattened payload.
+pub(crate) struct FlatRow {
+    /// Name and value of the index column, set for entries of a `data` vector.
+    pub(crate) index: Option<(&'static str, usize)>,
+    pub(crate) columns: Vec<(String, Option<Scalar>)>,
+}
+
+/// Returns [`None`] if none of the packet's payload slots are filled.
+pub(crate) fn payload(packet: &F1Packet) -> Result<Option<Payload>, serde_json::Error> {
+    let Some(json) = JsonPacket::new(packet.clone()) else {
+        return Ok(None);
+    };
+    let packet_type = json.payload.packet_type();
+    let value = match serde_json::to_value(&json.payload)? {
+        Value::Object(mut fields) => fields.remove("payload").unwrap_or_default(),
+        value => value,
+    };
+
+    Ok(Some(Payload {
+        packet_type,
+        event_code: packet
+            .event
+            .as_ref()
+            .filter(|_| packet_type == "event")
+            .map(|event| event.code.clone()),
+        value,
+    }))
+}
+
+/// Splits a payload into rows. Payloads with a `data` vector get a row per entry,
+/// with the other payload fields repeated in each row.
+pub(crate) fn payload_rows(packet_type: &str, payload: Value) -> Vec<FlatRow> {
+    let mut shared_columns = Vec::new();
+
+    let Value::Object(fields) = payload else {
+        flatten(&payload, String::new(), &mut shared_columns);
+        return vec![FlatRow { index: None, columns: shared_columns }];
+    };
+
+    let mut entries = None;
+
+    for (name, value) in fields {
+        match value {
+            Value::Array(items) if name == "data" => entries = Some(items),
+            value => flatten(&value, name, &mut shared_columns),
+        }
+    }
+
+    let Some(entries) = entries else {
+        return vec![FlatRow { index: None, columns: shared_columns }];
+    };
+
+    let index_column = match packet_type {
+        "tyre_sets" => "set_index",
+        "lobby" => "player_index",
+        _ => "vehicle_index",
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let mut columns = shared_columns.clone();
+            flatten(entry, String::new(), &mut columns);
+            FlatRow { index: Some((index_column, index)), columns }
+        })
+        .collect()
+}
+
+/// Column value. All integers in packet payloads fit in an `i64`,
+/// so [`U64`](Self::U64) is only used for columns filled in by exporters,
+/// such as session UIDs.
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) enum Scalar {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::I64(value) => write!(f, "{}", value),
+            Self::U64(value) => write!(f, "{}", value),
+            Self::F64(value) => write!(f, "{}", value),
+            Self::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}_{}", prefix, key)
+    }
+}
+
+/// Returns the variant name and its fields if the value is a serialized enum variant
+/// with fields, i.e. an object with a single `CamelCase` key.
+fn variant(value: &Value) -> Option<(&str, &Value)> {
+    let Value::Object(fields) = value else {
+        return None;
+    };
+
+    match fields.iter().next() {
+        Some((name, inner))
+            if fields.len() == 1
+                && name.starts_with(|c: char| c.is_ascii_uppercase()) =>
+        {
+            Some((name, inner))
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn flatten(
+    value: &Value,
+    prefix: String,
+    columns: &mut Vec<(String, Option<Scalar>)>,
+) {
+    if let Some((name, inner)) = variant(value) {
+        columns.push((prefix.clone(), Some(Scalar::Str(name.to_owned()))));
+
+        match inner {
+            Value::Object(_) | Value::Array(_) => flatten(inner, prefix, columns),
+            inner => flatten(inner, join(&prefix, "value"), columns),
+        }
+
+        return;
+    }
+
+    match value {
+        Value::Null => columns.push((prefix, None)),
+        Value::Bool(value) => columns.push((prefix, Some(Scalar::Bool(*value)))),
+        Value::Number(number) => {
+            let scalar = match (number.as_i64(), number.as_u64()) {
+                (Some(value), _) => Scalar::I64(value),
+                (None, Some(value)) => Scalar::U64(value),
+                _ => Scalar::F64(number.as_f64().unwrap_or(f64::NAN)),
+            };
+
+            columns.push((prefix, Some(scalar)));
+        }
+        Value::String(value) => columns.push((prefix, Some(Scalar::Str(value.clone())))),
+        // Lists of nested values, e.g. marshal zones, don't fit into columns.
+        Value::Array(items)
+            if items.iter().any(|item| item.is_array() || item.is_object()) =>
+        {
+            columns.push((prefix, Some(Scalar::Str(value.to_string()))));
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(item, join(&prefix, &i.to_string()), columns);
+            }
+        }
+        Value::Object(fields) => {
+            for (name, field) in fields {
+                flatten(field, join(&prefix, name), columns);
+            }
+        }
+    }
+}
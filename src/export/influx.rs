@@ -0,0 +1,206 @@
+use super::flatten::{payload, payload_rows, Scalar};
+use crate::constants::TrackId;
+use crate::F1Packet;
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Encodes packets as [InfluxDB line protocol](https://docs.influxdata.com/influxdb/v2/reference/syntax/line-protocol/) points.
+///
+/// Each packet type gets its own measurement named after its [`F1Packet`] field
+/// (e.g. `car_telemetry`), with all event packets going into the `event` measurement.
+/// Packets with per-car data produce a point for each car.
+///
+/// Points are tagged with `session_uid`, `track_id` (once a session packet
+/// has been seen), `car_index` and `driver` (the driver's
+/// [display name](crate::packets::participants::ParticipantsData::display_name),
+/// once a participants packet has been seen). Car tags are also set for
+/// packets and events about a single car, such as session history
+/// or penalties, in which case `vehicle_index` isn't written as a field.
+/// Event points are also tagged with their `code`, and tyre set points
+/// with their `set_index`. Numeric and boolean values
+/// become fields, with nested values flattened into underscore-separated keys
+/// (e.g. `tyres_pressure_0`). Text values, such as enum variants, are left out.
+///
+/// Timestamps are in nanoseconds and get calculated by adding
+/// the header's `session_time` to the wall-clock time the session started at.
+/// When a packet of a new session arrives, the start time, track and driver names
+/// get reset, so the new session's points don't overlap with the previous one's.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct InfluxEncoder {
+    session_uid: Option<u64>,
+    session_start_ns: Option<i64>,
+    track_id: Option<TrackId>,
+    driver_names: Vec<String>,
+}
+
+impl InfluxEncoder {
+    /// Creates an encoder that assumes each session has started
+    /// `session_time` seconds before its first packet got encoded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the wall-clock time the first session started at
+    /// as a Unix timestamp in nanoseconds.
+    pub fn with_session_start(mut self, unix_time_ns: i64) -> Self {
+        self.session_start_ns = Some(unix_time_ns);
+        self
+    }
+
+    /// Encodes a packet as newline-terminated line protocol points.
+    pub fn encode(&mut self, packet: &F1Packet) -> String {
+        if self.session_uid != Some(packet.header.session_uid) {
+            if self.session_uid.is_some() {
+                self.session_start_ns = None;
+                self.track_id = None;
+                self.driver_names.clear();
+            }
+
+            self.session_uid = Some(packet.header.session_uid);
+        }
+
+        let session_time_ns =
+            (f64::from(packet.header.session_time) * 1e9).round() as i64;
+        let session_start_ns = *self.session_start_ns.get_or_insert_with(|| {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+            now.as_nanos() as i64 - session_time_ns
+        });
+        let timestamp = session_start_ns + session_time_ns;
+
+        if let Some(session) = &packet.session {
+            self.track_id = Some(session.track_id);
+        } else if let Some(participants) = &packet.participants {
+            self.driver_names = participants
+                .data
+                .iter()
+                .map(|participant| {
+                    participant.display_name(packet.header.packet_format).into_owned()
+                })
+                .collect();
+        }
+
+        let Ok(Some(payload)) = payload(packet) else {
+            return String::new();
+        };
+        let mut lines = String::new();
+
+        for mut row in payload_rows(payload.packet_type, payload.value) {
+            let mut tags = BTreeMap::new();
+
+            tags.insert("session_uid", packet.header.session_uid.to_string());
+
+            if let Some(track_id) = self.track_id {
+                tags.insert("track_id", format!("{:?}", track_id));
+            }
+
+            if let Some(code) = &payload.event_code {
+                tags.insert("code", code.clone());
+            }
+
+            let vehicle_index = match row.index {
+                Some(("vehicle_index", index)) => Some(index),
+                Some((name, index)) => {
+                    tags.insert(name, index.to_string());
+                    take_vehicle_index(&mut row.columns)
+                }
+                None => take_vehicle_index(&mut row.columns),
+            };
+
+            if let Some(index) = vehicle_index {
+                tags.insert("car_index", index.to_string());
+
+                if let Some(name) =
+                    self.driver_names.get(index).filter(|name| !name.is_empty())
+                {
+                    tags.insert("driver", name.clone());
+                }
+            }
+
+            let mut fields =
+                format!("frame_identifier={}i", packet.header.frame_identifier);
+
+            for (key, value) in &row.columns {
+                if let Some(value) = value.as_ref().and_then(field_value) {
+                    let _ = write!(fields, ",{}={}", escape(key, ", ="), value);
+                }
+            }
+
+            lines.push_str(&escape(payload.packet_type, ", "));
+
+            for (key, value) in tags {
+                let value = escape(&value, ", =");
+
+                // Tags with empty values are invalid.
+                if !value.is_empty() {
+                    let _ = write!(lines, ",{}={}", escape(key, ", ="), value);
+                }
+            }
+
+            let _ = writeln!(lines, " {} {}", fields, timestamp);
+        }
+
+        lines
+    }
+}
+
+/// Removes the column holding the index of the car a payload is about,
+/// such as a session history's or a penalty event's `vehicle_index`,
+/// and returns its value.
+fn take_vehicle_index(columns: &mut Vec<(String, Option<Scalar>)>) -> Option<usize> {
+    let position = columns
+        .iter()
+        .position(|(key, _)| key == "vehicle_index" || key == "details_vehicle_index")?;
+    let index = match columns[position].1 {
+        Some(Scalar::I64(index)) => usize::try_from(index).ok()?,
+        Some(Scalar::U64(index)) => usize::try_from(index).ok()?,
+        _ => return None,
+    };
+
+    columns.remove(position);
+    Some(index)
+}
+
+fn field_value(value: &Scalar) -> Option<String> {
+    match value {
+        Scalar::Bool(value) => Some(value.to_string()),
+        Scalar::I64(value) => Some(format!("{}i", value)),
+        Scalar::U64(value) => {
+            i64::try_from(*value).ok().map(|value| format!("{}i", value))
+        }
+        Scalar::F64(value) if !value.is_finite() => None,
+        Scalar::F64(value) => {
+            // Most values are `f32`s, which get printed more concisely as such.
+            let narrow = *value as f32;
+
+            Some(if f64::from(narrow) == *value {
+                narrow.to_string()
+            } else {
+                value.to_string()
+            })
+        }
+        Scalar::Str(_) => None,
+    }
+}
+
+/// Escapes a measurement, tag key, tag value or field key.
+///
+/// Line protocol can't represent line breaks outside of string field values,
+/// which aren't written, so they're removed, e.g. from a driver's name.
+fn escape(value: &str, special: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\n' | '\r' => continue,
+            '\\' => escaped.push('\\'),
+            c if special.contains(c) => escaped.push('\\'),
+            _ => (),
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
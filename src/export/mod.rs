@@ -0,0 +1,14 @@
+/// Arrow record batch and Parquet export.
+/// Requires the `arrow` feature.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+/// CSV export of per-car channels.
+pub mod csv;
+#[cfg(feature = "json")]
+mod flatten;
+/// InfluxDB line protocol encoding.
+/// Requires the `json` feature.
+#[cfg(feature = "json")]
+pub mod influx;
+/// MoTeC i2 log export.
+pub mod motec;
@@ -0,0 +1,503 @@
+use crate::constants::wheel_index::{FRONT_LEFT, FRONT_RIGHT, REAR_LEFT, REAR_RIGHT};
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+const DEFAULT_SAMPLE_RATE: u16 = 20;
+const HEADER_SIZE: usize = 1762;
+const EVENT_SIZE: usize = 1154;
+const VENUE_SIZE: usize = 1100;
+const VEHICLE_SIZE: usize = 260;
+const CHANNEL_META_SIZE: usize = 124;
+const WHEELS: [(usize, &str); 4] =
+    [(FRONT_LEFT, "FL"), (FRONT_RIGHT, "FR"), (REAR_LEFT, "RL"), (REAR_RIGHT, "RR")];
+
+/// Session details stored in the header of a MoTeC log.
+/// Empty driver and venue names get filled in from participants and session packets.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotecLogInfo {
+    /// Name of the driver.
+    pub driver: String,
+    /// Name of the vehicle.
+    pub vehicle: String,
+    /// Name of the track.
+    pub venue: String,
+    /// Name of the event.
+    pub event: String,
+    /// Name of the session.
+    pub session: String,
+    /// Short comment shown in the log's details.
+    pub comment: String,
+    /// Date the log was recorded on in the `DD/MM/YYYY` format.
+    pub date: String,
+    /// Time the log was recorded at in the `HH:MM:SS` format.
+    pub time: String,
+}
+
+/// Lap completed by the player's car.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotecLap {
+    /// Number of the lap.
+    pub lap_num: u8,
+    /// Seconds from the start of the log to the moment the lap was completed.
+    pub end_time: f32,
+    /// Lap time in milliseconds.
+    pub lap_time_ms: u32,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+struct Channel {
+    name: String,
+    short_name: String,
+    unit: &'static str,
+    samples: Vec<(f32, f32)>,
+}
+
+/// Records the player car's channels and writes them as a MoTeC i2 log,
+/// made up of an `.ld` data file and an `.ldx` file holding lap markers.
+///
+/// Packets of different types arrive at different rates, so every channel
+/// gets resampled at a common sample rate when the log is written.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::export::motec::MotecLogger;
+/// use f1_game_packet_parser::parse;
+/// use std::fs::File;
+///
+/// let mut logger = MotecLogger::new().with_sample_rate(60);
+///
+/// # let data = [0u8; 0];
+/// logger.update(&parse(&data).unwrap());
+/// logger.write_ld(File::create("session.ld")?)?;
+/// logger.write_ldx(File::create("session.ldx")?)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct MotecLogger {
+    sample_rate: u16,
+    info: MotecLogInfo,
+    start_time: Option<f32>,
+    last_time: f32,
+    current_lap_num: u8,
+    channels: Vec<Channel>,
+    laps: Vec<MotecLap>,
+}
+
+impl Default for MotecLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MotecLogger {
+    /// Creates a logger that samples channels at 20 Hz.
+    pub fn new() -> Self {
+        Self {
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            info: MotecLogInfo::default(),
+            start_time: None,
+            last_time: 0.0,
+            current_lap_num: 0,
+            channels: Vec::new(),
+            laps: Vec::new(),
+        }
+    }
+
+    /// Sets the rate in Hz all channels get sampled at.
+    pub fn with_sample_rate(mut self, hz: u16) -> Self {
+        self.sample_rate = hz.max(1);
+        self
+    }
+
+    /// Sets session details stored in the log's header.
+    pub fn with_info(mut self, info: MotecLogInfo) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Returns session details stored in the log's header.
+    pub fn info(&self) -> &MotecLogInfo {
+        &self.info
+    }
+
+    /// Returns laps completed by the player's car so far.
+    pub fn laps(&self) -> &[MotecLap] {
+        &self.laps
+    }
+
+    /// Feeds a parsed packet into the logger. Only motion, session, lap data,
+    /// participants, car telemetry and extended motion packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) {
+        let player = packet.header.player_car_index;
+        let session_time = packet.header.session_time;
+
+        if let Some(session) = &packet.session {
+            if self.info.venue.is_empty() {
+                self.info.venue = format!("{:?}", session.track_id);
+            }
+
+            return;
+        } else if let Some(participants) = &packet.participants {
+            if let Some(participant) =
+                participants.data.get(player).filter(|_| self.info.driver.is_empty())
+            {
+                self.info.driver.clone_from(&participant.name);
+            }
+
+            return;
+        }
+
+        let start_time = *self.start_time.get_or_insert(session_time);
+        let time = session_time - start_time;
+
+        if time < self.last_time {
+            for channel in &mut self.channels {
+                channel.samples.retain(|&(t, _)| t < time);
+            }
+
+            self.laps.retain(|lap| lap.end_time < time);
+            self.current_lap_num = self.laps.last().map_or(0, |lap| lap.lap_num + 1);
+        }
+
+        self.last_time = time;
+
+        if let Some(laps) = &packet.laps {
+            let Some(lap_data) = laps.data.get(player) else {
+                return;
+            };
+
+            if self.current_lap_num > 0 && lap_data.current_lap_num > self.current_lap_num
+            {
+                self.laps.push(MotecLap {
+                    lap_num: self.current_lap_num,
+                    end_time: (time - lap_data.current_lap_time_ms as f32 / 1000.0)
+                        .max(0.0),
+                    lap_time_ms: lap_data.last_lap_time_ms,
+                });
+            }
+
+            self.current_lap_num = lap_data.current_lap_num;
+            self.record(
+                time,
+                "Lap Number",
+                "Lap",
+                "",
+                f32::from(lap_data.current_lap_num),
+            );
+            self.record(time, "Lap Distance", "LapDist", "m", lap_data.lap_distance);
+            self.record(
+                time,
+                "Lap Time Running",
+                "LapTime",
+                "s",
+                lap_data.current_lap_time_ms as f32 / 1000.0,
+            );
+        } else if let Some(telemetry) = &packet.car_telemetry {
+            let Some(data) = telemetry.data.get(player) else {
+                return;
+            };
+
+            self.record(time, "Ground Speed", "Speed", "km/h", f32::from(data.speed));
+            self.record(time, "Throttle Pos", "Throttle", "%", data.throttle * 100.0);
+            self.record(time, "Brake Pos", "Brake", "%", data.brake * 100.0);
+            self.record(time, "Steering Pos", "Steer", "%", data.steer * 100.0);
+            self.record(time, "Gear", "Gear", "", f32::from(data.gear));
+            self.record(time, "Engine RPM", "RPM", "rpm", f32::from(data.engine_rpm));
+
+            for (wheel, suffix) in WHEELS {
+                self.record_wheel(
+                    time,
+                    ("Tyre Temp Surface", "TTS", suffix),
+                    "C",
+                    f32::from(data.tyres_surface_temperature[wheel]),
+                );
+                self.record_wheel(
+                    time,
+                    ("Tyre Temp Inner", "TTI", suffix),
+                    "C",
+                    f32::from(data.tyres_inner_temperature[wheel]),
+                );
+                self.record_wheel(
+                    time,
+                    ("Tyre Pres", "TP", suffix),
+                    "psi",
+                    data.tyres_pressure[wheel],
+                );
+                self.record_wheel(
+                    time,
+                    ("Brake Temp", "BT", suffix),
+                    "C",
+                    f32::from(data.brakes_temperature[wheel]),
+                );
+            }
+        } else if let Some(motion) = &packet.motion {
+            let Some(data) = motion.data.get(player) else {
+                return;
+            };
+
+            self.record(time, "G Force Lat", "GLat", "G", data.g_force_lateral);
+            self.record(time, "G Force Long", "GLong", "G", data.g_force_longitudinal);
+            self.record(time, "G Force Vert", "GVert", "G", data.g_force_vertical);
+        }
+
+        // Extended motion data gets sent as a part of motion packets in the 2022 format.
+        let motion_ex = packet
+            .motion_ex
+            .as_ref()
+            .or_else(|| packet.motion.as_ref().and_then(|m| m.motion_ex.as_ref()));
+
+        if let Some(motion_ex) = motion_ex {
+            for (wheel, suffix) in WHEELS {
+                self.record_wheel(
+                    time,
+                    ("Susp Pos", "SP", suffix),
+                    "mm",
+                    motion_ex.suspension_position[wheel],
+                );
+                self.record_wheel(
+                    time,
+                    ("Susp Vel", "SV", suffix),
+                    "mm/s",
+                    motion_ex.suspension_velocity[wheel],
+                );
+                self.record_wheel(
+                    time,
+                    ("Wheel Speed", "WS", suffix),
+                    "m/s",
+                    motion_ex.wheel_speed[wheel],
+                );
+            }
+        }
+    }
+
+    /// Writes the recorded channels as an `.ld` file.
+    pub fn write_ld(&self, mut writer: impl Write) -> io::Result<()> {
+        let channels: Vec<&Channel> =
+            self.channels.iter().filter(|c| !c.samples.is_empty()).collect();
+        let num_samples = (self.last_time * f32::from(self.sample_rate)) as usize + 1;
+        let event_ptr = HEADER_SIZE;
+        let venue_ptr = event_ptr + EVENT_SIZE;
+        let vehicle_ptr = venue_ptr + VENUE_SIZE;
+        let meta_ptr = vehicle_ptr + VEHICLE_SIZE;
+        let data_ptr = meta_ptr + channels.len() * CHANNEL_META_SIZE;
+        let mut buf =
+            LdBuffer(Vec::with_capacity(data_ptr + channels.len() * num_samples * 4));
+
+        buf.u32(0x40);
+        buf.pad(4);
+        buf.u32(if channels.is_empty() { 0 } else { meta_ptr as u32 });
+        buf.u32(if channels.is_empty() { 0 } else { data_ptr as u32 });
+        buf.pad(20);
+        buf.u32(event_ptr as u32);
+        buf.pad(24);
+        buf.u16(1);
+        buf.u16(0x4240);
+        buf.u16(0xf);
+        buf.u32(0x1f44);
+        buf.str("ADL", 8);
+        buf.u16(420);
+        buf.u16(0xadb0);
+        buf.u32(channels.len() as u32);
+        buf.pad(4);
+        buf.str(&self.info.date, 16);
+        buf.pad(16);
+        buf.str(&self.info.time, 16);
+        buf.pad(16);
+        buf.str(&self.info.driver, 64);
+        buf.str(&self.info.vehicle, 64);
+        buf.pad(64);
+        buf.str(&self.info.venue, 64);
+        buf.pad(64);
+        buf.pad(1024);
+        buf.u32(0xc81a4);
+        buf.pad(66);
+        buf.str(&self.info.comment, 64);
+        buf.pad(126);
+
+        buf.str(&self.info.event, 64);
+        buf.str(&self.info.session, 64);
+        buf.str(&self.info.comment, 1024);
+        buf.u16(venue_ptr as u16);
+
+        buf.str(&self.info.venue, 64);
+        buf.pad(1034);
+        buf.u16(vehicle_ptr as u16);
+
+        buf.str(&self.info.vehicle, 64);
+        buf.pad(128);
+        buf.u32(0);
+        buf.str("", 32);
+        buf.str("", 32);
+
+        for (i, channel) in channels.iter().enumerate() {
+            let prev = if i == 0 { 0 } else { meta_ptr + (i - 1) * CHANNEL_META_SIZE };
+            let next = if i + 1 == channels.len() {
+                0
+            } else {
+                meta_ptr + (i + 1) * CHANNEL_META_SIZE
+            };
+
+            buf.u32(prev as u32);
+            buf.u32(next as u32);
+            buf.u32((data_ptr + i * num_samples * 4) as u32);
+            buf.u32(num_samples as u32);
+            buf.u16(0x2ee1 + i as u16);
+            // Data type: 32-bit float.
+            buf.u16(0x07);
+            buf.u16(4);
+            buf.u16(self.sample_rate);
+            // Shift, multiplier, scale and decimal places applied to raw values.
+            buf.u16(0);
+            buf.u16(1);
+            buf.u16(1);
+            buf.u16(0);
+            buf.str(&channel.name, 32);
+            buf.str(&channel.short_name, 8);
+            buf.str(channel.unit, 12);
+            buf.pad(40);
+        }
+
+        for channel in channels {
+            let mut samples = channel.samples.iter().peekable();
+            let mut value = channel.samples[0].1;
+
+            for i in 0..num_samples {
+                let time = i as f32 / f32::from(self.sample_rate);
+
+                while let Some(&(_, next)) = samples.next_if(|&&(t, _)| t <= time) {
+                    value = next;
+                }
+
+                buf.0.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        writer.write_all(&buf.0)
+    }
+
+    /// Writes lap markers and lap statistics as an `.ldx` file,
+    /// which MoTeC i2 reads alongside the `.ld` file with the same name.
+    pub fn write_ldx(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, r#"<?xml version="1.0"?>"#)?;
+        writeln!(
+            writer,
+            r#"<LDXFile Locale="English_United Kingdom.1252" DefaultLocale="C" Version="1.6">"#
+        )?;
+        writeln!(writer, " <Layers>")?;
+        writeln!(writer, "  <Layer>")?;
+        writeln!(writer, "   <MarkerBlock>")?;
+        writeln!(writer, r#"    <MarkerGroup Name="Beacons" Index="3">"#)?;
+
+        for (i, lap) in self.laps.iter().enumerate() {
+            writeln!(
+                writer,
+                r#"     <Marker Version="100" ClassName="BCN" Name="Manual.{}" Flags="77" Time="{}"/>"#,
+                i + 1,
+                (f64::from(lap.end_time) * 1e6).round()
+            )?;
+        }
+
+        writeln!(writer, "    </MarkerGroup>")?;
+        writeln!(writer, "   </MarkerBlock>")?;
+        writeln!(writer, "   <RangeBlock/>")?;
+        writeln!(writer, "  </Layer>")?;
+        writeln!(writer, "  <Details>")?;
+        writeln!(writer, r#"   <String Id="Total Laps" Value="{}"/>"#, self.laps.len())?;
+
+        if let Some(fastest) = self
+            .laps
+            .iter()
+            .filter(|lap| lap.lap_time_ms > 0)
+            .min_by_key(|lap| lap.lap_time_ms)
+        {
+            let ms = fastest.lap_time_ms;
+
+            writeln!(
+                writer,
+                r#"   <String Id="Fastest Time" Value="{}:{:02}.{:03}"/>"#,
+                ms / 60_000,
+                ms / 1000 % 60,
+                ms % 1000
+            )?;
+            writeln!(
+                writer,
+                r#"   <String Id="Fastest Lap" Value="{}"/>"#,
+                fastest.lap_num
+            )?;
+        }
+
+        writeln!(writer, "  </Details>")?;
+        writeln!(writer, " </Layers>")?;
+        writeln!(writer, "</LDXFile>")
+    }
+
+    fn record(
+        &mut self,
+        time: f32,
+        name: &str,
+        short_name: &str,
+        unit: &'static str,
+        value: f32,
+    ) {
+        let index = match self.channels.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                self.channels.push(Channel {
+                    name: name.to_owned(),
+                    short_name: short_name.to_owned(),
+                    unit,
+                    samples: Vec::new(),
+                });
+                self.channels.len() - 1
+            }
+        };
+
+        self.channels[index].samples.push((time, value));
+    }
+
+    fn record_wheel(
+        &mut self,
+        time: f32,
+        (name, short_name, suffix): (&str, &str, &str),
+        unit: &'static str,
+        value: f32,
+    ) {
+        self.record(
+            time,
+            &format!("{} {}", name, suffix),
+            &format!("{}{}", short_name, suffix),
+            unit,
+            value,
+        );
+    }
+}
+
+struct LdBuffer(Vec<u8>);
+
+impl LdBuffer {
+    fn u16(&mut self, value: u16) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn pad(&mut self, len: usize) {
+        self.0.resize(self.0.len() + len, 0);
+    }
+
+    /// Writes a null-padded string, truncated to leave room for at least one null byte.
+    fn str(&mut self, value: &str, len: usize) {
+        let bytes = &value.as_bytes()[..value.len().min(len - 1)];
+
+        self.0.extend_from_slice(bytes);
+        self.pad(len - bytes.len());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::constants::{MarshalZoneFlag, Sector, VehicleFiaFlag};
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Marshal zone mapped onto the track.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlagZone {
+    /// Index of the zone in the session packet's list of marshal zones.
+    pub zone_index: usize,
+    /// Distance around the lap the zone starts at in metres.
+    pub start_distance: f32,
+    /// Distance around the lap the zone ends at in metres.
+    /// Lower than the start distance if the zone spans the start/finish line.
+    pub end_distance: f32,
+    /// Sector the zone starts in.
+    /// Set to [`None`] until cars have been seen crossing sector boundaries.
+    pub sector: Option<Sector>,
+    /// Flag that's currently being waved in the zone.
+    pub flag: MarshalZoneFlag,
+}
+
+impl FlagZone {
+    /// Returns whether a yellow or red flag is being waved in the zone.
+    pub fn is_yellow(&self) -> bool {
+        matches!(self.flag, MarshalZoneFlag::Yellow | MarshalZoneFlag::Red)
+    }
+
+    /// Returns whether the given distance around the lap is within the zone.
+    pub fn contains(&self, lap_distance: f32) -> bool {
+        if self.start_distance < self.end_distance {
+            (self.start_distance..self.end_distance).contains(&lap_distance)
+        } else {
+            lap_distance >= self.start_distance || lap_distance < self.end_distance
+        }
+    }
+}
+
+/// Type of a [`FlagEvent`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FlagEventKind {
+    /// Flag waved in a marshal zone has changed.
+    Zone {
+        /// Zone with its new flag.
+        zone: FlagZone,
+        /// Flag that was waved before.
+        previous: MarshalZoneFlag,
+    },
+    /// Flag shown to a car has changed.
+    Car {
+        /// Index of the car.
+        vehicle_index: usize,
+        /// New flag.
+        flag: VehicleFiaFlag,
+        /// Flag that was shown before.
+        previous: VehicleFiaFlag,
+    },
+}
+
+/// Flag change reported by [`FlagMonitor::update`].
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlagEvent {
+    /// Session timestamp of the packet the change was detected in.
+    pub session_time: f32,
+    /// What has changed.
+    pub kind: FlagEventKind,
+}
+
+impl fmt::Display for FlagEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            FlagEventKind::Zone { zone, previous } => {
+                write!(
+                    f,
+                    "Zone {} ({:.0}-{:.0} m",
+                    zone.zone_index, zone.start_distance, zone.end_distance
+                )?;
+
+                if let Some(sector) = zone.sector {
+                    write!(f, ", sector {}", sector as u8 + 1)?;
+                }
+
+                write!(f, "): {:?} -> {:?}", previous, zone.flag)
+            }
+            FlagEventKind::Car { vehicle_index, flag, previous } => {
+                write!(f, "Car {}: {:?} -> {:?}", vehicle_index, previous, flag)
+            }
+        }
+    }
+}
+
+/// Yellow zone found by [`FlagMonitor::approaching_yellow`].
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ApproachingZone {
+    /// Zone the car is approaching.
+    pub zone: FlagZone,
+    /// Distance to the start of the zone in metres, 0 if the car is already in it.
+    pub distance: f32,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+struct CarState {
+    lap_distance: Option<f32>,
+    sector: Option<Sector>,
+    fia_flag: Option<VehicleFiaFlag>,
+}
+
+/// Keeps track of flags waved in marshal zones and shown to each car.
+///
+/// Zones are mapped onto lap distance ranges using the session's track length.
+/// The game doesn't send sector boundaries, so they're learned
+/// from lap distances at which cars move into the next sector,
+/// and zones are assigned to sectors once those are known.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct FlagMonitor {
+    track_length: f32,
+    zones: Vec<FlagZone>,
+    sector_starts: [Option<f32>; 2],
+    cars: Vec<CarState>,
+}
+
+impl FlagMonitor {
+    /// Creates a monitor with no known zones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a parsed packet into the monitor and returns flag changes.
+    /// Only session, lap data and car status packets are taken into account.
+    /// The first packet of each kind sets the initial state without reporting changes.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<FlagEvent> {
+        let session_time = packet.header.session_time;
+        let mut events = Vec::new();
+
+        if let Some(session) = &packet.session {
+            let track_length = f32::from(session.track_length);
+            let known_layout = self.track_length == track_length
+                && self.zones.len() == session.marshal_zones.len();
+            let mut zones: Vec<FlagZone> = session
+                .marshal_zones
+                .iter()
+                .enumerate()
+                .map(|(zone_index, marshal_zone)| {
+                    let starts =
+                        session.marshal_zones.iter().map(|other| other.zone_start);
+                    let end = starts
+                        .clone()
+                        .filter(|&start| start > marshal_zone.zone_start)
+                        .reduce(f32::min)
+                        .or_else(|| starts.reduce(f32::min))
+                        .unwrap_or(marshal_zone.zone_start);
+
+                    FlagZone {
+                        zone_index,
+                        start_distance: marshal_zone.zone_start * track_length,
+                        end_distance: end * track_length,
+                        sector: None,
+                        flag: marshal_zone.zone_flag,
+                    }
+                })
+                .collect();
+
+            if self.track_length > 0.0 && self.track_length != track_length {
+                self.sector_starts = [None; 2];
+            }
+
+            for zone in &mut zones {
+                zone.sector = sector_at(self.sector_starts, zone.start_distance);
+            }
+
+            if known_layout {
+                for (zone, previous) in zones.iter().zip(&self.zones) {
+                    if zone.flag != previous.flag {
+                        events.push(FlagEvent {
+                            session_time,
+                            kind: FlagEventKind::Zone {
+                                zone: *zone,
+                                previous: previous.flag,
+                            },
+                        });
+                    }
+                }
+            }
+
+            self.track_length = track_length;
+            self.zones = zones;
+        } else if let Some(laps) = &packet.laps {
+            self.ensure_cars(laps.data.len());
+
+            for (car, lap_data) in self.cars.iter_mut().zip(&laps.data) {
+                let boundary = match (car.sector, lap_data.sector) {
+                    (Some(Sector::First), Sector::Second) => Some(0),
+                    (Some(Sector::Second), Sector::Third) => Some(1),
+                    _ => None,
+                };
+
+                if let Some(boundary) = boundary.filter(|_| lap_data.lap_distance > 0.0) {
+                    let start = &mut self.sector_starts[boundary];
+
+                    *start = Some(start.map_or(lap_data.lap_distance, |start| {
+                        start.min(lap_data.lap_distance)
+                    }));
+                }
+
+                car.lap_distance = Some(lap_data.lap_distance);
+                car.sector = Some(lap_data.sector);
+            }
+
+            for zone in &mut self.zones {
+                zone.sector = sector_at(self.sector_starts, zone.start_distance);
+            }
+        } else if let Some(car_status) = &packet.car_status {
+            self.ensure_cars(car_status.data.len());
+
+            for (vehicle_index, (car, status)) in
+                self.cars.iter_mut().zip(&car_status.data).enumerate()
+            {
+                let flag = status.vehicle_fia_flag;
+
+                if let Some(previous) = car.fia_flag.replace(flag).filter(|&p| p != flag)
+                {
+                    events.push(FlagEvent {
+                        session_time,
+                        kind: FlagEventKind::Car { vehicle_index, flag, previous },
+                    });
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Returns marshal zones of the current session.
+    pub fn zones(&self) -> &[FlagZone] {
+        &self.zones
+    }
+
+    /// Returns distances around the lap in metres at which
+    /// the second and third sectors start, if they've been learned yet.
+    pub fn sector_starts(&self) -> [Option<f32>; 2] {
+        self.sector_starts
+    }
+
+    /// Returns the most severe flag waved in any zone starting in the given sector.
+    pub fn sector_flag(&self, sector: Sector) -> MarshalZoneFlag {
+        self.zones
+            .iter()
+            .filter(|zone| zone.sector == Some(sector))
+            .map(|zone| zone.flag)
+            .max()
+            .unwrap_or(MarshalZoneFlag::Unknown)
+    }
+
+    /// Returns the flag currently shown to the given car,
+    /// if a car status packet has been received.
+    pub fn car_flag(&self, vehicle_index: usize) -> Option<VehicleFiaFlag> {
+        self.cars.get(vehicle_index).and_then(|car| car.fia_flag)
+    }
+
+    /// Returns the nearest yellow or red zone the given car is in or is
+    /// approaching within `lookahead` metres, based on its latest lap distance.
+    pub fn approaching_yellow(
+        &self,
+        vehicle_index: usize,
+        lookahead: f32,
+    ) -> Option<ApproachingZone> {
+        if self.track_length <= 0.0 {
+            return None;
+        }
+
+        let lap_distance = self
+            .cars
+            .get(vehicle_index)
+            .and_then(|car| car.lap_distance)?
+            .rem_euclid(self.track_length);
+
+        self.zones
+            .iter()
+            .filter(|zone| zone.is_yellow())
+            .map(|zone| {
+                let distance = if zone.contains(lap_distance) {
+                    0.0
+                } else {
+                    (zone.start_distance - lap_distance).rem_euclid(self.track_length)
+                };
+
+                ApproachingZone { zone: *zone, distance }
+            })
+            .filter(|approaching| approaching.distance <= lookahead)
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    fn ensure_cars(&mut self, num_cars: usize) {
+        if self.cars.len() < num_cars {
+            self.cars.resize(num_cars, CarState::default());
+        }
+    }
+}
+
+fn sector_at(sector_starts: [Option<f32>; 2], lap_distance: f32) -> Option<Sector> {
+    let [second, third] = sector_starts;
+
+    match (second?, third?) {
+        (_, third) if lap_distance >= third => Some(Sector::Third),
+        (second, _) if lap_distance >= second => Some(Sector::Second),
+        _ => Some(Sector::First),
+    }
+}
@@ -0,0 +1,242 @@
+use crate::constants::{FuelMix, MAX_NUM_CARS};
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const DEFAULT_ROLLING_WINDOW: usize = 3;
+
+/// Fuel burned over the course of a single lap.
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LapFuelUsage {
+    /// Number of the lap.
+    pub lap_num: u8,
+    /// Total fuel burned on this lap.
+    pub fuel_used: f32,
+    /// Fuel burned on this lap, split by the fuel mix in use at the time.
+    pub fuel_used_by_mix: BTreeMap<FuelMix, f32>,
+}
+
+/// How much fuel a car has to save to make it to the chequered flag.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiftAndCoastTarget {
+    /// Total amount of fuel to save.
+    pub fuel_to_save: f32,
+    /// Amount of fuel to save on each remaining lap.
+    pub saving_per_lap: f32,
+    /// Fuel burn per lap that gets the car to the end of the race.
+    pub target_burn_per_lap: f32,
+}
+
+/// Projection of a car's fuel level at the chequered flag.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuelProjection {
+    /// Index of the car this projection refers to.
+    pub vehicle_index: usize,
+    /// Current fuel mass.
+    pub fuel_in_tank: f32,
+    /// Rolling average of fuel burned per lap.
+    pub average_burn_per_lap: f32,
+    /// Number of laps (including the rest of the current one) left in the race.
+    pub laps_remaining: f32,
+    /// Predicted fuel mass at the chequered flag. Negative if the car won't make it.
+    pub fuel_at_finish: f32,
+    /// Game's own estimate of fuel remaining in terms of laps.
+    pub game_fuel_remaining_laps: f32,
+    /// Fuel saving target. Set to [`None`] if the car has enough fuel.
+    pub lift_and_coast: Option<LiftAndCoastTarget>,
+}
+
+/// Warning emitted by [`FuelAnalyser::update`] when a car's projected
+/// fuel at the chequered flag drops below zero.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuelWarning {
+    /// Number of the lap the warning was raised on.
+    pub lap_num: u8,
+    /// Projection that's triggered the warning.
+    pub projection: FuelProjection,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct CarFuelState {
+    current_lap_num: u8,
+    lap_fraction: f32,
+    /// Whether the current lap has been observed from its start,
+    /// so the fuel burned on it covers the whole lap.
+    lap_start_seen: bool,
+    fuel_in_tank: Option<f32>,
+    fuel_remaining_laps: f32,
+    fuel_mix: Option<FuelMix>,
+    current_lap: BTreeMap<FuelMix, f32>,
+    laps: Vec<LapFuelUsage>,
+    warned: bool,
+}
+
+/// Tracks fuel burned per lap and projects whether each car
+/// has enough fuel to make it to the end of the race.
+///
+/// Laps that have been joined mid-way, either because the analyser
+/// got attached during them or because a flashback has rewound into them,
+/// aren't recorded, as their fuel usage doesn't cover the whole lap.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FuelAnalyser {
+    rolling_window: usize,
+    total_laps: Option<u8>,
+    track_length: Option<f32>,
+    cars: Vec<CarFuelState>,
+}
+
+impl Default for FuelAnalyser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuelAnalyser {
+    /// Creates an analyser that averages fuel burn over the last 3 laps.
+    pub fn new() -> Self {
+        Self::with_rolling_window(DEFAULT_ROLLING_WINDOW)
+    }
+
+    /// Creates an analyser that averages fuel burn over the given number of laps.
+    pub fn with_rolling_window(laps: usize) -> Self {
+        Self {
+            rolling_window: laps.max(1),
+            total_laps: None,
+            track_length: None,
+            cars: vec![CarFuelState::default(); MAX_NUM_CARS],
+        }
+    }
+
+    /// Feeds a parsed packet into the analyser and returns warnings
+    /// for cars whose projected fuel at the chequered flag has just gone negative.
+    /// Only session, lap data and car status packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<FuelWarning> {
+        if let Some(session) = &packet.session {
+            self.total_laps = Some(session.total_laps).filter(|&laps| laps > 0);
+            self.track_length =
+                Some(f32::from(session.track_length)).filter(|&length| length > 0.0);
+        } else if let Some(laps) = &packet.laps {
+            for (car, lap_data) in self.cars.iter_mut().zip(&laps.data) {
+                if lap_data.current_lap_num > car.current_lap_num {
+                    if car.current_lap_num > 0 && car.lap_start_seen {
+                        car.laps.push(LapFuelUsage {
+                            lap_num: car.current_lap_num,
+                            fuel_used: car.current_lap.values().sum(),
+                            fuel_used_by_mix: std::mem::take(&mut car.current_lap),
+                        });
+                    }
+
+                    car.current_lap.clear();
+                    // A car that's yet to cross the line on the grid
+                    // has a negative lap distance.
+                    car.lap_start_seen =
+                        car.current_lap_num > 0 || lap_data.lap_distance <= 0.0;
+                } else if lap_data.current_lap_num < car.current_lap_num {
+                    car.laps.retain(|lap| lap.lap_num < lap_data.current_lap_num);
+                    car.current_lap.clear();
+                    car.lap_start_seen = false;
+                }
+
+                car.current_lap_num = lap_data.current_lap_num;
+                car.lap_fraction = self.track_length.map_or(0.0, |length| {
+                    (lap_data.lap_distance / length).clamp(0.0, 1.0)
+                });
+            }
+        } else if let Some(car_status) = &packet.car_status {
+            for (car, status) in self.cars.iter_mut().zip(&car_status.data) {
+                if let (Some(previous), Some(mix)) = (car.fuel_in_tank, car.fuel_mix) {
+                    let burned = previous - status.fuel_in_tank;
+
+                    if burned > 0.0 {
+                        *car.current_lap.entry(mix).or_default() += burned;
+                    }
+                }
+
+                car.fuel_in_tank = Some(status.fuel_in_tank);
+                car.fuel_remaining_laps = status.fuel_remaining_laps;
+                car.fuel_mix = Some(status.fuel_mix);
+            }
+
+            return self.check_projections();
+        }
+
+        Vec::new()
+    }
+
+    /// Returns fuel usage of all laps completed by a car.
+    pub fn laps(&self, vehicle_index: usize) -> &[LapFuelUsage] {
+        self.cars.get(vehicle_index).map_or(&[], |car| &car.laps)
+    }
+
+    /// Returns the rolling average of fuel burned per lap by a car.
+    /// Set to [`None`] if the car hasn't completed a lap yet.
+    pub fn average_burn_per_lap(&self, vehicle_index: usize) -> Option<f32> {
+        let laps = self.laps(vehicle_index);
+
+        if laps.is_empty() {
+            return None;
+        }
+
+        let recent = &laps[laps.len().saturating_sub(self.rolling_window)..];
+
+        Some(recent.iter().map(|lap| lap.fuel_used).sum::<f32>() / recent.len() as f32)
+    }
+
+    /// Projects a car's fuel level at the chequered flag.
+    /// Set to [`None`] if the number of laps in the session is unknown,
+    /// or the car hasn't completed a lap yet.
+    pub fn projection(&self, vehicle_index: usize) -> Option<FuelProjection> {
+        let car = self.cars.get(vehicle_index)?;
+        let total_laps = f32::from(self.total_laps?);
+        let fuel_in_tank = car.fuel_in_tank?;
+        let average_burn_per_lap = self.average_burn_per_lap(vehicle_index)?;
+        let laps_completed = f32::from(car.current_lap_num.saturating_sub(1));
+        let laps_remaining = (total_laps - laps_completed - car.lap_fraction).max(0.0);
+        let fuel_at_finish = fuel_in_tank - average_burn_per_lap * laps_remaining;
+        let lift_and_coast = (fuel_at_finish < 0.0 && laps_remaining > 0.0).then(|| {
+            let fuel_to_save = -fuel_at_finish;
+            let saving_per_lap = fuel_to_save / laps_remaining;
+
+            LiftAndCoastTarget {
+                fuel_to_save,
+                saving_per_lap,
+                target_burn_per_lap: average_burn_per_lap - saving_per_lap,
+            }
+        });
+
+        Some(FuelProjection {
+            vehicle_index,
+            fuel_in_tank,
+            average_burn_per_lap,
+            laps_remaining,
+            fuel_at_finish,
+            game_fuel_remaining_laps: car.fuel_remaining_laps,
+            lift_and_coast,
+        })
+    }
+
+    fn check_projections(&mut self) -> Vec<FuelWarning> {
+        let mut warnings = Vec::new();
+
+        for vehicle_index in 0..self.cars.len() {
+            let Some(projection) = self.projection(vehicle_index) else {
+                continue;
+            };
+            let car = &mut self.cars[vehicle_index];
+
+            if projection.fuel_at_finish < 0.0 && !car.warned {
+                warnings.push(FuelWarning { lap_num: car.current_lap_num, projection });
+            }
+
+            car.warned = projection.fuel_at_finish < 0.0;
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,302 @@
+use crate::constants::{PitStatus, ResultStatus, MAX_NUM_CARS};
+use crate::packets::laps::LapData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+const DEFAULT_HISTORY_WINDOW: f32 = 180.0;
+
+/// Point on a car's distance-time trace.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+struct DistanceSample {
+    session_time: f32,
+    total_distance: f32,
+}
+
+/// Latest known state of a car.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug, Default)]
+struct CarState {
+    active: bool,
+    in_pit_lane: bool,
+    car_position: u8,
+    lap_distance: f32,
+    total_distance: f32,
+}
+
+/// Time interval to a neighbouring car.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interval {
+    /// Index of the neighbouring car.
+    pub vehicle_index: usize,
+    /// Interval in seconds.
+    pub seconds: f32,
+}
+
+/// Summary of a car's gaps to the rest of the field.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CarGap {
+    /// Index of the car this summary refers to.
+    pub vehicle_index: usize,
+    /// Car's race position.
+    pub car_position: u8,
+    /// Gap to the race leader in seconds.
+    /// Set to [`None`] if there's not enough history to work it out.
+    pub gap_to_leader: Option<f32>,
+    /// Number of full laps the car is behind the race leader.
+    /// Always 0 if the track's length is unknown.
+    pub laps_down: u32,
+    /// Interval to the car physically ahead on track, regardless of position.
+    pub interval_ahead: Option<Interval>,
+    /// Interval to the car physically behind on track, regardless of position.
+    pub interval_behind: Option<Interval>,
+    /// Whether the car is in the pit lane.
+    pub in_pit_lane: bool,
+}
+
+/// Works out time gaps between any two cars, on-track intervals
+/// and "laps down" status from the history of [`LapData`] distances.
+///
+/// Gaps are measured by looking up when the car ahead passed
+/// the point the car behind is currently at, so they don't depend
+/// on [`LapData`] delta fields that aren't available in every format.
+/// Cars in the pit lane keep their gaps to other cars,
+/// but are left out when looking for on-track neighbours.
+#[derive(PartialEq, Clone, Debug)]
+pub struct GapCalculator {
+    history_window: f32,
+    track_length: Option<f32>,
+    session_time: f32,
+    cars: Vec<CarState>,
+    history: Vec<VecDeque<DistanceSample>>,
+}
+
+impl Default for GapCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GapCalculator {
+    /// Creates a calculator that keeps the last 180 seconds of distance history.
+    pub fn new() -> Self {
+        Self::with_history_window(DEFAULT_HISTORY_WINDOW)
+    }
+
+    /// Creates a calculator that keeps the given number of seconds
+    /// of distance history. Gaps larger than this window can't be worked out.
+    pub fn with_history_window(seconds: f32) -> Self {
+        Self {
+            history_window: seconds,
+            track_length: None,
+            session_time: 0.0,
+            cars: vec![CarState::default(); MAX_NUM_CARS],
+            history: vec![VecDeque::new(); MAX_NUM_CARS],
+        }
+    }
+
+    /// Feeds a parsed packet into the calculator.
+    /// Only session and lap data packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) {
+        if let Some(session) = &packet.session {
+            self.set_track_length(f32::from(session.track_length));
+        } else if let Some(laps) = &packet.laps {
+            self.push_lap_data(packet.header.session_time, &laps.data);
+        }
+    }
+
+    /// Sets the track's length in metres.
+    pub fn set_track_length(&mut self, metres: f32) {
+        self.track_length = (metres > 0.0).then_some(metres);
+    }
+
+    /// Records lap data of all cars taken at the given session time.
+    /// Going back in time (e.g. after a flashback) discards
+    /// any history recorded after that point.
+    pub fn push_lap_data(&mut self, session_time: f32, data: &[LapData]) {
+        self.session_time = session_time;
+
+        for (vehicle_index, lap_data) in data.iter().enumerate().take(MAX_NUM_CARS) {
+            let history = &mut self.history[vehicle_index];
+
+            while history.back().is_some_and(|s| s.session_time >= session_time) {
+                history.pop_back();
+            }
+
+            while history
+                .front()
+                .is_some_and(|s| session_time - s.session_time > self.history_window)
+            {
+                history.pop_front();
+            }
+
+            let active = lap_data.result_status == ResultStatus::Active;
+
+            self.cars[vehicle_index] = CarState {
+                active,
+                in_pit_lane: lap_data.pit_status != PitStatus::None,
+                car_position: lap_data.car_position,
+                lap_distance: lap_data.lap_distance,
+                total_distance: lap_data.total_distance,
+            };
+
+            if active {
+                history.push_back(DistanceSample {
+                    session_time,
+                    total_distance: lap_data.total_distance,
+                });
+            } else {
+                history.clear();
+            }
+        }
+    }
+
+    /// Returns how many seconds `vehicle_index` is behind `other_vehicle_index`
+    /// (negative if it's ahead). Set to [`None`] if either car is inactive
+    /// or there's not enough history to work it out.
+    pub fn gap(&self, vehicle_index: usize, other_vehicle_index: usize) -> Option<f32> {
+        let car = self.active_car(vehicle_index)?;
+        let other = self.active_car(other_vehicle_index)?;
+
+        if car.total_distance <= other.total_distance {
+            self.time_since_passing(other_vehicle_index, car.total_distance)
+        } else {
+            self.time_since_passing(vehicle_index, other.total_distance).map(|gap| -gap)
+        }
+    }
+
+    /// Returns a matrix where the value at `[i][j]` is [`gap(i, j)`](GapCalculator::gap).
+    pub fn gap_matrix(&self) -> Vec<Vec<Option<f32>>> {
+        (0..MAX_NUM_CARS)
+            .map(|i| (0..MAX_NUM_CARS).map(|j| self.gap(i, j)).collect())
+            .collect()
+    }
+
+    /// Returns the index of the race leader's car, if there's one.
+    pub fn leader_index(&self) -> Option<usize> {
+        self.active_cars()
+            .filter(|(_, car)| car.car_position > 0)
+            .min_by_key(|(_, car)| car.car_position)
+            .map(|(vehicle_index, _)| vehicle_index)
+    }
+
+    /// Returns how many full laps a car is behind the race leader.
+    /// Set to [`None`] if the car is inactive or the track's length is unknown.
+    pub fn laps_down(&self, vehicle_index: usize) -> Option<u32> {
+        let car = self.active_car(vehicle_index)?;
+        let leader = self.active_car(self.leader_index()?)?;
+        let track_length = self.track_length?;
+        let deficit = (leader.total_distance - car.total_distance).max(0.0);
+
+        Some((deficit / track_length) as u32)
+    }
+
+    /// Returns the interval to the car that's physically ahead on track.
+    pub fn interval_ahead(&self, vehicle_index: usize) -> Option<Interval> {
+        let (ahead_index, distance) = self.nearest_on_track(vehicle_index, true)?;
+        let ahead = self.cars[ahead_index];
+        let seconds =
+            self.time_since_passing(ahead_index, ahead.total_distance - distance)?;
+
+        Some(Interval { vehicle_index: ahead_index, seconds })
+    }
+
+    /// Returns the interval to the car that's physically behind on track.
+    pub fn interval_behind(&self, vehicle_index: usize) -> Option<Interval> {
+        let (behind_index, distance) = self.nearest_on_track(vehicle_index, false)?;
+        let car = self.cars[vehicle_index];
+        let seconds =
+            self.time_since_passing(vehicle_index, car.total_distance - distance)?;
+
+        Some(Interval { vehicle_index: behind_index, seconds })
+    }
+
+    /// Returns gap summaries of all active cars ordered by race position.
+    pub fn car_gaps(&self) -> Vec<CarGap> {
+        let leader_index = self.leader_index();
+        let mut gaps: Vec<CarGap> = self
+            .active_cars()
+            .map(|(vehicle_index, car)| CarGap {
+                vehicle_index,
+                car_position: car.car_position,
+                gap_to_leader: leader_index
+                    .and_then(|leader_index| self.gap(vehicle_index, leader_index)),
+                laps_down: self.laps_down(vehicle_index).unwrap_or(0),
+                interval_ahead: self.interval_ahead(vehicle_index),
+                interval_behind: self.interval_behind(vehicle_index),
+                in_pit_lane: car.in_pit_lane,
+            })
+            .collect();
+
+        gaps.sort_by_key(|gap| gap.car_position);
+        gaps
+    }
+
+    fn active_car(&self, vehicle_index: usize) -> Option<CarState> {
+        self.cars.get(vehicle_index).copied().filter(|car| car.active)
+    }
+
+    fn active_cars(&self) -> impl Iterator<Item = (usize, CarState)> + '_ {
+        self.cars.iter().copied().enumerate().filter(|(_, car)| car.active)
+    }
+
+    /// Finds the closest car on track ahead of or behind the given one,
+    /// along with the distance between them in metres.
+    fn nearest_on_track(
+        &self,
+        vehicle_index: usize,
+        ahead: bool,
+    ) -> Option<(usize, f32)> {
+        let car = self.active_car(vehicle_index).filter(|car| !car.in_pit_lane)?;
+        let track_length = self.track_length?;
+
+        self.active_cars()
+            .filter(|(other_index, other)| {
+                *other_index != vehicle_index && !other.in_pit_lane
+            })
+            .map(|(other_index, other)| {
+                let delta = if ahead {
+                    other.lap_distance - car.lap_distance
+                } else {
+                    car.lap_distance - other.lap_distance
+                };
+
+                (other_index, delta.rem_euclid(track_length))
+            })
+            .filter(|(_, distance)| *distance > 0.0)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// Returns how long ago the given car was at `total_distance`,
+    /// interpolating between the recorded samples.
+    fn time_since_passing(
+        &self,
+        vehicle_index: usize,
+        total_distance: f32,
+    ) -> Option<f32> {
+        let history = self.history.get(vehicle_index)?;
+        let latest = history.back()?;
+
+        if total_distance >= latest.total_distance {
+            return Some(self.session_time - latest.session_time);
+        }
+
+        let after_index =
+            history.iter().rposition(|s| s.total_distance <= total_distance)? + 1;
+        let before = history[after_index - 1];
+        let after = history[after_index];
+        let distance_span = after.total_distance - before.total_distance;
+        let fraction = if distance_span > 0.0 {
+            (total_distance - before.total_distance) / distance_span
+        } else {
+            0.0
+        };
+        let passing_time =
+            before.session_time + fraction * (after.session_time - before.session_time);
+
+        Some(self.session_time - passing_time)
+    }
+}
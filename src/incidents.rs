@@ -0,0 +1,480 @@
+use crate::constants::{InfringementType, PenaltyType};
+use crate::packets::event::EventDetails;
+use crate::packets::laps::LapData;
+use crate::{F1Packet, F1PacketHeader};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How far back the session timestamp has to go to be considered a flashback
+/// in formats without an overall frame identifier.
+const FLASHBACK_THRESHOLD: f32 = 1.0;
+
+/// Type of an [`Incident`].
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IncidentKind {
+    /// A penalty or warning has been issued.
+    Penalty {
+        /// Penalty type.
+        penalty_type: PenaltyType,
+        /// Infringement type.
+        infringement_type: InfringementType,
+        /// Time gained or spent doing the action in seconds,
+        /// 255 if not applicable.
+        time: u8,
+        /// Number of places gained by the infringement.
+        places_gained: u8,
+    },
+    /// The car has collided with another one.
+    /// Available from the 2024 format onwards.
+    Collision,
+    /// The car has served a drive-through penalty.
+    DriveThroughServed,
+    /// The car has served a stop-go penalty.
+    StopGoServed,
+    /// The car's total number of warnings has gone up.
+    Warning {
+        /// New total number of warnings.
+        total_warnings: u8,
+    },
+    /// The car's number of corner cutting warnings has gone up.
+    /// Available from the 2023 format onwards.
+    CornerCuttingWarning {
+        /// New number of corner cutting warnings.
+        corner_cutting_warnings: u8,
+    },
+    /// The car's number of unserved drive-through penalties has gone up.
+    UnservedDriveThrough {
+        /// New number of unserved drive-through penalties.
+        num_unserved: u8,
+    },
+    /// The car's number of unserved stop-go penalties has gone up.
+    UnservedStopGo {
+        /// New number of unserved stop-go penalties.
+        num_unserved: u8,
+    },
+}
+
+impl fmt::Display for IncidentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Penalty { penalty_type, infringement_type, time, .. } => {
+                write!(f, "{:?} for {:?}", penalty_type, infringement_type)?;
+
+                if *time != 0 && *time != 255 {
+                    write!(f, " ({}s)", time)?;
+                }
+
+                Ok(())
+            }
+            Self::Collision => write!(f, "collision"),
+            Self::DriveThroughServed => write!(f, "drive-through served"),
+            Self::StopGoServed => write!(f, "stop-go served"),
+            Self::Warning { total_warnings } => {
+                write!(f, "warning ({} in total)", total_warnings)
+            }
+            Self::CornerCuttingWarning { corner_cutting_warnings } => {
+                write!(f, "corner cutting warning ({} in total)", corner_cutting_warnings)
+            }
+            Self::UnservedDriveThrough { num_unserved } => {
+                write!(f, "drive-through pending ({} unserved)", num_unserved)
+            }
+            Self::UnservedStopGo { num_unserved } => {
+                write!(f, "stop-go pending ({} unserved)", num_unserved)
+            }
+        }
+    }
+}
+
+/// State of a car involved in an [`Incident`], taken from the most recent
+/// lap data, motion and car telemetry packets received before it.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IncidentCar {
+    /// Index of the car.
+    pub vehicle_index: usize,
+    /// Race position, or 0 if no lap data has been received
+    /// since the last flashback.
+    pub car_position: u8,
+    /// Current lap number, or 0 if no lap data has been received
+    /// since the last flashback.
+    pub lap_num: u8,
+    /// Distance around the current lap in metres,
+    /// or 0 if no lap data has been received since the last flashback.
+    pub lap_distance: f32,
+    /// Speed in km/h, if a car telemetry packet has been received.
+    pub speed: Option<u16>,
+    /// World space position as `[x, y, z]`, if a motion packet has been received.
+    pub world_position: Option<[f32; 3]>,
+}
+
+impl fmt::Display for IncidentCar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "car {} (P{}", self.vehicle_index, self.car_position)?;
+
+        if let Some(speed) = self.speed {
+            write!(f, ", {} km/h", speed)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+/// Entry of an [`IncidentTracker`]'s log.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Incident {
+    /// Session timestamp of the packet the incident was detected in.
+    pub session_time: f32,
+    /// What has happened.
+    pub kind: IncidentKind,
+    /// Car the incident refers to.
+    pub car: IncidentCar,
+    /// Other car involved, e.g. in a collision.
+    pub other_car: Option<IncidentCar>,
+}
+
+impl fmt::Display for Incident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lap {}, {}: {}", self.car.lap_num, self.car, self.kind)?;
+
+        if let Some(other_car) = &self.other_car {
+            write!(f, " involving {}", other_car)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct CarState {
+    lap_data: Option<LapCounters>,
+    /// Whether [`lap_data`](Self::lap_data) has been restored after a flashback,
+    /// in which case only its counters are up to date.
+    restored: bool,
+    /// Lap data whenever the counters have changed, to restore after flashbacks.
+    counter_changes: Vec<(f32, LapCounters)>,
+    speed: Option<u16>,
+    world_position: Option<[f32; 3]>,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+struct LapCounters {
+    car_position: u8,
+    lap_num: u8,
+    lap_distance: f32,
+    total_warnings: u8,
+    corner_cutting_warnings: u8,
+    num_unserved_drive_through_pens: u8,
+    num_unserved_stop_go_pens: u8,
+}
+
+impl LapCounters {
+    fn counters(&self) -> [u8; 4] {
+        [
+            self.total_warnings,
+            self.corner_cutting_warnings,
+            self.num_unserved_drive_through_pens,
+            self.num_unserved_stop_go_pens,
+        ]
+    }
+}
+
+impl From<&LapData> for LapCounters {
+    fn from(lap_data: &LapData) -> Self {
+        Self {
+            car_position: lap_data.car_position,
+            lap_num: lap_data.current_lap_num,
+            lap_distance: lap_data.lap_distance,
+            total_warnings: lap_data.total_warnings,
+            corner_cutting_warnings: lap_data.corner_cutting_warnings,
+            num_unserved_drive_through_pens: lap_data.num_unserved_drive_through_pens,
+            num_unserved_stop_go_pens: lap_data.num_unserved_stop_go_pens,
+        }
+    }
+}
+
+/// Stewarding log combining penalty, collision and penalty served events
+/// with warning and unserved penalty counters from lap data into a
+/// time-ordered list of incidents.
+///
+/// Every incident carries the positions and speeds of the involved cars.
+/// Warnings issued through penalty events also increase the lap data
+/// warning counters, so they're reported both as
+/// [`IncidentKind::Penalty`] and [`IncidentKind::Warning`].
+///
+/// A packet with a different `session_uid` starts a new session,
+/// which resets the tracker, clearing the log of the previous one.
+/// To keep logs of several sessions, use a tracker per session,
+/// e.g. with a [`SessionDemux`](crate::demux::SessionDemux).
+///
+/// Within a session, a flashback is detected by the frame identifier going
+/// backwards while the overall frame identifier keeps going forwards.
+/// Formats before 2023 don't have the latter, so the session timestamp
+/// has to go back by more than a second instead.
+/// Incidents detected after the point the session has been rewound to
+/// are dropped from the log, and lap data counters are compared against
+/// their values at that point again, so that replayed incidents
+/// aren't logged twice.
+///
+/// Any other packet from before the latest one, e.g. a datagram
+/// that's arrived out of order, only has its events logged,
+/// as its car data is already out of date.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct IncidentTracker {
+    cars: Vec<CarState>,
+    incidents: Vec<Incident>,
+    session_uid: Option<u64>,
+    latest: Option<FramePosition>,
+}
+
+/// Point in a session the latest packet has been retrieved at.
+#[derive(PartialEq, Copy, Clone, Debug)]
+struct FramePosition {
+    session_time: f32,
+    frame_identifier: u32,
+    overall_frame_identifier: Option<u32>,
+}
+
+impl From<&F1PacketHeader> for FramePosition {
+    fn from(header: &F1PacketHeader) -> Self {
+        Self {
+            session_time: header.session_time,
+            frame_identifier: header.frame_identifier,
+            overall_frame_identifier: (header.packet_format >= 2023)
+                .then_some(header.overall_frame_identifier),
+        }
+    }
+}
+
+impl FramePosition {
+    fn is_flashback_from(&self, latest: &Self) -> bool {
+        match (self.overall_frame_identifier, latest.overall_frame_identifier) {
+            (Some(overall), Some(latest_overall)) => {
+                overall > latest_overall
+                    && self.frame_identifier < latest.frame_identifier
+            }
+            _ => self.session_time < latest.session_time - FLASHBACK_THRESHOLD,
+        }
+    }
+
+    fn is_before(&self, latest: &Self) -> bool {
+        match (self.overall_frame_identifier, latest.overall_frame_identifier) {
+            (Some(overall), Some(latest_overall)) => overall < latest_overall,
+            _ => self.session_time < latest.session_time,
+        }
+    }
+}
+
+impl IncidentTracker {
+    /// Creates a tracker with an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a parsed packet into the tracker and returns newly detected incidents.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<Incident> {
+        let session_time = packet.header.session_time;
+        let position = FramePosition::from(&packet.header);
+        let mut incidents = Vec::new();
+        let mut late = false;
+
+        if self.session_uid != Some(packet.header.session_uid) {
+            *self =
+                Self { session_uid: Some(packet.header.session_uid), ..Self::default() };
+        }
+
+        match self.latest {
+            Some(latest) if position.is_flashback_from(&latest) => {
+                self.incidents.retain(|incident| incident.session_time <= session_time);
+
+                for car in &mut self.cars {
+                    car.counter_changes.retain(|&(time, _)| time <= session_time);
+                    car.lap_data =
+                        car.counter_changes.last().map(|&(_, lap_data)| lap_data);
+                    car.restored = true;
+                }
+            }
+            Some(latest) if position.is_before(&latest) => late = true,
+            _ => (),
+        }
+
+        if !late {
+            self.latest = Some(position);
+        }
+
+        if late && packet.event.is_none() {
+            return incidents;
+        }
+
+        if let Some(laps) = &packet.laps {
+            self.ensure_cars(laps.data.len());
+
+            for (vehicle_index, lap_data) in laps.data.iter().enumerate() {
+                let current = LapCounters::from(lap_data);
+                let car = &mut self.cars[vehicle_index];
+                let previous = car.lap_data.replace(current);
+
+                car.restored = false;
+
+                if previous
+                    .map_or(true, |previous| previous.counters() != current.counters())
+                {
+                    car.counter_changes.push((session_time, current));
+                }
+
+                let Some(previous) = previous else {
+                    continue;
+                };
+
+                let increases = [
+                    (current.total_warnings > previous.total_warnings).then_some(
+                        IncidentKind::Warning { total_warnings: current.total_warnings },
+                    ),
+                    (current.corner_cutting_warnings > previous.corner_cutting_warnings)
+                        .then_some(IncidentKind::CornerCuttingWarning {
+                            corner_cutting_warnings: current.corner_cutting_warnings,
+                        }),
+                    (current.num_unserved_drive_through_pens
+                        > previous.num_unserved_drive_through_pens)
+                        .then_some(IncidentKind::UnservedDriveThrough {
+                            num_unserved: current.num_unserved_drive_through_pens,
+                        }),
+                    (current.num_unserved_stop_go_pens
+                        > previous.num_unserved_stop_go_pens)
+                        .then_some(IncidentKind::UnservedStopGo {
+                            num_unserved: current.num_unserved_stop_go_pens,
+                        }),
+                ];
+
+                for kind in increases.into_iter().flatten() {
+                    incidents.push(self.incident(
+                        session_time,
+                        kind,
+                        vehicle_index,
+                        None,
+                    ));
+                }
+            }
+        } else if let Some(motion) = &packet.motion {
+            self.ensure_cars(motion.data.len());
+
+            for (car, motion_data) in self.cars.iter_mut().zip(&motion.data) {
+                car.world_position = Some([
+                    motion_data.world_position_x,
+                    motion_data.world_position_y,
+                    motion_data.world_position_z,
+                ]);
+            }
+        } else if let Some(car_telemetry) = &packet.car_telemetry {
+            self.ensure_cars(car_telemetry.data.len());
+
+            for (car, telemetry) in self.cars.iter_mut().zip(&car_telemetry.data) {
+                car.speed = Some(telemetry.speed);
+            }
+        } else if let Some(event) = &packet.event {
+            let incident = match event.details {
+                EventDetails::Penalty {
+                    penalty_type,
+                    infringement_type,
+                    vehicle_index,
+                    other_vehicle_index,
+                    time,
+                    places_gained,
+                    ..
+                } => Some((
+                    IncidentKind::Penalty {
+                        penalty_type,
+                        infringement_type,
+                        time,
+                        places_gained,
+                    },
+                    vehicle_index,
+                    (other_vehicle_index != 255).then_some(other_vehicle_index),
+                )),
+                EventDetails::Collision { vehicle_index, other_vehicle_index } => Some((
+                    IncidentKind::Collision,
+                    vehicle_index,
+                    Some(other_vehicle_index),
+                )),
+                EventDetails::DriveThroughServed { vehicle_index } => {
+                    Some((IncidentKind::DriveThroughServed, vehicle_index, None))
+                }
+                EventDetails::StopGoServed { vehicle_index } => {
+                    Some((IncidentKind::StopGoServed, vehicle_index, None))
+                }
+                _ => None,
+            };
+
+            if let Some((kind, vehicle_index, other_vehicle_index)) = incident {
+                incidents.push(self.incident(
+                    session_time,
+                    kind,
+                    vehicle_index,
+                    other_vehicle_index,
+                ));
+            }
+        }
+
+        self.incidents.extend_from_slice(&incidents);
+        incidents
+    }
+
+    /// Returns all incidents detected so far, in order of detection.
+    pub fn incidents(&self) -> &[Incident] {
+        &self.incidents
+    }
+
+    /// Returns incidents a car has been involved in, in order of detection,
+    /// including ones where it's the other car.
+    pub fn driver_incidents(
+        &self,
+        vehicle_index: usize,
+    ) -> impl Iterator<Item = &Incident> {
+        self.incidents.iter().filter(move |incident| {
+            incident.car.vehicle_index == vehicle_index
+                || incident
+                    .other_car
+                    .is_some_and(|other_car| other_car.vehicle_index == vehicle_index)
+        })
+    }
+
+    fn ensure_cars(&mut self, num_cars: usize) {
+        if self.cars.len() < num_cars {
+            self.cars.resize(num_cars, CarState::default());
+        }
+    }
+
+    fn car(&self, vehicle_index: usize) -> IncidentCar {
+        let state = self.cars.get(vehicle_index);
+        let lap_data =
+            state.filter(|state| !state.restored).and_then(|state| state.lap_data);
+
+        IncidentCar {
+            vehicle_index,
+            car_position: lap_data.map_or(0, |lap_data| lap_data.car_position),
+            lap_num: lap_data.map_or(0, |lap_data| lap_data.lap_num),
+            lap_distance: lap_data.map_or(0.0, |lap_data| lap_data.lap_distance),
+            speed: state.and_then(|state| state.speed),
+            world_position: state.and_then(|state| state.world_position),
+        }
+    }
+
+    fn incident(
+        &self,
+        session_time: f32,
+        kind: IncidentKind,
+        vehicle_index: usize,
+        other_vehicle_index: Option<usize>,
+    ) -> Incident {
+        Incident {
+            session_time,
+            kind,
+            car: self.car(vehicle_index),
+            other_car: other_vehicle_index.map(|other| self.car(other)),
+        }
+    }
+}
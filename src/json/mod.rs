@@ -0,0 +1,249 @@
+/// Frozen types making up version 1 of the JSON representation.
+pub mod v1;
+
+use crate::F1Packet;
+#[cfg(feature = "schema")]
+use v1::EventDetails;
+use v1::{
+    F1PacketCarDamage, F1PacketCarSetups, F1PacketCarStatus, F1PacketCarTelemetry,
+    F1PacketEvent, F1PacketFinalClassification, F1PacketHeader, F1PacketLaps,
+    F1PacketLobby, F1PacketMotion, F1PacketMotionEx, F1PacketParticipants,
+    F1PacketSession, F1PacketSessionHistory, F1PacketTimeTrial, F1PacketTyreSets,
+};
+
+#[cfg(feature = "schema")]
+use schemars::{schema_for, Schema};
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "schema")]
+use std::collections::BTreeMap;
+
+/// Version of the JSON representation produced by [`to_string`].
+///
+/// Within a version, new fields and enum variants may get added,
+/// but existing ones never get renamed, removed or change their type.
+/// Any such change bumps the version, so consumers can detect documents
+/// they can't handle by checking the `schema_version` field.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Versioned JSON representation of a packet.
+///
+/// Unlike [`F1Packet`], which has an [`Option`] slot for every packet type,
+/// this representation only contains the payload of the received packet,
+/// tagged with its type:
+///
+/// ```json
+/// {
+///   "schema_version": 1,
+///   "header": { "packet_format": 2024, "packet_id": "CarTelemetry", ... },
+///   "packet_type": "car_telemetry",
+///   "payload": { "data": [...], ... }
+/// }
+/// ```
+///
+/// The representation is made of the dedicated types in [`v1`],
+/// which get converted from and into the packet types with [`From`].
+/// Their field and variant names are spelled out explicitly,
+/// so renaming anything in the packet types doesn't change the format.
+/// `_index` fields hold the [`u8`] sent by the game.
+/// With the `schema` feature enabled, [`schema`] returns the JSON Schema
+/// of this representation.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsonPacket {
+    /// Version of the representation, equal to [`SCHEMA_VERSION`].
+    pub schema_version: u32,
+    /// Universal packet header.
+    pub header: F1PacketHeader,
+    /// Packet type and its payload.
+    #[serde(flatten)]
+    pub payload: JsonPayload,
+}
+
+/// Payload of a [`JsonPacket`], tagged with a `packet_type` named after
+/// the matching [`F1Packet`] field.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "packet_type", content = "payload", rename_all = "snake_case")]
+pub enum JsonPayload {
+    /// See [`F1Packet::motion`].
+    Motion(F1PacketMotion),
+    /// See [`F1Packet::session`].
+    Session(F1PacketSession),
+    /// See [`F1Packet::laps`].
+    Laps(F1PacketLaps),
+    /// See [`F1Packet::event`].
+    Event(F1PacketEvent),
+    /// See [`F1Packet::participants`].
+    Participants(F1PacketParticipants),
+    /// See [`F1Packet::car_setups`].
+    CarSetups(F1PacketCarSetups),
+    /// See [`F1Packet::car_telemetry`].
+    CarTelemetry(F1PacketCarTelemetry),
+    /// See [`F1Packet::car_status`].
+    CarStatus(F1PacketCarStatus),
+    /// See [`F1Packet::final_classification`].
+    FinalClassification(F1PacketFinalClassification),
+    /// See [`F1Packet::lobby`].
+    Lobby(F1PacketLobby),
+    /// See [`F1Packet::car_damage`].
+    CarDamage(F1PacketCarDamage),
+    /// See [`F1Packet::session_history`].
+    SessionHistory(F1PacketSessionHistory),
+    /// See [`F1Packet::tyre_sets`].
+    TyreSets(F1PacketTyreSets),
+    /// See [`F1Packet::motion_ex`].
+    MotionEx(F1PacketMotionEx),
+    /// See [`F1Packet::time_trial`].
+    TimeTrial(F1PacketTimeTrial),
+}
+
+macro_rules! payload_conversions {
+    ($($variant:ident($payload:ty) => $field:ident),* $(,)?) => {
+        impl JsonPacket {
+            /// Converts a packet into its JSON representation.
+            /// Returns [`None`] if none of the packet's payload slots are filled.
+            pub fn new(packet: F1Packet) -> Option<Self> {
+                let payload = match packet {
+                    $(
+                        F1Packet { $field: Some(payload), .. } => {
+                            JsonPayload::$variant(payload.into())
+                        }
+                    )*
+                    _ => return None,
+                };
+
+                Some(Self {
+                    schema_version: SCHEMA_VERSION,
+                    header: packet.header.into(),
+                    payload,
+                })
+            }
+        }
+
+        impl JsonPayload {
+            /// Name of the matching [`F1Packet`] field.
+            pub(crate) fn packet_type(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => stringify!($field),)*
+                }
+            }
+        }
+
+        impl From<JsonPacket> for F1Packet {
+            fn from(packet: JsonPacket) -> Self {
+                let mut converted = F1Packet {
+                    header: packet.header.into(),
+                    $($field: None,)*
+                };
+
+                match packet.payload {
+                    $(JsonPayload::$variant(payload) => converted.$field = Some(payload.into()),)*
+                }
+
+                converted
+            }
+        }
+
+        /// Returns standalone JSON Schemas of each packet type's payload,
+        /// keyed by their `packet_type`.
+        #[cfg(feature = "schema")]
+        pub fn payload_schemas() -> BTreeMap<&'static str, Schema> {
+            BTreeMap::from([$((stringify!($field), schema_for!($payload)),)*])
+        }
+    };
+}
+
+payload_conversions! {
+    Motion(F1PacketMotion) => motion,
+    Session(F1PacketSession) => session,
+    Laps(F1PacketLaps) => laps,
+    Event(F1PacketEvent) => event,
+    Participants(F1PacketParticipants) => participants,
+    CarSetups(F1PacketCarSetups) => car_setups,
+    CarTelemetry(F1PacketCarTelemetry) => car_telemetry,
+    CarStatus(F1PacketCarStatus) => car_status,
+    FinalClassification(F1PacketFinalClassification) => final_classification,
+    Lobby(F1PacketLobby) => lobby,
+    CarDamage(F1PacketCarDamage) => car_damage,
+    SessionHistory(F1PacketSessionHistory) => session_history,
+    TyreSets(F1PacketTyreSets) => tyre_sets,
+    MotionEx(F1PacketMotionEx) => motion_ex,
+    TimeTrial(F1PacketTimeTrial) => time_trial,
+}
+
+/// Serializes a packet into its versioned JSON representation.
+pub fn to_string(packet: &F1Packet) -> serde_json::Result<String> {
+    match JsonPacket::new(packet.clone()) {
+        Some(packet) => serde_json::to_string(&packet),
+        None => Err(serde_json::Error::custom("Packet has no payload")),
+    }
+}
+
+/// Deserializes a packet from its versioned JSON representation.
+///
+/// ## Errors
+///
+/// Besides malformed JSON, fails if the document's `schema_version`
+/// differs from [`SCHEMA_VERSION`].
+pub fn from_str(json: &str) -> serde_json::Result<F1Packet> {
+    #[derive(Deserialize)]
+    struct Version {
+        schema_version: u32,
+    }
+
+    let Version { schema_version } = serde_json::from_str(json)?;
+
+    if schema_version != SCHEMA_VERSION {
+        return Err(serde_json::Error::custom(format!(
+            "Unsupported schema version: {}, expected {}",
+            schema_version, SCHEMA_VERSION
+        )));
+    }
+
+    serde_json::from_str::<JsonPacket>(json).map(F1Packet::from)
+}
+
+/// Returns the JSON Schema of [`JsonPacket`], with every payload type
+/// and enum defined under `$defs`.
+#[cfg(feature = "schema")]
+pub fn schema() -> Schema {
+    schema_for!(JsonPacket)
+}
+
+/// Returns standalone JSON Schemas of each [`EventDetails`] variant,
+/// keyed by the variant's name.
+#[cfg(feature = "schema")]
+pub fn event_schemas() -> BTreeMap<String, Schema> {
+    let root = schema_for!(EventDetails);
+    let mut schemas = BTreeMap::new();
+    let variants = root.get("oneOf").and_then(|variants| variants.as_array());
+
+    for variant in variants.into_iter().flatten() {
+        let name = variant
+            .get("const")
+            .or_else(|| variant.pointer("/required/0"))
+            .and_then(|name| name.as_str());
+        let (Some(name), Some(fields)) = (name, variant.as_object()) else {
+            continue;
+        };
+        let mut schema = serde_json::Map::new();
+
+        if let Some(meta_schema) = root.get("$schema") {
+            schema.insert("$schema".to_owned(), meta_schema.clone());
+        }
+
+        schema.insert("title".to_owned(), name.into());
+        schema.extend(fields.clone());
+
+        if let Some(defs) = root.get("$defs") {
+            schema.insert("$defs".to_owned(), defs.clone());
+        }
+
+        schemas.insert(name.to_owned(), Schema::from(schema));
+    }
+
+    schemas
+}
@@ -0,0 +1,2291 @@
+use crate::constants::{self, ButtonStatus, LapValid, RevLights};
+use crate::packets::{self, event};
+
+use serde::{Deserialize, Serialize};
+
+/// Conversion between the crate's packet types and their frozen counterparts,
+/// covering the cases [`From`] can't, such as indexes and collections.
+trait Convert<T> {
+    fn convert(self) -> T;
+}
+
+macro_rules! convert_identity {
+    ($($ty:ty),*) => {
+        $(
+            impl Convert<$ty> for $ty {
+                fn convert(self) -> $ty {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+convert_identity!(bool, u8, i8, u16, i16, u32, u64, f32, f64, String);
+
+/// Indexes are stored as the [`u8`] they're sent as,
+/// with out-of-range ones saturating to 255 (the "invalid index" value).
+impl Convert<u8> for usize {
+    fn convert(self) -> u8 {
+        u8::try_from(self).unwrap_or(u8::MAX)
+    }
+}
+
+impl Convert<usize> for u8 {
+    fn convert(self) -> usize {
+        usize::from(self)
+    }
+}
+
+impl<A: Convert<B>, B> Convert<Vec<B>> for Vec<A> {
+    fn convert(self) -> Vec<B> {
+        self.into_iter().map(Convert::convert).collect()
+    }
+}
+
+impl<A: Convert<B>, B> Convert<Option<B>> for Option<A> {
+    fn convert(self) -> Option<B> {
+        self.map(Convert::convert)
+    }
+}
+
+impl<A: Convert<B>, B, const N: usize> Convert<[B; N]> for [A; N] {
+    fn convert(self) -> [B; N] {
+        self.map(Convert::convert)
+    }
+}
+
+macro_rules! convert_bitflags {
+    ($($flags:ty => $bits:ty),*) => {
+        $(
+            impl Convert<$bits> for $flags {
+                fn convert(self) -> $bits {
+                    self.bits()
+                }
+            }
+
+            impl Convert<$flags> for $bits {
+                fn convert(self) -> $flags {
+                    <$flags>::from_bits_retain(self)
+                }
+            }
+        )*
+    };
+}
+
+convert_bitflags!(ButtonStatus => u32, RevLights => u16, LapValid => u8);
+
+/// Declares frozen structs with explicitly named fields,
+/// along with [`From`] conversions from and into the crate's packet types.
+macro_rules! frozen_structs {
+    ($(
+        $(#[$meta:meta])*
+        struct $name:ident: $source:path {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty => $json:literal,
+            )*
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+            #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+            pub struct $name {
+                $(
+                    $(#[$field_meta])*
+                    #[serde(rename = $json)]
+                    pub $field: $ty,
+                )*
+            }
+
+            impl From<$source> for $name {
+                fn from(value: $source) -> Self {
+                    Self { $($field: value.$field.convert(),)* }
+                }
+            }
+
+            impl From<$name> for $source {
+                fn from(value: $name) -> Self {
+                    Self { $($field: value.$field.convert(),)* }
+                }
+            }
+
+            impl Convert<$name> for $source {
+                fn convert(self) -> $name {
+                    self.into()
+                }
+            }
+
+            impl Convert<$source> for $name {
+                fn convert(self) -> $source {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+/// Declares frozen unit-only enums with explicitly named variants,
+/// along with [`From`] conversions from and into the crate's constants.
+macro_rules! frozen_enums {
+    ($(
+        $(#[$meta:meta])*
+        enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $json:literal,
+            )*
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(
+                Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash, Serialize, Deserialize,
+            )]
+            #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+            pub enum $name {
+                $(
+                    $(#[$variant_meta])*
+                    #[serde(rename = $json)]
+                    $variant,
+                )*
+            }
+
+            impl From<constants::$name> for $name {
+                fn from(value: constants::$name) -> Self {
+                    match value {
+                        $(constants::$name::$variant => Self::$variant,)*
+                    }
+                }
+            }
+
+            impl From<$name> for constants::$name {
+                fn from(value: $name) -> Self {
+                    match value {
+                        $($name::$variant => Self::$variant,)*
+                    }
+                }
+            }
+
+            impl Convert<$name> for constants::$name {
+                fn convert(self) -> $name {
+                    self.into()
+                }
+            }
+
+            impl Convert<constants::$name> for $name {
+                fn convert(self) -> constants::$name {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+frozen_structs! {
+    /// F1 game packet's header. It contains metadata about the game,
+    /// the ongoing session, the frame this packet was sent on, and player car indexes.
+    struct F1PacketHeader: crate::F1PacketHeader {
+        /// Value of the "UDP Format" option in the game's telemetry settings.
+        /// This crate currently supports formats in range `(2022..=2024)`.
+        packet_format: u16 => "packet_format",
+        /// Game year (last two digits).
+        /// Available from the 2023 format onwards.
+        game_year: u8 => "game_year",
+        /// Game's major version - "X.00".
+        game_major_version: u8 => "game_major_version",
+        /// Game's minor version - "1.XX".
+        game_minor_version: u8 => "game_minor_version",
+        /// Version of this packet type, all start from 1.
+        packet_version: u8 => "packet_version",
+        /// Unique identifier for the packet type.
+        packet_id: PacketId => "packet_id",
+        /// Unique identifier for the session.
+        session_uid: u64 => "session_uid",
+        /// Session timestamp.
+        session_time: f32 => "session_time",
+        /// Identifier for the frame the data was retrieved on.
+        /// Goes back after a flashback is triggered.
+        frame_identifier: u32 => "frame_identifier",
+        /// Overall identifier for the frame the data was retrieved on
+        /// (i.e. it doesn't go back after flashbacks).
+        /// Available from the 2023 format onwards.
+        overall_frame_identifier: u32 => "overall_frame_identifier",
+        /// Index of player 1's car (255 if in spectator mode).
+        player_car_index: u8 => "player_car_index",
+        /// Index of player 2's car in splitscreen mode.
+        /// Set to 255 if not in splitscreen mode.
+        secondary_player_car_index: u8 => "secondary_player_car_index",
+    }
+
+    /// The motion packet gives physics data for all the cars being driven.
+    struct F1PacketMotion: packets::F1PacketMotion {
+        /// Motion data for all cars on track. Should have a size of 22.
+        data: Vec<CarMotionData> => "data",
+        /// Extra player-car-only motion data.
+        /// Available only in the 2022 format.
+        motion_ex: Option<F1PacketMotionEx> => "motion_ex",
+    }
+
+    struct CarMotionData: packets::motion::CarMotionData {
+        /// World space X position in metres.
+        world_position_x: f32 => "world_position_x",
+        /// World space Y position in metres.
+        world_position_y: f32 => "world_position_y",
+        /// World space Z position in metres.
+        world_position_z: f32 => "world_position_z",
+        /// Velocity in world space X position in metres per second.
+        world_velocity_x: f32 => "world_velocity_x",
+        /// Velocity in world space Y position in metres per second.
+        world_velocity_y: f32 => "world_velocity_y",
+        /// Velocity in world space Z position in metres per second.
+        world_velocity_z: f32 => "world_velocity_z",
+        /// World space forward X direction (normalised).
+        world_forward_dir_x: i16 => "world_forward_dir_x",
+        /// World space forward Y direction (normalised).
+        world_forward_dir_y: i16 => "world_forward_dir_y",
+        /// World space forward Z direction (normalised).
+        world_forward_dir_z: i16 => "world_forward_dir_z",
+        /// World space right X direction (normalised).
+        world_right_dir_x: i16 => "world_right_dir_x",
+        /// World space right Y direction (normalised).
+        world_right_dir_y: i16 => "world_right_dir_y",
+        /// World space right Z direction (normalised).
+        world_right_dir_z: i16 => "world_right_dir_z",
+        /// Lateral G-Force component.
+        g_force_lateral: f32 => "g_force_lateral",
+        /// Longitudinal G-Force component.
+        g_force_longitudinal: f32 => "g_force_longitudinal",
+        /// Vertical G-Force component.
+        g_force_vertical: f32 => "g_force_vertical",
+        /// Yaw angle in radians.
+        yaw: f32 => "yaw",
+        /// Pitch angle in radians.
+        pitch: f32 => "pitch",
+        /// Roll angle in radians.
+        roll: f32 => "roll",
+    }
+
+    /// The session packet includes details about the current session in progress.
+    #[allow(clippy::struct_excessive_bools)]
+    struct F1PacketSession: packets::F1PacketSession {
+        /// Current weather.
+        weather: Weather => "weather",
+        /// Track temperature in degrees Celsius.
+        track_temperature: i8 => "track_temperature",
+        /// Air temperature in degrees Celsius.
+        air_temperature: i8 => "air_temperature",
+        /// Total number of laps in this session.
+        total_laps: u8 => "total_laps",
+        /// Track's length in metres.
+        track_length: u16 => "track_length",
+        /// Session's type.
+        /// See [`session_type`](mod@crate::constants::session_type)
+        /// for possible values.
+        session_type: u8 => "session_type",
+        /// Unique identifier of the track.
+        track_id: TrackId => "track_id",
+        /// Formula of cars being raced.
+        formula: Formula => "formula",
+        /// Time left in the session in seconds.
+        session_time_left: u16 => "session_time_left",
+        /// Session's duration in seconds.
+        session_duration: u16 => "session_duration",
+        /// Pit lane's speed limit in kilometres per hour.
+        pit_speed_limit: u8 => "pit_speed_limit",
+        /// Whether the game is paused.
+        game_paused: bool => "game_paused",
+        /// Whether the player is spectating.
+        is_spectating: bool => "is_spectating",
+        /// Index of the car being spectated.
+        spectator_car_index: u8 => "spectator_car_index",
+        /// Whether SLI Pro support is active.
+        sli_pro_native_support: bool => "sli_pro_native_support",
+        /// Number of marshal zones to follow.
+        num_marshal_zones: u8 => "num_marshal_zones",
+        /// List of marshal zones.
+        /// Should have a size equal to
+        /// [`num_marshal_zones`](field@crate::packets::F1PacketSession::num_marshal_zones).
+        marshal_zones: Vec<MarshalZone> => "marshal_zones",
+        /// Safety car deployment status.
+        safety_car_status: SafetyCarStatus => "safety_car_status",
+        /// Whether this game is online.
+        network_game: bool => "network_game",
+        /// Number of weather samples to follow.
+        num_weather_forecast_samples: u8 => "num_weather_forecast_samples",
+        /// List of up to weather forecast samples.
+        /// Should have a size equal to
+        /// [`num_weather_forecast_samples`](field@crate::packets::F1PacketSession::num_weather_forecast_samples).
+        weather_forecast_samples: Vec<WeatherForecastSample> => "weather_forecast_samples",
+        /// Weather forecast accuracy.
+        forecast_accuracy: ForecastAccuracy => "forecast_accuracy",
+        /// AI difficulty rating in range `(0..=110)`.
+        ai_difficulty: u8 => "ai_difficulty",
+        /// Identifier for season - persists across saves.
+        season_link_identifier: u32 => "season_link_identifier",
+        /// Identifier for weekend - persists across saves.
+        weekend_link_identifier: u32 => "weekend_link_identifier",
+        /// Identifier for session - persists across saves.
+        session_link_identifier: u32 => "session_link_identifier",
+        /// Ideal lap for the player to pit on for current strategy.
+        pit_stop_window_ideal_lap: u8 => "pit_stop_window_ideal_lap",
+        /// The latest lap for the player to pit on for current strategy.
+        pit_stop_window_latest_lap: u8 => "pit_stop_window_latest_lap",
+        /// Predicted position for the player to rejoin at.
+        pit_stop_rejoin_position: u8 => "pit_stop_rejoin_position",
+        /// Whether the steering assist is enabled.
+        steering_assist: bool => "steering_assist",
+        /// Type of braking assist enabled.
+        braking_assist: BrakingAssist => "braking_assist",
+        /// Type of gearbox assist enabled.
+        gearbox_assist: GearboxAssist => "gearbox_assist",
+        /// Whether the pit assist is enabled.
+        pit_assist: bool => "pit_assist",
+        /// Whether the pit release assist is enabled.
+        pit_release_assist: bool => "pit_release_assist",
+        /// Whether the ERS assist is enabled.
+        ers_assist: bool => "ers_assist",
+        /// Whether the DRS assist is enabled.
+        drs_assist: bool => "drs_assist",
+        /// Type of the dynamic racing line assist.
+        dynamic_racing_line: DynamicRacingLine => "dynamic_racing_line",
+        /// Type of the dynamic racing line (2D/3D).
+        dynamic_racing_line_type: DynamicRacingLineType => "dynamic_racing_line_type",
+        /// Game mode's identifier.
+        game_mode: GameMode => "game_mode",
+        /// Rule set's identifier.
+        rule_set: RuleSet => "rule_set",
+        /// Local time of day as minutes since midnight.
+        time_of_day: u32 => "time_of_day",
+        /// Session's length.
+        session_length: SessionLength => "session_length",
+        /// Speed unit used by player 1.
+        /// Available from the 2023 format onwards.
+        speed_unit_lead_player: Option<SpeedUnit> => "speed_unit_lead_player",
+        /// Temperature unit used by player 1.
+        /// Available from the 2023 format onwards.
+        temperature_unit_lead_player: Option<TemperatureUnit> => "temperature_unit_lead_player",
+        /// Speed unit used by player 2.
+        /// Available from the 2023 format onwards.
+        speed_unit_secondary_player: Option<SpeedUnit> => "speed_unit_secondary_player",
+        /// Temperature unit used by player 2.
+        /// Available from the 2023 format onwards.
+        temperature_unit_secondary_player: Option<TemperatureUnit> => "temperature_unit_secondary_player",
+        /// Number of full safety cars called during the session.
+        /// Available from the 2023 format onwards.
+        num_safety_car_periods: u8 => "num_safety_car_periods",
+        /// Number of virtual safety cars called during the session.
+        /// Available from the 2023 format onwards.
+        num_virtual_safety_car_periods: u8 => "num_virtual_safety_car_periods",
+        /// Number of red flags called during the session.
+        /// Available from the 2023 format onwards.
+        num_red_flag_periods: u8 => "num_red_flag_periods",
+        /// Whether equal car performance is enabled.
+        /// Available from the 2024 format onwards.
+        equal_car_performance: bool => "equal_car_performance",
+        /// Recovery mode assist.
+        /// Available from the 2024 format onwards.
+        recovery_mode: Option<RecoveryMode> => "recovery_mode",
+        /// Flashback limit type.
+        /// Available from the 2024 format onwards.
+        flashback_limit: Option<FlashbackLimit> => "flashback_limit",
+        /// Surface simulation type.
+        /// Available from the 2024 format onwards.
+        surface_sim_type: Option<SurfaceSimType> => "surface_sim_type",
+        /// Low fuel driving difficulty.
+        /// Available from the 2024 format onwards.
+        low_fuel_mode: Option<LowFuelMode> => "low_fuel_mode",
+        /// Race starts assist.
+        /// Available from the 2024 format onwards.
+        race_starts: Option<RaceStarts> => "race_starts",
+        /// Tyre temperature simulation type.
+        /// Available from the 2024 format onwards.
+        tyre_temperature: Option<TyreTemperature> => "tyre_temperature",
+        /// Whether the pit lane tyre simulation
+        /// (cold tyres and low grip right after a stop) is enabled.
+        /// Available from the 2024 format onwards.
+        pit_lane_tyre_sim: bool => "pit_lane_tyre_sim",
+        /// Car damage simulation type.
+        /// Available from the 2024 format onwards.
+        car_damage: Option<CarDamage> => "car_damage",
+        /// Car damage rate.
+        /// Available from the 2024 format onwards.
+        car_damage_rate: Option<CarDamageRate> => "car_damage_rate",
+        /// Collision simulation type.
+        /// Available from the 2024 format onwards.
+        collisions: Option<Collisions> => "collisions",
+        /// Whether collisions are disabled only for lap 1.
+        /// Available from the 2024 format onwards.
+        collisions_off_for_first_lap_only: bool => "collisions_off_for_first_lap_only",
+        /// Whether unsafe pit release is disabled in a multiplayer game.
+        /// Available from the 2024 format onwards.
+        mp_unsafe_pit_release_disabled: bool => "mp_unsafe_pit_release_disabled",
+        /// Whether collisions get disabled for griefing in a multiplayer game.
+        /// Available from the 2024 format onwards.
+        mp_collisions_off_for_griefing: bool => "mp_collisions_off_for_griefing",
+        /// Corner cutting stringency.
+        /// Available from the 2024 format onwards.
+        corner_cutting_stringency: Option<CornerCuttingStringency> => "corner_cutting_stringency",
+        /// Whether parc fermé rules are enabled.
+        /// Available from the 2024 format onwards.
+        parc_ferme_rules: bool => "parc_ferme_rules",
+        /// Pit stop experience.
+        /// Available from the 2024 format onwards.
+        pit_stop_experience: Option<PitStopExperience> => "pit_stop_experience",
+        /// Safety car intensity.
+        /// Available from the 2024 format onwards.
+        safety_car_intensity: Option<SafetyCarIntensity> => "safety_car_intensity",
+        /// Safety car experience.
+        /// Available from the 2024 format onwards.
+        safety_car_experience: Option<SafetyCarExperience> => "safety_car_experience",
+        /// Whether formation lap is enabled.
+        /// Available from the 2024 format onwards.
+        formation_lap: bool => "formation_lap",
+        /// Formation lap experience.
+        /// Available from the 2024 format onwards.
+        formation_lap_experience: Option<FormationLapExperience> => "formation_lap_experience",
+        /// Red flag intensity.
+        /// Available from the 2024 format onwards.
+        red_flag_intensity: Option<RedFlagIntensity> => "red_flag_intensity",
+        /// Whether this single player game affects the license level.
+        /// Available from the 2024 format onwards.
+        affects_license_level_solo: bool => "affects_license_level_solo",
+        /// Whether this multiplayer game affects the license level.
+        /// Available from the 2024 format onwards.
+        affects_license_level_mp: bool => "affects_license_level_mp",
+        /// Number of sessions in the ongoing race weekend.
+        num_sessions_in_weekend: u8 => "num_sessions_in_weekend",
+        /// List of sessions that shows this weekend's structure.
+        /// Should have a size equal to
+        /// [`num_sessions_in_weekend`](field@crate::packets::F1PacketSession::num_sessions_in_weekend).
+        /// See [`session_type`](mod@crate::constants::session_type)
+        /// for possible values.
+        /// Available from the 2024 format onwards.
+        weekend_structure: Vec<u8> => "weekend_structure",
+        /// Distance (in metres) around the track where sector 2 starts.
+        /// Available from the 2024 format onwards.
+        sector2_lap_distance_start: f32 => "sector2_lap_distance_start",
+        /// Distance (in metres) around the track where sector 3 starts.
+        /// Available from the 2024 format onwards.
+        sector3_lap_distance_start: f32 => "sector3_lap_distance_start",
+    }
+
+    /// Section of the track supervised by marshals.
+    struct MarshalZone: packets::session::MarshalZone {
+        /// Fraction (in range `(0.0..1.0)`) of way through the lap the marshal zone starts.
+        zone_start: f32 => "zone_start",
+        /// Flag that's currently being waved in the marshal zone.
+        zone_flag: MarshalZoneFlag => "zone_flag",
+    }
+
+    /// Weather forecast sample for a given session.
+    struct WeatherForecastSample: packets::session::WeatherForecastSample {
+        /// Session's type.
+        /// See [`session_type`](mod@crate::constants::session_type)
+        /// for possible values.
+        session_type: u8 => "session_type",
+        /// Time in minutes the forecast is for.
+        time_offset: u8 => "time_offset",
+        /// Forecasted weather.
+        weather: Weather => "weather",
+        /// Track temperature in degrees Celsius.
+        track_temperature: i8 => "track_temperature",
+        /// Track temperature change.
+        track_temperature_change: TemperatureChange => "track_temperature_change",
+        /// Air temperature in degrees Celsius.
+        air_temperature: i8 => "air_temperature",
+        /// Air temperature change.
+        air_temperature_change: TemperatureChange => "air_temperature_change",
+        /// Chance of rain.
+        rain_percentage: u8 => "rain_percentage",
+    }
+
+    /// Data about all the lap times of cars in the session.
+    struct F1PacketLaps: packets::F1PacketLaps {
+        /// Lap data for all cars on track. Should have a size of 22.
+        data: Vec<LapData> => "data",
+        /// Index of personal best car in time trial mode (255 if invalid).
+        time_trial_pb_car_index: u8 => "time_trial_pb_car_index",
+        /// Index of rival's car in time trial mode (255 if invalid).
+        time_trial_rival_car_index: u8 => "time_trial_rival_car_index",
+    }
+
+    /// Lap data for a car on track.
+    struct LapData: packets::laps::LapData {
+        /// Last lap time in milliseconds.
+        last_lap_time_ms: u32 => "last_lap_time_ms",
+        /// Current lap time in milliseconds.
+        current_lap_time_ms: u32 => "current_lap_time_ms",
+        /// Current sector 1 time millisecond part.
+        sector1_time_ms_part: u16 => "sector1_time_ms_part",
+        /// Sector 1 whole minute part.
+        /// Available from the 2023 format onwards.
+        sector1_time_minutes_part: u8 => "sector1_time_minutes_part",
+        /// Current sector 2 time millisecond part.
+        sector2_time_ms_part: u16 => "sector2_time_ms_part",
+        /// Sector 2 whole minute part.
+        /// Available from the 2023 format onwards.
+        sector2_time_minutes_part: u8 => "sector2_time_minutes_part",
+        /// Time delta to car in front in milliseconds.
+        /// Available from the 2023 format onwards.
+        delta_to_car_in_front_ms_part: u16 => "delta_to_car_in_front_ms_part",
+        /// Time delta to car in front whole minute part.
+        /// Available from the 2024 format onwards.
+        delta_to_car_in_front_minutes_part: u8 => "delta_to_car_in_front_minutes_part",
+        /// Time delta to race leader in milliseconds.
+        /// Available from the 2023 format onwards.
+        delta_to_race_leader_ms: u16 => "delta_to_race_leader_ms",
+        /// Time delta to car in front whole minute part.
+        /// Available from the 2024 format onwards.
+        delta_to_race_leader_minutes_part: u8 => "delta_to_race_leader_minutes_part",
+        /// The distance the vehicle is around current lap in metres.
+        /// It may be negative if the start/finish line hasn’t been crossed yet.
+        lap_distance: f32 => "lap_distance",
+        /// The total distance the vehicle has gone around in this session in metres.
+        /// It may be negative if the start/finish line hasn’t been crossed yet.
+        total_distance: f32 => "total_distance",
+        /// Delta for the safety car in seconds.
+        safety_car_delta: f32 => "safety_car_delta",
+        /// Car's race position.
+        car_position: u8 => "car_position",
+        /// Current lap number.
+        current_lap_num: u8 => "current_lap_num",
+        /// Car's pit status.
+        pit_status: PitStatus => "pit_status",
+        /// Number of pit stops taken in this race.
+        num_pit_stops: u8 => "num_pit_stops",
+        /// Zero-based number of the sector the driver is currently going through.
+        sector: Sector => "sector",
+        /// Whether the current lap is invalid.
+        current_lap_invalid: bool => "current_lap_invalid",
+        /// Accumulated time penalties to be added in seconds.
+        penalties: u8 => "penalties",
+        /// Accumulated number of warnings issued.
+        total_warnings: u8 => "total_warnings",
+        /// Accumulated number of corner cutting warnings issued.
+        /// Available from the 2023 format onwards.
+        corner_cutting_warnings: u8 => "corner_cutting_warnings",
+        /// Number of unserved drive through penalties left to serve.
+        num_unserved_drive_through_pens: u8 => "num_unserved_drive_through_pens",
+        /// Number of unserved stop-go penalties left to serve.
+        num_unserved_stop_go_pens: u8 => "num_unserved_stop_go_pens",
+        /// The grid position the vehicle started the race in.
+        grid_position: u8 => "grid_position",
+        /// Status of the driver.
+        driver_status: DriverStatus => "driver_status",
+        /// Status of the driver's result.
+        result_status: ResultStatus => "result_status",
+        /// Whether the pit lane timer is active.
+        pit_lane_timer_active: bool => "pit_lane_timer_active",
+        /// Current time spent in the pit lane in milliseconds.
+        pit_lane_time_in_lane_ms: u16 => "pit_lane_time_in_lane_ms",
+        /// Time of the actual pit stop in milliseconds.
+        pit_stop_timer_ms: u16 => "pit_stop_timer_ms",
+        /// Whether the car should serve a penalty at this stop.
+        pit_stop_should_serve_pen: bool => "pit_stop_should_serve_pen",
+        /// Fastest speed through speed trap for this car in kilometres per hour.
+        /// Available from the 2024 format onwards.
+        speed_trap_fastest_speed: f32 => "speed_trap_fastest_speed",
+        /// Number of the lap the fastest speed was achieved on
+        /// (255 means "not set").
+        /// Available from the 2024 format onwards.
+        speed_trap_fastest_lap: u8 => "speed_trap_fastest_lap",
+    }
+
+    /// Various notable events that happen during a session.
+    struct F1PacketEvent: packets::F1PacketEvent {
+        /// 4-letter event code.
+        code: String => "code",
+        /// Extra data for this event.
+        details: EventDetails => "details",
+    }
+
+    /// Data of participants in the session, mostly relevant for multiplayer.
+    struct F1PacketParticipants: packets::F1PacketParticipants {
+        /// Number of active cars in the session.
+        num_active_cars: u8 => "num_active_cars",
+        /// Data for all participants.
+        /// Should have a size equal to
+        /// [`num_active_cars`](field@crate::packets::F1PacketParticipants::num_active_cars).
+        data: Vec<ParticipantsData> => "data",
+    }
+
+    struct ParticipantsData: packets::participants::ParticipantsData {
+        /// Whether the vehicle is controlled by AI.
+        ai_controlled: bool => "ai_controlled",
+        /// Driver's ID.
+        /// See [`driver_id`](mod@crate::constants::driver_id)
+        /// for possible values.
+        driver_id: u8 => "driver_id",
+        /// Unique ID for network players.
+        network_id: u8 => "network_id",
+        /// Team's ID.
+        /// See [`team_id`](mod@crate::constants::team_id) for possible values.
+        team_id: u8 => "team_id",
+        /// Whether my team is being used.
+        my_team: bool => "my_team",
+        /// Race number of the car.
+        race_number: u8 => "race_number",
+        /// Driver's nationality.
+        nationality: Nationality => "nationality",
+        /// Driver's name. Invalid UTF-8 sequences get replaced with U+FFFD.
+        name: String => "name",
+        /// Whether any invalid UTF-8 sequences in the name have been replaced.
+        #[serde(default)]
+        name_lossy: bool => "name_lossy",
+        /// Player's UDP visibility setting.
+        your_telemetry: Option<YourTelemetry> => "your_telemetry",
+        /// Whether this player's "show online names" setting is on.
+        /// Available from the 2023 format onwards.
+        show_online_names: bool => "show_online_names",
+        /// F1 World tech level.
+        /// Available from the 2024 format onwards.
+        tech_level: u16 => "tech_level",
+        /// Player's platform.
+        /// Available from the 2023 format onwards.
+        platform: Option<Platform> => "platform",
+    }
+
+    /// Car setups for all cars in the race.
+    /// In multiplayer games, other player cars will appear as blank.
+    /// You will only be able to see your car setup and AI cars.
+    struct F1PacketCarSetups: packets::F1PacketCarSetups {
+        /// Setup data for all cars on track. Should have a size of 22.
+        data: Vec<CarSetupData> => "data",
+        /// Value of front wing after next pit stop - player only.
+        /// Available from the 2024 format onwards
+        next_front_wing_value: f32 => "next_front_wing_value",
+    }
+
+    struct CarSetupData: packets::car_setups::CarSetupData {
+        /// Front wing aero.
+        front_wing: u8 => "front_wing",
+        /// Rear wing aero.
+        rear_wing: u8 => "rear_wing",
+        /// Differential adjustment on throttle (percentage).
+        on_throttle: u8 => "on_throttle",
+        /// Differential adjustment off throttle (percentage).
+        off_throttle: u8 => "off_throttle",
+        /// Front camber angle (suspension geometry).
+        front_camber: f32 => "front_camber",
+        /// Rear camber angle (suspension geometry).
+        rear_camber: f32 => "rear_camber",
+        /// Front toe angle (suspension geometry).
+        front_toe: f32 => "front_toe",
+        /// Rear toe angle (suspension geometry).
+        rear_toe: f32 => "rear_toe",
+        /// Front suspension.
+        front_suspension: u8 => "front_suspension",
+        /// Rear suspension.
+        rear_suspension: u8 => "rear_suspension",
+        /// Front anti-roll bar.
+        front_anti_roll_bar: u8 => "front_anti_roll_bar",
+        /// Rear anti-roll bar.
+        rear_anti_roll_bar: u8 => "rear_anti_roll_bar",
+        /// Front ride height.
+        front_suspension_height: u8 => "front_suspension_height",
+        /// Rear ride height.
+        rear_suspension_height: u8 => "rear_suspension_height",
+        /// Brake pressure (percentage).
+        brake_pressure: u8 => "brake_pressure",
+        /// Brake bias (percentage).
+        brake_bias: u8 => "brake_bias",
+        /// Engine braking (percentage).
+        /// Available from the 2024 format onwards.
+        engine_braking: u8 => "engine_braking",
+        /// Rear left tyre pressure.
+        rear_left_tyre_pressure: f32 => "rear_left_tyre_pressure",
+        /// Rear right tyre pressure.
+        rear_right_tyre_pressure: f32 => "rear_right_tyre_pressure",
+        /// Front left tyre pressure.
+        front_left_tyre_pressure: f32 => "front_left_tyre_pressure",
+        /// Front right tyre pressure.
+        front_right_tyre_pressure: f32 => "front_right_tyre_pressure",
+        /// Ballast.
+        ballast: u8 => "ballast",
+        /// Fuel load.
+        fuel_load: f32 => "fuel_load",
+    }
+
+    /// Telemetry (such as speed, DRS, throttle application, etc.)
+    /// for all cars in the race.
+    struct F1PacketCarTelemetry: packets::F1PacketCarTelemetry {
+        /// Telemetry data for all cars on track. Should have a size of 22.
+        data: Vec<CarTelemetryData> => "data",
+        /// Index of currently open MFD panel for player 1.
+        mfd_panel_index: MfdPanelIndex => "mfd_panel_index",
+        /// Index of currently open MFD panel for player 2.
+        mfd_panel_index_secondary_player: MfdPanelIndex => "mfd_panel_index_secondary_player",
+        /// Suggested gear (0 if no gear suggested).
+        suggested_gear: i8 => "suggested_gear",
+    }
+
+    struct CarTelemetryData: packets::car_telemetry::CarTelemetryData {
+        /// Speed of the car in kilometres per hour.
+        speed: u16 => "speed",
+        /// Amount of throttle applied. Value in range `(0.0..=1.0)`.
+        throttle: f32 => "throttle",
+        /// Steering lock. Value in range `(-1.0..=1.0)`.
+        steer: f32 => "steer",
+        /// Amount of brake applied. Value in range `(0.0..=1.0)`.
+        brake: f32 => "brake",
+        /// Amount of clutch applied (percentage).
+        clutch: u8 => "clutch",
+        /// Selected gear. Neutral = 0, reverse = -1.
+        gear: i8 => "gear",
+        /// Engine RPM.
+        engine_rpm: u16 => "engine_rpm",
+        /// Whether DRS is enabled.
+        drs_enabled: bool => "drs_enabled",
+        /// Rev lights indicator (percentage).
+        rev_lights_percent: u8 => "rev_lights_percent",
+        /// Bitmap of active rev lights.
+        rev_lights_bit_value: u16 => "rev_lights_bit_value",
+        /// Brakes' temperature values in degrees Celsius.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        brakes_temperature: [u16; 4] => "brakes_temperature",
+        /// Tyres' surface temperature values in degrees Celsius.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        tyres_surface_temperature: [u8; 4] => "tyres_surface_temperature",
+        /// Tyres' inner temperature values in degrees Celsius.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        tyres_inner_temperature: [u8; 4] => "tyres_inner_temperature",
+        /// Engine's temperature in degrees Celsius
+        engine_temperature: u16 => "engine_temperature",
+        /// Tyre pressure values in PSI.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        tyres_pressure: [f32; 4] => "tyres_pressure",
+        /// Driving surface of each tyre.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        surface_type: [Surface; 4] => "surface_type",
+    }
+
+    /// Car status data for each car in the race.
+    struct F1PacketCarStatus: packets::F1PacketCarStatus {
+        /// Car status data for all cars. Should have a size of 22.
+        data: Vec<CarStatusData> => "data",
+    }
+
+    struct CarStatusData: packets::car_status::CarStatusData {
+        /// How much traction control is enabled.
+        traction_control: TractionControl => "traction_control",
+        /// Whether ABS is enabled.
+        anti_lock_brakes: bool => "anti_lock_brakes",
+        /// Fuel mix currently in use.
+        fuel_mix: FuelMix => "fuel_mix",
+        /// Front brake bias (percentage).
+        front_brake_bias: u8 => "front_brake_bias",
+        /// Whether the pit limiter is enabled.
+        pit_limiter_enabled: bool => "pit_limiter_enabled",
+        /// Current fuel mass.
+        fuel_in_tank: f32 => "fuel_in_tank",
+        /// Fuel capacity.
+        fuel_capacity: f32 => "fuel_capacity",
+        /// Fuel remaining in terms of laps.
+        fuel_remaining_laps: f32 => "fuel_remaining_laps",
+        /// Car's max RPM, point of rev limiter.
+        max_rpm: u16 => "max_rpm",
+        /// Car's idle RPM.
+        idle_rpm: u16 => "idle_rpm",
+        /// Maximum number of gears.
+        max_gears: u8 => "max_gears",
+        /// Whether DRS can be used (might be unknown).
+        drs_allowed: DrsAllowed => "drs_allowed",
+        /// 0 = DRS is unavailable, Non-zero = DRS will be available in X metres.
+        drs_activation_distance: u16 => "drs_activation_distance",
+        /// Actual tyre compound currently in use.
+        actual_tyre_compound: ActualTyreCompound => "actual_tyre_compound",
+        /// Visible tyre compound currently in use.
+        visual_tyre_compound: VisualTyreCompound => "visual_tyre_compound",
+        /// Age of the current set of tyres in laps.
+        tyres_age_laps: u8 => "tyres_age_laps",
+        /// Flag the driver is currently being shown.
+        vehicle_fia_flag: VehicleFiaFlag => "vehicle_fia_flag",
+        /// Engine power output of ICE in watts.
+        /// Available from the 2023 format onwards.
+        engine_power_ice: f32 => "engine_power_ice",
+        /// Engine power output of MGU-K in watts.
+        /// Available from the 2023 format onwards.
+        engine_power_mguk: f32 => "engine_power_mguk",
+        /// ERS energy store in Joules.
+        ers_store_energy: f32 => "ers_store_energy",
+        /// ERS deployment mode.
+        ers_deploy_mode: ErsDeployMode => "ers_deploy_mode",
+        /// ERS energy harvested this lap by the MGU-K.
+        ers_harvested_this_lap_mguk: f32 => "ers_harvested_this_lap_mguk",
+        /// ERS energy harvested this lap by the MGU-H.
+        ers_harvested_this_lap_mguh: f32 => "ers_harvested_this_lap_mguh",
+        /// ERS energy deployed this lap.
+        ers_deployed_this_lap: f32 => "ers_deployed_this_lap",
+        /// Whether the car has paused in a network game.
+        network_paused: bool => "network_paused",
+    }
+
+    /// Final classification confirmation at the end of a race.
+    struct F1PacketFinalClassification: packets::F1PacketFinalClassification {
+        /// Number of cars in the final classification.
+        num_cars: u8 => "num_cars",
+        /// Final classification data for all cars.
+        /// Should have a size equal to
+        /// [`num_cars`](field@crate::packets::F1PacketFinalClassification::num_cars).
+        data: Vec<FinalClassificationData> => "data",
+    }
+
+    struct FinalClassificationData: packets::final_classification::FinalClassificationData {
+        /// Finishing position.
+        position: u8 => "position",
+        /// Number of laps completed.
+        num_laps: u8 => "num_laps",
+        /// Grid position of the car.
+        grid_position: u8 => "grid_position",
+        /// Number of points scored.
+        points: u8 => "points",
+        /// Number of pit stops made.
+        num_pit_stops: u8 => "num_pit_stops",
+        /// Result status.
+        result_status: ResultStatus => "result_status",
+        /// Best lap time of the session in milliseconds.
+        best_lap_time_ms: u32 => "best_lap_time_ms",
+        /// Total race time in seconds (without penalties).
+        total_race_time: f64 => "total_race_time",
+        /// Total penalties accumulated in seconds.
+        penalties_time: u8 => "penalties_time",
+        /// Number of penalties applied to this driver.
+        num_penalties: u8 => "num_penalties",
+        /// Number of tyre stints.
+        num_tyre_stints: u8 => "num_tyre_stints",
+        /// Actual tyres used by the driver.
+        /// Should have a size equal to
+        /// [`num_tyre_stints`](field@FinalClassificationData::num_tyre_stints).
+        tyre_stints_actual: Vec<ActualTyreCompound> => "tyre_stints_actual",
+        /// Visual tyres used by the driver.
+        /// Should have a size equal to
+        /// [`num_tyre_stints`](field@FinalClassificationData::num_tyre_stints).
+        tyre_stints_visual: Vec<VisualTyreCompound> => "tyre_stints_visual",
+        /// The lap numbers the stints end on.
+        /// Should have a size equal to
+        /// [`num_tyre_stints`](field@FinalClassificationData::num_tyre_stints).
+        tyre_stints_end_laps: Vec<u8> => "tyre_stints_end_laps",
+    }
+
+    /// Packet detailing all the players that are currently in a multiplayer lobby.
+    struct F1PacketLobby: packets::F1PacketLobby {
+        /// Number of players in the lobby.
+        num_players: u8 => "num_players",
+        /// Lobby info data for all players.
+        /// Should have a size equal to
+        /// [`num_players`](field@crate::packets::F1PacketLobby::num_players).
+        data: Vec<LobbyInfoData> => "data",
+    }
+
+    struct LobbyInfoData: packets::lobby::LobbyInfoData {
+        /// Whether the vehicle is controlled by AI.
+        ai_controlled: bool => "ai_controlled",
+        /// Team's ID.
+        /// See [`team_id`](mod@crate::constants::team_id) for possible values.
+        team_id: u8 => "team_id",
+        /// Driver's nationality.
+        nationality: Nationality => "nationality",
+        /// Player's platform.
+        /// Available from the 2023 format onwards.
+        platform: Option<Platform> => "platform",
+        /// Driver's name. Invalid UTF-8 sequences get replaced with U+FFFD.
+        name: String => "name",
+        /// Whether any invalid UTF-8 sequences in the name have been replaced.
+        #[serde(default)]
+        name_lossy: bool => "name_lossy",
+        /// Player's car number.
+        car_number: u8 => "car_number",
+        /// The player's "Your Telemetry" visibility setting.
+        /// Available from the 2024 format onwards.
+        your_telemetry: Option<YourTelemetry> => "your_telemetry",
+        /// Whether the player has enabled the "Show online names" setting.
+        /// Available from the 2024 format onwards.
+        show_online_names: bool => "show_online_names",
+        /// F1 World tech level.
+        /// Available from the 2024 format onwards.
+        tech_level: u16 => "tech_level",
+        /// Readiness status.
+        ready_status: ReadyStatus => "ready_status",
+    }
+
+    /// Car damage parameters for all cars in the session.
+    struct F1PacketCarDamage: packets::F1PacketCarDamage {
+        /// Car damage data. Should have a size of 22.
+        data: Vec<CarDamageData> => "data",
+    }
+
+    #[allow(clippy::struct_excessive_bools)]
+    struct CarDamageData: packets::car_damage::CarDamageData {
+        /// Tyre wear percentage for all wheels.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        tyres_wear: [f32; 4] => "tyres_wear",
+        /// Tyre damage percentage for all wheels.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        tyres_damage: [u8; 4] => "tyres_damage",
+        /// Brake damage percentage for all wheels.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        brakes_damage: [u8; 4] => "brakes_damage",
+        /// Front left wing damage (percentage).
+        front_left_wing_damage: u8 => "front_left_wing_damage",
+        /// Front right wing damage (percentage).
+        front_right_wing_damage: u8 => "front_right_wing_damage",
+        /// Rear wing damage (percentage).
+        rear_wing_damage: u8 => "rear_wing_damage",
+        /// Floor damage (percentage).
+        floor_damage: u8 => "floor_damage",
+        /// Diffuser damage (percentage).
+        diffuser_damage: u8 => "diffuser_damage",
+        /// Sidepod damage (percentage).
+        sidepod_damage: u8 => "sidepod_damage",
+        /// Whether DRS has failed.
+        drs_fault: bool => "drs_fault",
+        /// Whether ERS has failed.
+        ers_fault: bool => "ers_fault",
+        /// Gearbox damage (percentage).
+        gearbox_damage: u8 => "gearbox_damage",
+        /// Engine damage (percentage).
+        engine_damage: u8 => "engine_damage",
+        /// Engine MGU-H wear (percentage).
+        engine_mguh_wear: u8 => "engine_mguh_wear",
+        /// Engine ES wear (percentage).
+        engine_es_wear: u8 => "engine_es_wear",
+        /// Engine CE wear (percentage).
+        engine_ce_wear: u8 => "engine_ce_wear",
+        /// Engine ICE wear (percentage).
+        engine_ice_wear: u8 => "engine_ice_wear",
+        /// Engine MGU-K wear (percentage).
+        engine_mguk_wear: u8 => "engine_mguk_wear",
+        /// Engine TC wear (percentage).
+        engine_tc_wear: u8 => "engine_tc_wear",
+        /// Whether the engine has blown.
+        engine_blown: bool => "engine_blown",
+        /// Whether the engine has seized.
+        engine_seized: bool => "engine_seized",
+    }
+
+    /// Packet detailing lap and tyre data history for a given driver in the session.
+    struct F1PacketSessionHistory: packets::F1PacketSessionHistory {
+        /// Index of the car this packet refers to.
+        vehicle_index: u8 => "vehicle_index",
+        /// Number of laps in the data (including the current one).
+        num_laps: u8 => "num_laps",
+        /// Number of tyre stints in the data (including the current one).
+        num_tyre_stints: u8 => "num_tyre_stints",
+        /// Number of the lap the best lap time was achieved on.
+        best_lap_time_lap_num: u8 => "best_lap_time_lap_num",
+        /// Number of the lap the best sector 1 time was achieved on.
+        best_sector1_lap_num: u8 => "best_sector1_lap_num",
+        /// Number of the lap the best sector 2 time was achieved on.
+        best_sector2_lap_num: u8 => "best_sector2_lap_num",
+        /// Number of the lap the best sector 3 time was achieved on.
+        best_sector3_lap_num: u8 => "best_sector3_lap_num",
+        /// Lap history. Should have a size equal to
+        /// [`num_laps`](field@crate::packets::F1PacketSessionHistory::num_laps).
+        lap_history_data: Vec<LapHistoryData> => "lap_history_data",
+        /// Tyre stint history.
+        /// Should have a size equal to
+        /// [`num_tyre_stints`](field@crate::packets::F1PacketSessionHistory::num_tyre_stints).
+        tyre_stint_history_data: Vec<TyreStintHistoryData> => "tyre_stint_history_data",
+    }
+
+    struct LapHistoryData: packets::session_history::LapHistoryData {
+        /// Lap time in milliseconds.
+        lap_time_ms: u32 => "lap_time_ms",
+        /// Sector 1 time milliseconds part.
+        sector1_time_ms_part: u16 => "sector1_time_ms_part",
+        /// Sector 1 whole minute part.
+        /// Available from the 2023 format onwards.
+        sector1_time_minutes_part: u8 => "sector1_time_minutes_part",
+        /// Sector 2 time milliseconds part.
+        sector2_time_ms_part: u16 => "sector2_time_ms_part",
+        /// Sector 2 whole minute part.
+        /// Available from the 2023 format onwards.
+        sector2_time_minutes_part: u8 => "sector2_time_minutes_part",
+        /// Sector 3 time milliseconds part.
+        sector3_time_ms_part: u16 => "sector3_time_ms_part",
+        /// Sector 3 whole minute part.
+        /// Available from the 2023 format onwards.
+        sector3_time_minutes: u8 => "sector3_time_minutes",
+        /// Bitmap of lap validity across all sectors and overall.
+        lap_valid_bit_flags: u8 => "lap_valid_bit_flags",
+    }
+
+    struct TyreStintHistoryData: packets::session_history::TyreStintHistoryData {
+        /// Lap the tyre usage ends on (255 if current tyre).
+        end_lap: u8 => "end_lap",
+        /// Actual tyre compound used.
+        actual_tyre_compound: ActualTyreCompound => "actual_tyre_compound",
+        /// Visual tyre compound used.
+        visual_tyre_compound: VisualTyreCompound => "visual_tyre_compound",
+    }
+
+    /// In-depth details about tyre sets assigned to a vehicle during the session.
+    /// Available from the 2023 format onwards.
+    struct F1PacketTyreSets: packets::F1PacketTyreSets {
+        /// Index of the car this packet relates to.
+        vehicle_index: u8 => "vehicle_index",
+        /// 13 dry + 7 wet tyre sets.
+        data: Vec<TyreSetData> => "data",
+        /// Index of fitted tyre set.
+        fitted_index: u8 => "fitted_index",
+    }
+
+    struct TyreSetData: packets::tyre_sets::TyreSetData {
+        /// Actual tyre compound.
+        actual_tyre_compound: ActualTyreCompound => "actual_tyre_compound",
+        /// Visual tyre compound.
+        visual_tyre_compound: VisualTyreCompound => "visual_tyre_compound",
+        /// Tyre wear (percentage).
+        wear: u8 => "wear",
+        /// Whether this set is currently available.
+        available: bool => "available",
+        /// Recommended session for this tyre set.
+        /// See [`session_type`](mod@crate::constants::session_type)
+        /// for possible values.
+        recommended_session: u8 => "recommended_session",
+        /// Laps left in this set.
+        life_span: u8 => "life_span",
+        /// Max number of laps recommended for this compound.
+        usable_life: u8 => "usable_life",
+        /// Lap time delta in milliseconds compared to fitted set.
+        lap_delta_time: i16 => "lap_delta_time",
+        /// Whether this set is fitted or not.
+        fitted: bool => "fitted",
+    }
+
+    /// Extended motion data for player's car. Available as a:
+    /// - part of [`F1PacketMotion`] in the 2022 format
+    /// - standalone packet from the 2023 format onwards
+    struct F1PacketMotionEx: packets::F1PacketMotionEx {
+        /// Positions of suspension for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        suspension_position: [f32; 4] => "suspension_position",
+        /// Velocity values of suspension for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        suspension_velocity: [f32; 4] => "suspension_velocity",
+        /// Acceleration values of suspension for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        suspension_acceleration: [f32; 4] => "suspension_acceleration",
+        /// Speed of each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        wheel_speed: [f32; 4] => "wheel_speed",
+        /// Slip ratio for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        wheel_slip_ratio: [f32; 4] => "wheel_slip_ratio",
+        /// Slip angles for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        /// Available from the 2024 format onwards.
+        wheel_slip_angle: [f32; 4] => "wheel_slip_angle",
+        /// Lateral forces for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        /// Available from the 2024 format onwards.
+        wheel_lat_force: [f32; 4] => "wheel_lat_force",
+        /// Longitudinal forces for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        /// Available from the 2024 format onwards.
+        wheel_long_force: [f32; 4] => "wheel_long_force",
+        /// Height of centre of gravity above ground.
+        /// Available from the 2024 format onwards.
+        height_of_cog_above_ground: f32 => "height_of_cog_above_ground",
+        /// X velocity in local space.
+        local_velocity_x: f32 => "local_velocity_x",
+        /// Y velocity in local space.
+        local_velocity_y: f32 => "local_velocity_y",
+        /// Z velocity in local space.
+        local_velocity_z: f32 => "local_velocity_z",
+        /// Angular velocity X component.
+        angular_velocity_x: f32 => "angular_velocity_x",
+        /// Angular velocity Y component.
+        angular_velocity_y: f32 => "angular_velocity_y",
+        /// Angular velocity Z component.
+        angular_velocity_z: f32 => "angular_velocity_z",
+        /// Angular acceleration X component.
+        angular_acceleration_x: f32 => "angular_acceleration_x",
+        /// Angular acceleration Y component.
+        angular_acceleration_y: f32 => "angular_acceleration_y",
+        /// Angular acceleration Z component.
+        angular_acceleration_z: f32 => "angular_acceleration_z",
+        /// Current front wheels angle in radians.
+        front_wheels_angle: f32 => "front_wheels_angle",
+        /// Vertical forces for each wheel.
+        /// See [`wheel_index`](mod@crate::constants::wheel_index)
+        /// for wheel order.
+        /// Available from the 2023 format onwards.
+        wheel_vert_force: [f32; 4] => "wheel_vert_force",
+        /// Front plank edge height above road surface.
+        /// Available from the 2024 format onwards.
+        front_aero_height: f32 => "front_aero_height",
+        /// Rear plank edge height above road surface.
+        /// Available from the 2024 format onwards.
+        rear_aero_height: f32 => "rear_aero_height",
+        /// Roll angle of the front suspension.
+        /// Available from the 2024 format onwards.
+        front_roll_angle: f32 => "front_roll_angle",
+        /// Roll angle of the rear suspension.
+        /// Available from the 2024 format onwards.
+        rear_roll_angle: f32 => "rear_roll_angle",
+        /// Yaw angle of the chassis relative to the direction of motion - radians.
+        /// Available from the 2024 format onwards.
+        chassis_yaw: f32 => "chassis_yaw",
+    }
+
+    /// Extra information that's only relevant to time trial game mode.
+    /// Available from the 2024 format onwards.
+    struct F1PacketTimeTrial: packets::F1PacketTimeTrial {
+        /// Data set of player's best run this session.
+        player_session_best_data_set: TimeTrialDataSet => "player_session_best_data_set",
+        /// Data set of player's personal best run.
+        personal_best_data_set: TimeTrialDataSet => "personal_best_data_set",
+        /// Data set of rival's best run.
+        rival_data_set: TimeTrialDataSet => "rival_data_set",
+    }
+
+    #[allow(clippy::struct_excessive_bools)]
+    struct TimeTrialDataSet: packets::time_trial::TimeTrialDataSet {
+        /// Index of the car this data set relates to.
+        vehicle_index: u8 => "vehicle_index",
+        /// Team's ID.
+        /// See [`team_id`](mod@crate::constants::team_id) for possible values.
+        team_id: u8 => "team_id",
+        /// Lap time in milliseconds.
+        lap_time_ms: u32 => "lap_time_ms",
+        /// Sector 1 time in milliseconds.
+        sector1_time_ms: u32 => "sector1_time_ms",
+        /// Sector 2 time in milliseconds.
+        sector2_time_ms: u32 => "sector2_time_ms",
+        /// Sector 3 time in milliseconds.
+        sector3_time_ms: u32 => "sector3_time_ms",
+        /// Type of traction control assist enabled.
+        traction_control: TractionControl => "traction_control",
+        /// Type of gearbox assist enabled.
+        gearbox_assist: GearboxAssist => "gearbox_assist",
+        /// Whether ABS is enabled.
+        anti_lock_brakes: bool => "anti_lock_brakes",
+        /// Whether equal car performance is enabled.
+        equal_car_performance: bool => "equal_car_performance",
+        /// Whether custom setup is in use.
+        custom_setup: bool => "custom_setup",
+        /// Whether this lap is valid.
+        valid: bool => "valid",
+    }
+}
+
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EventDetails {
+    /// Sent when the session starts.
+    #[serde(rename = "SessionStarted")]
+    SessionStarted,
+    /// Sent when the session ends.
+    #[serde(rename = "SessionEnded")]
+    SessionEnded,
+    /// Sent when a driver achieves the fastest lap.
+    #[serde(rename = "FastestLap")]
+    FastestLap {
+        /// Index of the car that's achieved the fastest lap.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+        /// Lap time in seconds.
+        #[serde(rename = "lap_time")]
+        lap_time: f32,
+    },
+    /// Sent when a driver retires.
+    #[serde(rename = "Retirement")]
+    Retirement {
+        /// Index of the retiring car.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+    },
+    /// Sent when race control enable DRS.
+    #[serde(rename = "DrsEnabled")]
+    DrsEnabled,
+    /// Sent when race control disable DRS.
+    #[serde(rename = "DrsDisabled")]
+    DrsDisabled,
+    /// Sent when your teammate enters the pit lane.
+    #[serde(rename = "TeamMateInPits")]
+    TeamMateInPits {
+        /// Index of teammate's car.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+    },
+    /// Sent when the chequered flag has been waved.
+    #[serde(rename = "ChequeredFlag")]
+    ChequeredFlag,
+    /// Sent when the race winner has been announced.
+    #[serde(rename = "RaceWinner")]
+    RaceWinner {
+        /// Index of race winner's car.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+    },
+    /// Sent when a penalty has been issued.
+    #[serde(rename = "Penalty")]
+    Penalty {
+        /// Penalty type.
+        #[serde(rename = "penalty_type")]
+        penalty_type: PenaltyType,
+        /// Infringement type.
+        #[serde(rename = "infringement_type")]
+        infringement_type: InfringementType,
+        /// Index of the car the penalty is applied to.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+        /// Index of the other car involved.
+        /// Set to 255 if only one driver is involved.
+        #[serde(rename = "other_vehicle_index")]
+        other_vehicle_index: u8,
+        /// Time gained/spent doing the action in seconds.
+        #[serde(rename = "time")]
+        time: u8,
+        /// Number of the lap the infringement occurred on.
+        #[serde(rename = "lap_num")]
+        lap_num: u8,
+        /// Number of places gained by this infringement.
+        #[serde(rename = "places_gained")]
+        places_gained: u8,
+    },
+    /// Sent when a speed trap is triggered.
+    #[serde(rename = "SpeedTrap")]
+    SpeedTrap {
+        /// Index of the car that's triggered the speed trap.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+        /// Top speed achieved in kilometres per hour.
+        #[serde(rename = "speed")]
+        speed: f32,
+        /// Whether the driver is overall fastest in the session.
+        #[serde(rename = "is_overall_fastest_in_session")]
+        is_overall_fastest_in_session: bool,
+        /// Whether this speed is personal fastest in the session.
+        #[serde(rename = "is_driver_fastest_in_session")]
+        is_driver_fastest_in_session: bool,
+        /// Index of the vehicle that's the fastest in the session.
+        #[serde(rename = "fastest_vehicle_index")]
+        fastest_vehicle_index: u8,
+        /// Fastest speed in the session in kilometres per hour.
+        #[serde(rename = "fastest_speed_in_session")]
+        fastest_speed_in_session: f32,
+    },
+    /// Sent when a start light is lit.
+    #[serde(rename = "StartLights")]
+    StartLights {
+        /// Number of lights showing.
+        #[serde(rename = "num_lights")]
+        num_lights: u8,
+    },
+    /// "It's lights out, and away we go!"
+    #[serde(rename = "LightsOut")]
+    LightsOut,
+    /// Sent when a driver has served a drive-through penalty.
+    #[serde(rename = "DriveThroughServed")]
+    DriveThroughServed {
+        /// Index of the vehicle serving the penalty.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+    },
+    /// Sent when a driver has served a stop-go penalty.
+    #[serde(rename = "StopGoServed")]
+    StopGoServed {
+        /// Index of the vehicle serving the penalty.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+    },
+    /// Sent when a flashback is activated.
+    #[serde(rename = "Flashback")]
+    Flashback {
+        /// Frame identifier that's been flashed back to.
+        #[serde(rename = "frame_identifier")]
+        frame_identifier: u32,
+        /// Session time that's been flashed back to.
+        #[serde(rename = "flashback_session_time")]
+        flashback_session_time: f32,
+    },
+    /// Sent when the button status has changed.
+    #[serde(rename = "Buttons")]
+    Buttons {
+        /// Bitmap specifying which buttons are currently pressed.
+        #[serde(rename = "button_status")]
+        button_status: u32,
+    },
+    /// Sent when the red flag is shown.
+    /// Available from the 2023 format onwards.
+    #[serde(rename = "RedFlag")]
+    RedFlag,
+    /// Sent when a car has overtaken another.
+    /// Available from the 2023 format onwards.
+    #[serde(rename = "Overtake")]
+    Overtake {
+        /// Index of the overtaking vehicle.
+        #[serde(rename = "overtaking_vehicle_index")]
+        overtaking_vehicle_index: u8,
+        /// Index of the overtaken vehicle.
+        #[serde(rename = "overtaken_vehicle_index")]
+        overtaken_vehicle_index: u8,
+    },
+    /// Sent when safety car gets deployed.
+    /// Available from the 2024 format onwards.
+    #[serde(rename = "SafetyCar")]
+    SafetyCar {
+        /// Type of the safety car that's been deployed.
+        #[serde(rename = "safety_car_type")]
+        safety_car_type: SafetyCarType,
+        /// New safety car deployment status.
+        #[serde(rename = "event_type")]
+        event_type: SafetyCarEventType,
+    },
+    /// Sent when two vehicles collide.
+    /// Available from the 2024 format onwards.
+    #[serde(rename = "Collision")]
+    Collision {
+        /// Index of the first vehicle involved in the collision.
+        #[serde(rename = "vehicle_index")]
+        vehicle_index: u8,
+        /// Index of the second vehicle involved in the collision.
+        #[serde(rename = "other_vehicle_index")]
+        other_vehicle_index: u8,
+    },
+}
+
+impl From<event::EventDetails> for EventDetails {
+    fn from(details: event::EventDetails) -> Self {
+        match details {
+            event::EventDetails::SessionStarted => Self::SessionStarted,
+            event::EventDetails::SessionEnded => Self::SessionEnded,
+            event::EventDetails::FastestLap { vehicle_index, lap_time } => {
+                Self::FastestLap {
+                    vehicle_index: vehicle_index.convert(),
+                    lap_time: lap_time.convert(),
+                }
+            }
+            event::EventDetails::Retirement { vehicle_index } => {
+                Self::Retirement { vehicle_index: vehicle_index.convert() }
+            }
+            event::EventDetails::DrsEnabled => Self::DrsEnabled,
+            event::EventDetails::DrsDisabled => Self::DrsDisabled,
+            event::EventDetails::TeamMateInPits { vehicle_index } => {
+                Self::TeamMateInPits { vehicle_index: vehicle_index.convert() }
+            }
+            event::EventDetails::ChequeredFlag => Self::ChequeredFlag,
+            event::EventDetails::RaceWinner { vehicle_index } => {
+                Self::RaceWinner { vehicle_index: vehicle_index.convert() }
+            }
+            event::EventDetails::Penalty {
+                penalty_type,
+                infringement_type,
+                vehicle_index,
+                other_vehicle_index,
+                time,
+                lap_num,
+                places_gained,
+            } => Self::Penalty {
+                penalty_type: penalty_type.convert(),
+                infringement_type: infringement_type.convert(),
+                vehicle_index: vehicle_index.convert(),
+                other_vehicle_index: other_vehicle_index.convert(),
+                time: time.convert(),
+                lap_num: lap_num.convert(),
+                places_gained: places_gained.convert(),
+            },
+            event::EventDetails::SpeedTrap {
+                vehicle_index,
+                speed,
+                is_overall_fastest_in_session,
+                is_driver_fastest_in_session,
+                fastest_vehicle_index,
+                fastest_speed_in_session,
+            } => Self::SpeedTrap {
+                vehicle_index: vehicle_index.convert(),
+                speed: speed.convert(),
+                is_overall_fastest_in_session: is_overall_fastest_in_session.convert(),
+                is_driver_fastest_in_session: is_driver_fastest_in_session.convert(),
+                fastest_vehicle_index: fastest_vehicle_index.convert(),
+                fastest_speed_in_session: fastest_speed_in_session.convert(),
+            },
+            event::EventDetails::StartLights { num_lights } => {
+                Self::StartLights { num_lights: num_lights.convert() }
+            }
+            event::EventDetails::LightsOut => Self::LightsOut,
+            event::EventDetails::DriveThroughServed { vehicle_index } => {
+                Self::DriveThroughServed { vehicle_index: vehicle_index.convert() }
+            }
+            event::EventDetails::StopGoServed { vehicle_index } => {
+                Self::StopGoServed { vehicle_index: vehicle_index.convert() }
+            }
+            event::EventDetails::Flashback {
+                frame_identifier,
+                flashback_session_time,
+            } => Self::Flashback {
+                frame_identifier: frame_identifier.convert(),
+                flashback_session_time: flashback_session_time.convert(),
+            },
+            event::EventDetails::Buttons { button_status } => {
+                Self::Buttons { button_status: button_status.convert() }
+            }
+            event::EventDetails::RedFlag => Self::RedFlag,
+            event::EventDetails::Overtake {
+                overtaking_vehicle_index,
+                overtaken_vehicle_index,
+            } => Self::Overtake {
+                overtaking_vehicle_index: overtaking_vehicle_index.convert(),
+                overtaken_vehicle_index: overtaken_vehicle_index.convert(),
+            },
+            event::EventDetails::SafetyCar { safety_car_type, event_type } => {
+                Self::SafetyCar {
+                    safety_car_type: safety_car_type.convert(),
+                    event_type: event_type.convert(),
+                }
+            }
+            event::EventDetails::Collision { vehicle_index, other_vehicle_index } => {
+                Self::Collision {
+                    vehicle_index: vehicle_index.convert(),
+                    other_vehicle_index: other_vehicle_index.convert(),
+                }
+            }
+        }
+    }
+}
+
+impl From<EventDetails> for event::EventDetails {
+    fn from(details: EventDetails) -> Self {
+        match details {
+            EventDetails::SessionStarted => Self::SessionStarted,
+            EventDetails::SessionEnded => Self::SessionEnded,
+            EventDetails::FastestLap { vehicle_index, lap_time } => Self::FastestLap {
+                vehicle_index: vehicle_index.convert(),
+                lap_time: lap_time.convert(),
+            },
+            EventDetails::Retirement { vehicle_index } => {
+                Self::Retirement { vehicle_index: vehicle_index.convert() }
+            }
+            EventDetails::DrsEnabled => Self::DrsEnabled,
+            EventDetails::DrsDisabled => Self::DrsDisabled,
+            EventDetails::TeamMateInPits { vehicle_index } => {
+                Self::TeamMateInPits { vehicle_index: vehicle_index.convert() }
+            }
+            EventDetails::ChequeredFlag => Self::ChequeredFlag,
+            EventDetails::RaceWinner { vehicle_index } => {
+                Self::RaceWinner { vehicle_index: vehicle_index.convert() }
+            }
+            EventDetails::Penalty {
+                penalty_type,
+                infringement_type,
+                vehicle_index,
+                other_vehicle_index,
+                time,
+                lap_num,
+                places_gained,
+            } => Self::Penalty {
+                penalty_type: penalty_type.convert(),
+                infringement_type: infringement_type.convert(),
+                vehicle_index: vehicle_index.convert(),
+                other_vehicle_index: other_vehicle_index.convert(),
+                time: time.convert(),
+                lap_num: lap_num.convert(),
+                places_gained: places_gained.convert(),
+            },
+            EventDetails::SpeedTrap {
+                vehicle_index,
+                speed,
+                is_overall_fastest_in_session,
+                is_driver_fastest_in_session,
+                fastest_vehicle_index,
+                fastest_speed_in_session,
+            } => Self::SpeedTrap {
+                vehicle_index: vehicle_index.convert(),
+                speed: speed.convert(),
+                is_overall_fastest_in_session: is_overall_fastest_in_session.convert(),
+                is_driver_fastest_in_session: is_driver_fastest_in_session.convert(),
+                fastest_vehicle_index: fastest_vehicle_index.convert(),
+                fastest_speed_in_session: fastest_speed_in_session.convert(),
+            },
+            EventDetails::StartLights { num_lights } => {
+                Self::StartLights { num_lights: num_lights.convert() }
+            }
+            EventDetails::LightsOut => Self::LightsOut,
+            EventDetails::DriveThroughServed { vehicle_index } => {
+                Self::DriveThroughServed { vehicle_index: vehicle_index.convert() }
+            }
+            EventDetails::StopGoServed { vehicle_index } => {
+                Self::StopGoServed { vehicle_index: vehicle_index.convert() }
+            }
+            EventDetails::Flashback { frame_identifier, flashback_session_time } => {
+                Self::Flashback {
+                    frame_identifier: frame_identifier.convert(),
+                    flashback_session_time: flashback_session_time.convert(),
+                }
+            }
+            EventDetails::Buttons { button_status } => {
+                Self::Buttons { button_status: button_status.convert() }
+            }
+            EventDetails::RedFlag => Self::RedFlag,
+            EventDetails::Overtake {
+                overtaking_vehicle_index,
+                overtaken_vehicle_index,
+            } => Self::Overtake {
+                overtaking_vehicle_index: overtaking_vehicle_index.convert(),
+                overtaken_vehicle_index: overtaken_vehicle_index.convert(),
+            },
+            EventDetails::SafetyCar { safety_car_type, event_type } => Self::SafetyCar {
+                safety_car_type: safety_car_type.convert(),
+                event_type: event_type.convert(),
+            },
+            EventDetails::Collision { vehicle_index, other_vehicle_index } => {
+                Self::Collision {
+                    vehicle_index: vehicle_index.convert(),
+                    other_vehicle_index: other_vehicle_index.convert(),
+                }
+            }
+        }
+    }
+}
+
+impl Convert<EventDetails> for event::EventDetails {
+    fn convert(self) -> EventDetails {
+        self.into()
+    }
+}
+
+impl Convert<event::EventDetails> for EventDetails {
+    fn convert(self) -> event::EventDetails {
+        self.into()
+    }
+}
+
+frozen_enums! {
+    /// Unique identifier of the type of this packet.
+    /// Represents a [`u8`].
+    enum PacketId {
+        Motion => "Motion",
+        Session => "Session",
+        Laps => "Laps",
+        Event => "Event",
+        Participants => "Participants",
+        CarSetups => "CarSetups",
+        CarTelemetry => "CarTelemetry",
+        CarStatus => "CarStatus",
+        FinalClassification => "FinalClassification",
+        LobbyInfo => "LobbyInfo",
+        CarDamage => "CarDamage",
+        SessionHistory => "SessionHistory",
+        TyreSets => "TyreSets",
+        MotionEx => "MotionEx",
+        TimeTrial => "TimeTrial",
+    }
+
+    /// Flag that's currently being waved in
+    /// a [`MarshalZone`](crate::packets::session::MarshalZone).
+    /// Represents an [`i8`].
+    enum MarshalZoneFlag {
+        Unknown => "Unknown",
+        None => "None",
+        Green => "Green",
+        Blue => "Blue",
+        Yellow => "Yellow",
+        Red => "Red",
+    }
+
+    /// Session/forecast weather type. Represents a [`u8`].
+    enum Weather {
+        Clear => "Clear",
+        LightCloud => "LightCloud",
+        Overcast => "Overcast",
+        LightRain => "LightRain",
+        HeavyRain => "HeavyRain",
+        Storm => "Storm",
+    }
+
+    /// Temperature change direction. Represents an [`i8`].
+    enum TemperatureChange {
+        Up => "Up",
+        Down => "Down",
+        NoChange => "NoChange",
+    }
+
+    /// Unique circuit ID. Represents an [`i8`].
+    enum TrackId {
+        /// Unknown circuit.
+        Unknown => "Unknown",
+        /// Australian Grand Prix.
+        AlbertPark => "AlbertPark",
+        /// French Grand Prix.
+        PaulRicard => "PaulRicard",
+        /// Chinese Grand Prix.
+        Shanghai => "Shanghai",
+        /// Bahrain Grand Prix.
+        Sakhir => "Sakhir",
+        /// Spanish Grand Prix.
+        Catalunya => "Catalunya",
+        /// Monaco Grand Prix.
+        MonteCarlo => "MonteCarlo",
+        /// Canadian Grand Prix.
+        Montreal => "Montreal",
+        /// British Grand Prix.
+        Silverstone => "Silverstone",
+        /// German Grand Prix.
+        Hockenheim => "Hockenheim",
+        /// Hungarian Grand Prix.
+        Hungaroring => "Hungaroring",
+        /// Belgian Grand Prix.
+        Spa => "Spa",
+        /// Italian Grand Prix.
+        Monza => "Monza",
+        /// Singapore Grand Prix.
+        MarinaBay => "MarinaBay",
+        /// Japanese Grand Prix.
+        Suzuka => "Suzuka",
+        /// Abu Dhabi Grand Prix.
+        YasMarina => "YasMarina",
+        /// Circuit of the Americas. United States (Texas) Grand Prix.
+        Cota => "Cota",
+        /// Brazilian (Sao Paulo) Grand Prix.
+        Interlagos => "Interlagos",
+        /// Austrian Grand Prix.
+        RedBullRing => "RedBullRing",
+        /// Russian Grand Prix.
+        Sochi => "Sochi",
+        /// Mexican Grand Prix.
+        MexicoCity => "MexicoCity",
+        /// Azerbaijan Grand Prix.
+        Baku => "Baku",
+        /// Short variant of the [`Sakhir`](TrackId::Sakhir) circuit.
+        SakhirShort => "SakhirShort",
+        /// Short variant of the [`Silverstone`](TrackId::Silverstone) circuit.
+        SilverstoneShort => "SilverstoneShort",
+        /// Short variant of the [`Cota`](TrackId::Cota) circuit.
+        CotaShort => "CotaShort",
+        /// Short variant of the [`Suzuka`](TrackId::Suzuka) circuit.
+        SuzukaShort => "SuzukaShort",
+        /// Vietnamese Grand Prix.
+        Hanoi => "Hanoi",
+        /// Dutch Grand Prix.
+        Zandvoort => "Zandvoort",
+        /// ~~San Marino~~ Emilia-Romagna Grand Prix.
+        Imola => "Imola",
+        /// Portuguese Grand Prix.
+        Portimao => "Portimao",
+        /// Saudi Arabian Grand Prix.
+        Jeddah => "Jeddah",
+        /// Miami Grand Prix.
+        Miami => "Miami",
+        /// Las Vegas Grand Prix.
+        LasVegas => "LasVegas",
+        /// Qatar Grand Prix.
+        Losail => "Losail",
+    }
+
+    /// Type of cars being raced in
+    /// [`F1PacketSession`](struct@crate::F1PacketSession).
+    /// Represents a [`u8`].
+    enum Formula {
+        F1Modern => "F1Modern",
+        F1Classic => "F1Classic",
+        F2 => "F2",
+        F1Generic => "F1Generic",
+        Beta => "Beta",
+        Supercars => "Supercars",
+        Esports => "Esports",
+        F22021 => "F22021",
+        F1World => "F1World",
+        F1Elimination => "F1Elimination",
+    }
+
+    /// Safety car deployment status in [`F1PacketSession`](struct@crate::F1PacketSession).
+    /// Represents a [`u8`].
+    enum SafetyCarStatus {
+        None => "None",
+        Virtual => "Virtual",
+        Full => "Full",
+        FormationLap => "FormationLap",
+    }
+
+    /// Accuracy of a
+    /// [`WeatherForecastSample`](struct@crate::packets::session::WeatherForecastSample).
+    /// Represents a [`u8`].
+    enum ForecastAccuracy {
+        Perfect => "Perfect",
+        Approximate => "Approximate",
+    }
+
+    /// Type of enabled braking assist. Represents a [`u8`].
+    enum BrakingAssist {
+        Off => "Off",
+        Low => "Low",
+        Medium => "Medium",
+        High => "High",
+    }
+
+    /// Type of enabled gearbox assist. Represents a [`u8`].
+    enum GearboxAssist {
+        Unknown => "Unknown",
+        Manual => "Manual",
+        ManualWithSuggestedGear => "ManualWithSuggestedGear",
+        Automatic => "Automatic",
+    }
+
+    /// Type of enabled racing line assist. Represents a [`u8`].
+    enum DynamicRacingLine {
+        Off => "Off",
+        CornersOnly => "CornersOnly",
+        Full => "Full",
+    }
+
+    /// Shape of the racing line. Represents a [`u8`].
+    enum DynamicRacingLineType {
+        TwoDimensional => "TwoDimensional",
+        ThreeDimensional => "ThreeDimensional",
+    }
+
+    /// Game mode that's currently in use. Represents a [`u8`].
+    enum GameMode {
+        EventMode => "EventMode",
+        GrandPrix => "GrandPrix",
+        GrandPrix2023 => "GrandPrix2023",
+        TimeTrial => "TimeTrial",
+        Splitscreen => "Splitscreen",
+        OnlineCustom => "OnlineCustom",
+        OnlineLeague => "OnlineLeague",
+        CareerInvitational => "CareerInvitational",
+        ChampionshipInvitational => "ChampionshipInvitational",
+        Championship => "Championship",
+        OnlineChampionship => "OnlineChampionship",
+        OnlineWeeklyEvent => "OnlineWeeklyEvent",
+        BrakingPoint2023 => "BrakingPoint2023",
+        Career2022 => "Career2022",
+        OnlineCareer2022 => "OnlineCareer2022",
+        Career2023 => "Career2023",
+        OnlineCareer2023 => "OnlineCareer2023",
+        DriverCareer2024 => "DriverCareer2024",
+        OnlineCareer2024 => "OnlineCareer2024",
+        MyTeamCareer2024 => "MyTeamCareer2024",
+        CuratedCareer2024 => "CuratedCareer2024",
+        Benchmark => "Benchmark",
+    }
+
+    /// Set of rules that's in use for this session. Represents a [`u8`].
+    enum RuleSet {
+        PracticeAndQualifying => "PracticeAndQualifying",
+        Race => "Race",
+        TimeTrial => "TimeTrial",
+        TimeAttack => "TimeAttack",
+        CheckpointChallenge => "CheckpointChallenge",
+        Autocross => "Autocross",
+        Drift => "Drift",
+        AverageSpeedZone => "AverageSpeedZone",
+        RivalDuel => "RivalDuel",
+    }
+
+    /// Length of the ongoing session. Represents a [`u8`].
+    enum SessionLength {
+        None => "None",
+        VeryShort => "VeryShort",
+        Short => "Short",
+        Medium => "Medium",
+        MediumLong => "MediumLong",
+        Long => "Long",
+        Full => "Full",
+    }
+
+    /// Whether the car is outside/entering/in the pit lane. Represents a [`u8`].
+    enum PitStatus {
+        None => "None",
+        Pitting => "Pitting",
+        InPitArea => "InPitArea",
+    }
+
+    /// Zero-based sector number. Represents a [`u8`].
+    enum Sector {
+        First => "First",
+        Second => "Second",
+        Third => "Third",
+    }
+
+    /// Status of a driver in the current session. Represents a [`u8`].
+    enum DriverStatus {
+        InGarage => "InGarage",
+        FlyingLap => "FlyingLap",
+        InLap => "InLap",
+        OutLap => "OutLap",
+        OnTrack => "OnTrack",
+    }
+
+    /// Status of a driver's result in the current session and final classification.
+    /// Represents a [`u8`].
+    enum ResultStatus {
+        Unknown => "Unknown",
+        Inactive => "Inactive",
+        Active => "Active",
+        Finished => "Finished",
+        DidNotFinish => "DidNotFinish",
+        Disqualified => "Disqualified",
+        NotClassified => "NotClassified",
+        Retired => "Retired",
+    }
+
+    /// Type of penalty awarded to a driver. Represents a [`u8`].
+    enum PenaltyType {
+        DriveThrough => "DriveThrough",
+        StopGo => "StopGo",
+        GridPenalty => "GridPenalty",
+        PenaltyReminder => "PenaltyReminder",
+        TimePenalty => "TimePenalty",
+        Warning => "Warning",
+        Disqualified => "Disqualified",
+        RemovedFromFormationLap => "RemovedFromFormationLap",
+        ParkedTooLongTimer => "ParkedTooLongTimer",
+        TyreRegulations => "TyreRegulations",
+        ThisLapInvalidated => "ThisLapInvalidated",
+        ThisAndNextLapInvalidated => "ThisAndNextLapInvalidated",
+        ThisLapInvalidatedWithoutReason => "ThisLapInvalidatedWithoutReason",
+        ThisAndNextLapInvalidatedWithoutReason => "ThisAndNextLapInvalidatedWithoutReason",
+        ThisAndPreviousLapInvalidated => "ThisAndPreviousLapInvalidated",
+        ThisAndPreviousLapInvalidatedWithoutReason => "ThisAndPreviousLapInvalidatedWithoutReason",
+        Retired => "Retired",
+        BlackFlagTimer => "BlackFlagTimer",
+    }
+
+    /// Type of offence commited by a driver. Represents a [`u8`].
+    enum InfringementType {
+        BlockingBySlowDriving => "BlockingBySlowDriving",
+        BlockingByWrongWayDriving => "BlockingByWrongWayDriving",
+        ReversingOffTheStartLine => "ReversingOffTheStartLine",
+        BigCollision => "BigCollision",
+        SmallCollision => "SmallCollision",
+        CollisionFailedToHandBackPositionSingle => "CollisionFailedToHandBackPositionSingle",
+        CollisionFailedToHandBackPositionMultiple => "CollisionFailedToHandBackPositionMultiple",
+        CornerCuttingGainedTime => "CornerCuttingGainedTime",
+        CornerCuttingOvertakeSingle => "CornerCuttingOvertakeSingle",
+        CornerCuttingOvertakeMultiple => "CornerCuttingOvertakeMultiple",
+        CrossedPitExitLane => "CrossedPitExitLane",
+        IgnoringBlueFlags => "IgnoringBlueFlags",
+        IgnoringYellowFlags => "IgnoringYellowFlags",
+        IgnoringDriveThrough => "IgnoringDriveThrough",
+        TooManyDriveThroughs => "TooManyDriveThroughs",
+        DriveThroughReminderServeWithinNLaps => "DriveThroughReminderServeWithinNLaps",
+        DriveThroughReminderServeThisLap => "DriveThroughReminderServeThisLap",
+        PitLaneSpeeding => "PitLaneSpeeding",
+        ParkedForTooLong => "ParkedForTooLong",
+        IgnoringTyreRegulations => "IgnoringTyreRegulations",
+        TooManyPenalties => "TooManyPenalties",
+        MultipleWarnings => "MultipleWarnings",
+        ApproachingDisqualification => "ApproachingDisqualification",
+        TyreRegulationsSelectSingle => "TyreRegulationsSelectSingle",
+        TyreRegulationsSelectMultiple => "TyreRegulationsSelectMultiple",
+        LapInvalidatedCornerCutting => "LapInvalidatedCornerCutting",
+        LapInvalidatedRunningWide => "LapInvalidatedRunningWide",
+        CornerCuttingRanWideMinorTimeGain => "CornerCuttingRanWideMinorTimeGain",
+        CornerCuttingRanWideSignificantTimeGain => "CornerCuttingRanWideSignificantTimeGain",
+        CornerCuttingRanWideExtremeTimeGain => "CornerCuttingRanWideExtremeTimeGain",
+        LapInvalidatedWallRiding => "LapInvalidatedWallRiding",
+        LapInvalidatedFlashbackUsed => "LapInvalidatedFlashbackUsed",
+        LapInvalidatedResetToTrack => "LapInvalidatedResetToTrack",
+        BlockingThePitLane => "BlockingThePitLane",
+        JumpStart => "JumpStart",
+        SafetyCarCollision => "SafetyCarCollision",
+        SafetyCarIllegalOvertake => "SafetyCarIllegalOvertake",
+        SafetyCarExceedingAllowedPace => "SafetyCarExceedingAllowedPace",
+        VirtualSafetyCarExceedingAllowedPace => "VirtualSafetyCarExceedingAllowedPace",
+        FormationLapBelowAllowedSpeed => "FormationLapBelowAllowedSpeed",
+        FormationLapParking => "FormationLapParking",
+        RetiredMechanicalFailure => "RetiredMechanicalFailure",
+        RetiredTerminallyDamaged => "RetiredTerminallyDamaged",
+        SafetyCarFallingTooFarBack => "SafetyCarFallingTooFarBack",
+        BlackFlagTimer => "BlackFlagTimer",
+        UnservedStopGoPenalty => "UnservedStopGoPenalty",
+        UnservedDriveThroughPenalty => "UnservedDriveThroughPenalty",
+        EngineComponentChange => "EngineComponentChange",
+        GearboxChange => "GearboxChange",
+        ParcFermeChange => "ParcFermeChange",
+        LeagueGridPenalty => "LeagueGridPenalty",
+        RetryPenalty => "RetryPenalty",
+        IllegalTimeGain => "IllegalTimeGain",
+        MandatoryPitStop => "MandatoryPitStop",
+        AttributeAssigned => "AttributeAssigned",
+    }
+
+    /// Unique identifier of a driver's nationality. Represents a [`u8`].
+    enum Nationality {
+        Unknown => "Unknown",
+        American => "American",
+        Argentinian => "Argentinian",
+        Australian => "Australian",
+        Austrian => "Austrian",
+        Azerbaijani => "Azerbaijani",
+        Bahraini => "Bahraini",
+        Belgian => "Belgian",
+        Bolivian => "Bolivian",
+        Brazilian => "Brazilian",
+        British => "British",
+        Bulgarian => "Bulgarian",
+        Cameroonian => "Cameroonian",
+        Canadian => "Canadian",
+        Chilean => "Chilean",
+        Chinese => "Chinese",
+        Colombian => "Colombian",
+        CostaRican => "CostaRican",
+        Croatian => "Croatian",
+        Cypriot => "Cypriot",
+        Czech => "Czech",
+        Danish => "Danish",
+        Dutch => "Dutch",
+        Ecuadorian => "Ecuadorian",
+        English => "English",
+        Emirian => "Emirian",
+        Estonian => "Estonian",
+        Finnish => "Finnish",
+        French => "French",
+        German => "German",
+        Ghanaian => "Ghanaian",
+        Greek => "Greek",
+        Guatemalan => "Guatemalan",
+        Honduran => "Honduran",
+        HongKonger => "HongKonger",
+        Hungarian => "Hungarian",
+        Icelander => "Icelander",
+        Indian => "Indian",
+        Indonesian => "Indonesian",
+        Irish => "Irish",
+        Israeli => "Israeli",
+        Italian => "Italian",
+        Jamaican => "Jamaican",
+        Japanese => "Japanese",
+        Jordanian => "Jordanian",
+        Kuwaiti => "Kuwaiti",
+        Latvian => "Latvian",
+        Lebanese => "Lebanese",
+        Lithuanian => "Lithuanian",
+        Luxembourger => "Luxembourger",
+        Malaysian => "Malaysian",
+        Maltese => "Maltese",
+        Mexican => "Mexican",
+        Monegasque => "Monegasque",
+        NewZealander => "NewZealander",
+        Nicaraguan => "Nicaraguan",
+        NorthernIrish => "NorthernIrish",
+        Norwegian => "Norwegian",
+        Omani => "Omani",
+        Pakistani => "Pakistani",
+        Panamanian => "Panamanian",
+        Paraguayan => "Paraguayan",
+        Peruvian => "Peruvian",
+        Polish => "Polish",
+        Portuguese => "Portuguese",
+        Qatari => "Qatari",
+        Romanian => "Romanian",
+        Russian => "Russian",
+        Salvadoran => "Salvadoran",
+        Saudi => "Saudi",
+        Scottish => "Scottish",
+        Serbian => "Serbian",
+        Singaporean => "Singaporean",
+        Slovakian => "Slovakian",
+        Slovenian => "Slovenian",
+        SouthKorean => "SouthKorean",
+        SouthAfrican => "SouthAfrican",
+        Spanish => "Spanish",
+        Swedish => "Swedish",
+        Swiss => "Swiss",
+        Thai => "Thai",
+        Turkish => "Turkish",
+        Uruguayan => "Uruguayan",
+        Ukrainian => "Ukrainian",
+        Venezuelan => "Venezuelan",
+        Welsh => "Welsh",
+        Barbadian => "Barbadian",
+        Vietnamese => "Vietnamese",
+        Algerian => "Algerian",
+        Bosnian => "Bosnian",
+        Filipino => "Filipino",
+    }
+
+    /// "Your telemetry" UDP setting value. Represents a [`u8`].
+    enum YourTelemetry {
+        Restricted => "Restricted",
+        Public => "Public",
+    }
+
+    /// Type of surface a tyre is on. Represents a [`u8`].
+    enum Surface {
+        Tarmac => "Tarmac",
+        RumbleStrip => "RumbleStrip",
+        Concrete => "Concrete",
+        Rock => "Rock",
+        Gravel => "Gravel",
+        Mud => "Mud",
+        Sand => "Sand",
+        Grass => "Grass",
+        Water => "Water",
+        Cobblestone => "Cobblestone",
+        Metal => "Metal",
+        Ridged => "Ridged",
+    }
+
+    /// Index of currently open multi-function display panel. Represents a [`u8`].
+    enum MfdPanelIndex {
+        CarSetup => "CarSetup",
+        Pits => "Pits",
+        Damage => "Damage",
+        Engine => "Engine",
+        Temperatures => "Temperatures",
+        Closed => "Closed",
+    }
+
+    /// Type of enabled traction control assist. Represents a [`u8`].
+    enum TractionControl {
+        Off => "Off",
+        Medium => "Medium",
+        Full => "Full",
+    }
+
+    /// Type of fuel mix that's currently in use. Represents a [`u8`].
+    enum FuelMix {
+        Lean => "Lean",
+        Standard => "Standard",
+        Rich => "Rich",
+        Max => "Max",
+    }
+
+    /// ERS deployment mode that's currently in use. Represents a [`u8`].
+    enum ErsDeployMode {
+        None => "None",
+        Medium => "Medium",
+        Overtake => "Overtake",
+        Hotlap => "Hotlap",
+    }
+
+    /// Flag the driver is currently being shown. Represents an [`i8`].
+    enum VehicleFiaFlag {
+        Unknown => "Unknown",
+        None => "None",
+        Green => "Green",
+        Blue => "Blue",
+        Yellow => "Yellow",
+        Red => "Red",
+    }
+
+    /// Global DRS activation permission status. Represents an [`i8`].
+    enum DrsAllowed {
+        Unknown => "Unknown",
+        NotAllowed => "NotAllowed",
+        Allowed => "Allowed",
+    }
+
+    /// Session-independent tyre compound type. Represents a [`u8`].
+    enum ActualTyreCompound {
+        Unknown => "Unknown",
+        C5 => "C5",
+        C4 => "C4",
+        C3 => "C3",
+        C2 => "C2",
+        C1 => "C1",
+        C0 => "C0",
+        Inter => "Inter",
+        Wet => "Wet",
+        ClassicDry => "ClassicDry",
+        ClassicWet => "ClassicWet",
+        F2SuperSoft => "F2SuperSoft",
+        F2Soft => "F2Soft",
+        F2Medium => "F2Medium",
+        F2Hard => "F2Hard",
+        F2Wet => "F2Wet",
+    }
+
+    /// Visual indicator of a tyre compound's type in a given session.
+    /// Represents a [`u8`].
+    enum VisualTyreCompound {
+        Unknown => "Unknown",
+        F1Soft => "F1Soft",
+        F1Medium => "F1Medium",
+        F1Hard => "F1Hard",
+        F1Inter => "F1Inter",
+        F1Wet => "F1Wet",
+        ClassicDry => "ClassicDry",
+        ClassicWet => "ClassicWet",
+        F2SuperSoft => "F2SuperSoft",
+        F2Soft => "F2Soft",
+        F2Medium => "F2Medium",
+        F2Hard => "F2Hard",
+        F2Wet => "F2Wet",
+    }
+
+    /// Readiness of a player in an online lobby. Represents a [`u8`].
+    enum ReadyStatus {
+        NotReady => "NotReady",
+        Ready => "Ready",
+        Spectating => "Spectating",
+    }
+
+    /// Speed unit used by a player. Represents a [`u8`].
+    enum SpeedUnit {
+        MilesPerHour => "MilesPerHour",
+        KilometresPerHour => "KilometresPerHour",
+    }
+
+    /// Temperature unit used by a player. Represents a [`u8`].
+    enum TemperatureUnit {
+        Celsius => "Celsius",
+        Fahrenheit => "Fahrenheit",
+    }
+
+    /// Console or PC game distribution platform used by a player.
+    /// Represents a [`u8`].
+    enum Platform {
+        Invalid => "Invalid",
+        Steam => "Steam",
+        PlayStation => "PlayStation",
+        Xbox => "Xbox",
+        Origin => "Origin",
+        Unknown => "Unknown",
+    }
+
+    /// Recovery mode assist that's currently enabled.
+    /// Represents a [`u8`].
+    enum RecoveryMode {
+        None => "None",
+        Flashbacks => "Flashbacks",
+        AutoRecovery => "AutoRecovery",
+    }
+
+    /// Flashback usage limit that's currently enabled.
+    /// Represents a [`u8`].
+    enum FlashbackLimit {
+        Low => "Low",
+        Medium => "Medium",
+        High => "High",
+        Unlimited => "Unlimited",
+    }
+
+    /// Type of surface simulation that's currently enabled.
+    /// Represents a [`u8`].
+    enum SurfaceSimType {
+        Simplified => "Simplified",
+        Realistic => "Realistic",
+    }
+
+    /// Difficulty of driving with low fuel. Represent a [`u8`].
+    enum LowFuelMode {
+        Easy => "Easy",
+        Hard => "Hard",
+    }
+
+    /// Race starts assist that's currently in use.
+    /// Represents a [`u8`].
+    enum RaceStarts {
+        Manual => "Manual",
+        Assisted => "Assisted",
+    }
+
+    /// Type of tyre temperature simulation that's currently in use.
+    /// Represents a [`u8`].
+    enum TyreTemperature {
+        SurfaceOnly => "SurfaceOnly",
+        SurfaceAndCarcass => "SurfaceAndCarcass",
+    }
+
+    /// Type of car damage simulation that's currently in use.
+    /// Represents a [`u8`].
+    enum CarDamage {
+        Off => "Off",
+        Reduced => "Reduced",
+        Standard => "Standard",
+        Simulation => "Simulation",
+    }
+
+    /// Car damage severity. Represents a [`u8`].
+    enum CarDamageRate {
+        Reduced => "Reduced",
+        Standard => "Standard",
+        Simulation => "Simulation",
+    }
+
+    /// Type of collision simulation that's currently enabled.
+    /// Represents a [`u8`].
+    enum Collisions {
+        Off => "Off",
+        PlayerToPlayerOff => "PlayerToPlayerOff",
+        On => "On",
+    }
+
+    /// Type of corner cutting and track limits punishability.
+    /// Represents a [`u8`].
+    enum CornerCuttingStringency {
+        Regular => "Regular",
+        Strict => "Strict",
+    }
+
+    /// The way the game handles pit stops. Represents a [`u8`].
+    enum PitStopExperience {
+        Automatic => "Automatic",
+        Broadcast => "Broadcast",
+        Immersive => "Immersive",
+    }
+
+    /// The likelihood of safety car getting deployed with hazard on track.
+    /// Represents a [`u8`].
+    enum SafetyCarIntensity {
+        Off => "Off",
+        Reduced => "Reduced",
+        Standard => "Standard",
+        Increased => "Increased",
+    }
+
+    /// The way the game handles safety car periods. Represents a [`u8`].
+    enum SafetyCarExperience {
+        Broadcast => "Broadcast",
+        Immersive => "Immersive",
+        Unknown => "Unknown",
+    }
+
+    /// The way the game handles formation laps. Represents a [`u8`].
+    enum FormationLapExperience {
+        Broadcast => "Broadcast",
+        Immersive => "Immersive",
+        Unknown => "Unknown",
+    }
+
+    /// The likelihood of the game using a red flag after a serious incident.
+    /// Represents a [`u8`].
+    enum RedFlagIntensity {
+        Off => "Off",
+        Reduced => "Reduced",
+        Standard => "Standard",
+        Increased => "Increased",
+    }
+
+    /// Type of safety car being deployed in a
+    /// [`SafetyCar` event](variant@crate::packets::event::EventDetails::SafetyCar).
+    /// Represents a [`u8`].
+    enum SafetyCarType {
+        None => "None",
+        Full => "Full",
+        Virtual => "Virtual",
+        FormationLap => "FormationLap",
+    }
+
+    /// Type of [`SafetyCar` event](variant@crate::packets::event::EventDetails::SafetyCar).
+    /// Represents a [`u8`].
+    enum SafetyCarEventType {
+        Deployed => "Deployed",
+        Returning => "Returning",
+        Returned => "Returned",
+        ResumeRace => "ResumeRace",
+    }
+}
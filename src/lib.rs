@@ -180,11 +180,95 @@
 //! - [F1 23](https://forums.ea.com/discussions/f1-23-en/f1-23-udp-specification/8390745)
 //! - [F1 22](https://forums.ea.com/discussions/f1-games-franchise-discussion-en/f1-22-udp-specification/8418392)
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// Button press, release, hold, double press and chord detection with action bindings.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod buttons;
+/// Lossless delta compression of raw packets for compact storage.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod codec;
 /// Contains appendix constants and enums for various packet-specific struct field values.
 pub mod constants;
+/// Damage change detection with collision correlation.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod damage;
+/// Routing of interleaved packets into per-session sinks.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod demux;
+/// Per-lap ERS energy budgets and deployment analytics.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod ers;
+/// Exporters turning parsed packets into external data formats.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod export;
+/// Marshal zone and per-car flag monitoring.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod flags;
+/// Per-lap fuel usage and fuel-to-finish projections.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod fuel;
+/// Time gaps and on-track intervals between cars.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod gaps;
+/// Penalties, warnings and collisions timeline for stewarding.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod incidents;
+/// Versioned JSON representation of packets and its JSON Schema.
+/// Requires the `json` feature.
+#[cfg(feature = "json")]
+pub mod json;
+/// MQTT publisher of packet fields, e.g. for sim rig hardware.
+/// Requires the `mqtt` feature.
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+/// Safety car, virtual safety car, red flag and formation lap periods.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod neutralisations;
 /// Contains structures for each kind of packet payload
 /// and submodules for packet-specific structs.
 pub mod packets;
+/// Prometheus metrics of the player's car served over HTTP.
+/// Requires the `prometheus` feature.
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+/// Markdown and HTML race reports built from a session's packets.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod report;
+/// Car setup diffing, saving and loading, and change detection.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod setup;
+/// Championship standings from final classifications with configurable points systems.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod standings;
+/// Tyre degradation, remaining life projections and pit stop strategy planning.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod strategy;
+/// WebSocket server broadcasting packets as JSON, e.g. to stream overlays.
+/// Requires the `websocket` feature.
+#[cfg(feature = "websocket")]
+pub mod websocket;
+/// Race weekend aggregation of session classifications.
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod weekend;
 
 use crate::constants::PacketId;
 use crate::packets::{
@@ -196,6 +280,7 @@ use crate::packets::{
 
 use binrw::io::Cursor;
 use binrw::{BinRead, BinReaderExt, BinResult};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Attempts to extract F1 game packet data from a byte buffer
@@ -263,7 +348,8 @@ pub fn parse<T: AsRef<[u8]>>(data: T) -> BinResult<F1Packet> {
 ///
 /// Each [`Option`] field acts as a slot for a payload of a packet of a certain type.
 /// Only one of these fields can be [`Some`] for a given `F1Packet` instance.
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little)]
 pub struct F1Packet {
     /// Universal packet header.
@@ -324,7 +410,8 @@ pub struct F1Packet {
 /// F1 game packet's header. It contains metadata about the game,
 /// the ongoing session, the frame this packet was sent on, and player car indexes.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little)]
 pub struct F1PacketHeader {
     /// Value of the "UDP Format" option in the game's telemetry settings.
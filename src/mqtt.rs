@@ -0,0 +1,507 @@
+use crate::packets::event::EventDetails;
+use crate::F1Packet;
+
+use rumqttc::{
+    valid_topic, Client, ConnectionError, Event, MqttOptions, Outgoing, Packet, QoS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_CAPACITY: usize = 64;
+
+/// Value that can be published to an MQTT topic.
+#[non_exhaustive]
+#[derive(
+    Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MqttField {
+    /// [`CarTelemetryData::speed`](crate::packets::car_telemetry::CarTelemetryData::speed).
+    Speed,
+    /// [`CarTelemetryData::gear`](crate::packets::car_telemetry::CarTelemetryData::gear).
+    Gear,
+    /// [`CarTelemetryData::engine_rpm`](crate::packets::car_telemetry::CarTelemetryData::engine_rpm).
+    EngineRpm,
+    /// [`CarTelemetryData::throttle`](crate::packets::car_telemetry::CarTelemetryData::throttle).
+    Throttle,
+    /// [`CarTelemetryData::brake`](crate::packets::car_telemetry::CarTelemetryData::brake).
+    Brake,
+    /// [`CarTelemetryData::drs_enabled`](crate::packets::car_telemetry::CarTelemetryData::drs_enabled).
+    DrsEnabled,
+    /// [`CarTelemetryData::rev_lights_percent`](crate::packets::car_telemetry::CarTelemetryData::rev_lights_percent).
+    RevLightsPercent,
+    /// [`CarMotionData::g_force_lateral`](crate::packets::motion::CarMotionData::g_force_lateral).
+    GForceLateral,
+    /// [`CarMotionData::g_force_longitudinal`](crate::packets::motion::CarMotionData::g_force_longitudinal).
+    GForceLongitudinal,
+    /// [`CarMotionData::g_force_vertical`](crate::packets::motion::CarMotionData::g_force_vertical).
+    GForceVertical,
+    /// Event code and [`EventDetails`].
+    Event,
+}
+
+impl MqttField {
+    /// Fields mapped by [`MqttMapping::new`].
+    pub const DEFAULTS: [MqttField; 5] = [
+        MqttField::Speed,
+        MqttField::RevLightsPercent,
+        MqttField::GForceLateral,
+        MqttField::GForceLongitudinal,
+        MqttField::Event,
+    ];
+
+    /// Returns the field's name, used as the default topic suffix.
+    pub fn name(self) -> &'static str {
+        match self {
+            MqttField::Speed => "speed",
+            MqttField::Gear => "gear",
+            MqttField::EngineRpm => "engine_rpm",
+            MqttField::Throttle => "throttle",
+            MqttField::Brake => "brake",
+            MqttField::DrsEnabled => "drs_enabled",
+            MqttField::RevLightsPercent => "rev_lights_percent",
+            MqttField::GForceLateral => "g_force_lateral",
+            MqttField::GForceLongitudinal => "g_force_longitudinal",
+            MqttField::GForceVertical => "g_force_vertical",
+            MqttField::Event => "event",
+        }
+    }
+}
+
+impl fmt::Display for MqttField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Encoding of published payloads.
+#[non_exhaustive]
+#[derive(
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFormat {
+    /// The bare value as text, e.g. `287` or `true`.
+    /// Events are published as their 4-letter code.
+    #[default]
+    Plain,
+    /// A JSON object, e.g. `{"value": 287, "vehicle_index": 19, "session_time": 12.5}`.
+    /// Events are published as `{"code": "COLL", "details": {...}, "session_time": 12.5}`.
+    Json,
+}
+
+/// Message produced by [`MqttMapping`].
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+pub struct MqttMessage {
+    /// Topic to publish to.
+    pub topic: String,
+    /// Encoded payload.
+    pub payload: String,
+    /// Whether the broker should retain the message for new subscribers.
+    pub retain: bool,
+}
+
+/// Maps packet fields into MQTT topics and payloads.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MqttMapping {
+    topic_prefix: String,
+    topics: BTreeMap<MqttField, Option<String>>,
+    format: PayloadFormat,
+    vehicle_index: Option<usize>,
+    retain: bool,
+}
+
+impl Default for MqttMapping {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MqttMapping {
+    /// Creates a mapping of the player car's [`MqttField::DEFAULTS`]
+    /// into plain text payloads under the `f1/` topic prefix, e.g. `f1/speed`.
+    pub fn new() -> Self {
+        Self {
+            topic_prefix: "f1/".to_owned(),
+            topics: MqttField::DEFAULTS.iter().map(|&field| (field, None)).collect(),
+            format: PayloadFormat::default(),
+            vehicle_index: None,
+            retain: false,
+        }
+    }
+
+    /// Sets the prefix of topics that haven't been set with [`with_topic`](Self::with_topic).
+    pub fn with_topic_prefix(mut self, prefix: &str) -> Self {
+        self.topic_prefix = prefix.to_owned();
+        self
+    }
+
+    /// Publishes a field to the given topic, regardless of the prefix.
+    pub fn with_topic(mut self, field: MqttField, topic: &str) -> Self {
+        self.topics.insert(field, Some(topic.to_owned()));
+        self
+    }
+
+    /// Publishes a field to its default topic, i.e. the prefix followed by its name.
+    pub fn with_field(mut self, field: MqttField) -> Self {
+        self.topics.entry(field).or_insert(None);
+        self
+    }
+
+    /// Stops publishing a field.
+    pub fn without_field(mut self, field: MqttField) -> Self {
+        self.topics.remove(&field);
+        self
+    }
+
+    /// Sets the payload encoding. Defaults to [`PayloadFormat::Plain`].
+    pub fn with_format(mut self, format: PayloadFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Publishes values of the given car instead of the player's one.
+    pub fn with_vehicle_index(mut self, vehicle_index: usize) -> Self {
+        self.vehicle_index = Some(vehicle_index);
+        self
+    }
+
+    /// Sets whether messages should be retained by the broker. Defaults to `false`.
+    pub fn with_retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    /// Returns the topic a field gets published to, if it's mapped.
+    pub fn topic(&self, field: MqttField) -> Option<String> {
+        self.topics.get(&field).map(|topic| match topic {
+            Some(topic) => topic.clone(),
+            None => format!("{}{}", self.topic_prefix, field.name()),
+        })
+    }
+
+    /// Returns messages of all mapped fields available in the packet.
+    pub fn messages(&self, packet: &F1Packet) -> Vec<MqttMessage> {
+        let vehicle_index = self.vehicle_index.unwrap_or(packet.header.player_car_index);
+        let session_time = packet.header.session_time;
+        let mut values: Vec<(MqttField, String)> = Vec::new();
+
+        if let Some(telemetry) =
+            packet.car_telemetry.as_ref().and_then(|p| p.data.get(vehicle_index))
+        {
+            values.extend([
+                (MqttField::Speed, to_json(&telemetry.speed)),
+                (MqttField::Gear, to_json(&telemetry.gear)),
+                (MqttField::EngineRpm, to_json(&telemetry.engine_rpm)),
+                (MqttField::Throttle, to_json(&telemetry.throttle)),
+                (MqttField::Brake, to_json(&telemetry.brake)),
+                (MqttField::DrsEnabled, to_json(&telemetry.drs_enabled)),
+                (MqttField::RevLightsPercent, to_json(&telemetry.rev_lights_percent)),
+            ]);
+        } else if let Some(motion) =
+            packet.motion.as_ref().and_then(|p| p.data.get(vehicle_index))
+        {
+            values.extend([
+                (MqttField::GForceLateral, to_json(&motion.g_force_lateral)),
+                (MqttField::GForceLongitudinal, to_json(&motion.g_force_longitudinal)),
+                (MqttField::GForceVertical, to_json(&motion.g_force_vertical)),
+            ]);
+        } else if let Some(event) = &packet.event {
+            let Some(topic) = self.topic(MqttField::Event) else {
+                return Vec::new();
+            };
+            let payload = match self.format {
+                PayloadFormat::Plain => event.code.clone(),
+                PayloadFormat::Json => to_json(&EventPayload {
+                    code: &event.code,
+                    details: &event.details,
+                    session_time,
+                }),
+            };
+
+            return vec![MqttMessage { topic, payload, retain: self.retain }];
+        }
+
+        values
+            .into_iter()
+            .filter_map(|(field, value)| {
+                let topic = self.topic(field)?;
+                let payload = match self.format {
+                    PayloadFormat::Plain => value,
+                    PayloadFormat::Json => format!(
+                        r#"{{"value":{},"vehicle_index":{},"session_time":{}}}"#,
+                        value,
+                        vehicle_index,
+                        to_json(&session_time)
+                    ),
+                };
+
+                Some(MqttMessage { topic, payload, retain: self.retain })
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+struct EventPayload<'a> {
+    code: &'a str,
+    details: &'a EventDetails,
+    session_time: f32,
+}
+
+// Serializing directly keeps the shortest representation of `f32` values.
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Publishes mapped packet fields to an MQTT broker,
+/// using MQTT 3.1.1 with QoS 0 (at most once delivery).
+///
+/// The connection is handled by [`rumqttc`] on a background thread,
+/// which also sends keep alive pings while no packets are being published,
+/// e.g. when the game is paused or in a menu.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::mqtt::{MqttField, MqttMapping, MqttPublisher};
+/// use f1_game_packet_parser::parse;
+/// use std::net::UdpSocket;
+///
+/// let mapping = MqttMapping::new()
+///     .with_topic_prefix("rig/")
+///     .with_topic(MqttField::RevLightsPercent, "rig/leds/rev");
+/// let mut publisher = MqttPublisher::new("f1-rig")
+///     .with_mapping(mapping)
+///     .with_keep_alive(30)
+///     .connect("localhost:1883")?;
+/// let socket = UdpSocket::bind("0.0.0.0:20777")?;
+/// let mut buf = [0u8; 2048];
+///
+/// loop {
+///     let len = socket.recv(&mut buf)?;
+///
+///     if let Ok(packet) = parse(&buf[..len]) {
+///         publisher.publish(&packet)?;
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct MqttPublisher {
+    client_id: String,
+    credentials: Option<(String, Option<String>)>,
+    keep_alive: u16,
+    mapping: MqttMapping,
+    client: Option<Client>,
+    connection: Option<JoinHandle<()>>,
+    /// Kind and message of the error that closed the connection.
+    failure: Arc<Mutex<Option<(ErrorKind, String)>>>,
+}
+
+impl fmt::Debug for MqttPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MqttPublisher")
+            .field("client_id", &self.client_id)
+            .field("keep_alive", &self.keep_alive)
+            .field("mapping", &self.mapping)
+            .field("connected", &self.client.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MqttPublisher {
+    /// Creates a disconnected publisher with the given client identifier
+    /// and the default [`MqttMapping`].
+    pub fn new(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_owned(),
+            credentials: None,
+            keep_alive: 0,
+            mapping: MqttMapping::new(),
+            client: None,
+            connection: None,
+            failure: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Sets the mapping of fields into topics and payloads.
+    pub fn with_mapping(mut self, mapping: MqttMapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Sets the username and optional password sent when connecting.
+    pub fn with_credentials(mut self, username: &str, password: Option<&str>) -> Self {
+        self.credentials = Some((username.to_owned(), password.map(str::to_owned)));
+        self
+    }
+
+    /// Sets the keep alive interval in seconds. Pings get sent on a timer
+    /// by the connection's background thread, independently of [`publish`](Self::publish).
+    /// Defaults to 0, i.e. disabled.
+    pub fn with_keep_alive(mut self, seconds: u16) -> Self {
+        self.keep_alive = seconds;
+        self
+    }
+
+    /// Returns the mapping of fields into topics and payloads.
+    pub fn mapping(&self) -> &MqttMapping {
+        &self.mapping
+    }
+
+    /// Connects to the broker and waits for it to accept the connection.
+    pub fn connect(mut self, addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let addr = addr.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(ErrorKind::InvalidInput, "No address to connect to")
+        })?;
+        let host = match addr {
+            SocketAddr::V4(addr) => addr.ip().to_string(),
+            SocketAddr::V6(addr) => format!("[{}]", addr.ip()),
+        };
+        let mut options = MqttOptions::new(&self.client_id, host, addr.port());
+
+        options
+            .set_clean_session(true)
+            .set_keep_alive(Duration::from_secs(u64::from(self.keep_alive)));
+
+        if let Some((username, password)) = &self.credentials {
+            // An empty password leaves the password flag unset.
+            options.set_credentials(username, password.as_deref().unwrap_or_default());
+        }
+
+        let (client, mut connection) = Client::new(options, REQUEST_CAPACITY);
+
+        loop {
+            match connection.recv_timeout(CONNECT_TIMEOUT) {
+                Ok(Ok(Event::Incoming(Packet::ConnAck(_)))) => break,
+                Ok(Ok(_)) => (),
+                Ok(Err(error)) => return Err(into_io_error(error)),
+                Err(_) => return Err(ErrorKind::TimedOut.into()),
+            }
+        }
+
+        let failure = Arc::clone(&self.failure);
+
+        self.client = Some(client);
+        self.connection = Some(thread::spawn(move || {
+            for event in connection.iter() {
+                match event {
+                    Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+                    Ok(_) => (),
+                    Err(error) => {
+                        let error = into_io_error(error);
+
+                        *failure.lock().unwrap_or_else(PoisonError::into_inner) =
+                            Some((error.kind(), error.to_string()));
+                        break;
+                    }
+                }
+            }
+        }));
+
+        Ok(self)
+    }
+
+    /// Publishes mapped fields of the packet. Returns the number of published messages.
+    ///
+    /// ## Errors
+    ///
+    /// See [`publish_message`](Self::publish_message). Messages following
+    /// the one that has failed to get published are dropped.
+    pub fn publish(&mut self, packet: &F1Packet) -> io::Result<usize> {
+        let messages = self.mapping.messages(packet);
+
+        for message in &messages {
+            self.publish_message(message)?;
+        }
+
+        Ok(messages.len())
+    }
+
+    /// Publishes a single message without blocking.
+    ///
+    /// ## Errors
+    ///
+    /// Fails if the publisher isn't connected, or with the error that has closed
+    /// the connection if it's been lost. Fails with [`ErrorKind::InvalidInput`]
+    /// if the topic contains wildcards, and with [`ErrorKind::WouldBlock`]
+    /// if the queue of outgoing messages is full, e.g. because the broker
+    /// can't keep up, in which case the message is dropped.
+    pub fn publish_message(&mut self, message: &MqttMessage) -> io::Result<()> {
+        self.check_connection()?;
+
+        let client = self.client.as_ref().ok_or(ErrorKind::NotConnected)?;
+
+        if !valid_topic(&message.topic) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid topic: {}", message.topic),
+            ));
+        }
+
+        client
+            .try_publish(
+                message.topic.as_str(),
+                QoS::AtMostOnce,
+                message.retain,
+                message.payload.as_bytes(),
+            )
+            .map_err(|error| match self.check_connection() {
+                Err(connection_error) => connection_error,
+                Ok(()) => io::Error::new(ErrorKind::WouldBlock, error),
+            })
+    }
+
+    /// Sends a disconnect notification and closes the connection.
+    pub fn disconnect(mut self) -> io::Result<()> {
+        let client = self.client.take().ok_or(ErrorKind::NotConnected)?;
+
+        self.check_connection()?;
+        client
+            .disconnect()
+            .map_err(|error| io::Error::new(ErrorKind::NotConnected, error))?;
+
+        if let Some(connection) = self.connection.take() {
+            let _ = connection.join();
+        }
+
+        self.check_connection()
+    }
+
+    /// Returns the error that closed the connection, if there's been one.
+    fn check_connection(&self) -> io::Result<()> {
+        match &*self.failure.lock().unwrap_or_else(PoisonError::into_inner) {
+            Some((kind, message)) => Err(io::Error::new(*kind, message.as_str())),
+            None => Ok(()),
+        }
+    }
+}
+
+fn into_io_error(error: ConnectionError) -> io::Error {
+    match error {
+        ConnectionError::Io(error) => error,
+        ConnectionError::ConnectionRefused(code) => io::Error::new(
+            ErrorKind::ConnectionRefused,
+            format!("Broker refused the connection: {:?}", code),
+        ),
+        error => io::Error::new(ErrorKind::ConnectionAborted, error),
+    }
+}
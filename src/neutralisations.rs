@@ -0,0 +1,301 @@
+use crate::constants::{SafetyCarEventType, SafetyCarStatus, SafetyCarType};
+use crate::packets::event::EventDetails;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Type of a [`NeutralisationPeriod`].
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NeutralisationKind {
+    SafetyCar,
+    VirtualSafetyCar,
+    RedFlag,
+    FormationLap,
+}
+
+impl NeutralisationKind {
+    /// All period types.
+    pub const ALL: [NeutralisationKind; 4] =
+        [Self::SafetyCar, Self::VirtualSafetyCar, Self::RedFlag, Self::FormationLap];
+
+    fn from_status(status: SafetyCarStatus) -> Option<Self> {
+        match status {
+            SafetyCarStatus::Full => Some(Self::SafetyCar),
+            SafetyCarStatus::Virtual => Some(Self::VirtualSafetyCar),
+            SafetyCarStatus::FormationLap => Some(Self::FormationLap),
+            _ => None,
+        }
+    }
+
+    fn from_safety_car_type(safety_car_type: SafetyCarType) -> Option<Self> {
+        match safety_car_type {
+            SafetyCarType::Full => Some(Self::SafetyCar),
+            SafetyCarType::Virtual => Some(Self::VirtualSafetyCar),
+            SafetyCarType::FormationLap => Some(Self::FormationLap),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for NeutralisationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::SafetyCar => "Safety car",
+            Self::VirtualSafetyCar => "Virtual safety car",
+            Self::RedFlag => "Red flag",
+            Self::FormationLap => "Formation lap",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+/// Period of a session run under a safety car, virtual safety car,
+/// red flag or formation lap.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NeutralisationPeriod {
+    /// Type of the period.
+    pub kind: NeutralisationKind,
+    /// Session timestamp the period started at.
+    pub start_time: f32,
+    /// Session timestamp the period ended at, `None` if it's still ongoing.
+    pub end_time: Option<f32>,
+    /// Race leader's lap number when the period started.
+    pub start_lap: u8,
+    /// Race leader's lap number when the period ended,
+    /// `None` if it's still ongoing.
+    pub end_lap: Option<u8>,
+}
+
+impl NeutralisationPeriod {
+    /// Returns the period's duration in seconds, if it has ended.
+    pub fn duration(&self) -> Option<f32> {
+        self.end_time.map(|end_time| end_time - self.start_time)
+    }
+
+    /// Returns whether any part of the given lap has been run during this period.
+    pub fn contains_lap(&self, lap_num: u8) -> bool {
+        self.start_lap <= lap_num
+            && self.end_lap.map_or(true, |end_lap| lap_num <= end_lap)
+    }
+}
+
+impl fmt::Display for NeutralisationPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} from lap {}", self.kind, self.start_lap)?;
+
+        match (self.end_lap, self.duration()) {
+            (Some(end_lap), Some(duration)) => {
+                write!(f, " to lap {} ({:.1}s)", end_lap, duration)
+            }
+            _ => write!(f, " (ongoing)"),
+        }
+    }
+}
+
+/// Change reported by [`NeutralisationTracker::update`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NeutralisationChange {
+    /// A period has started.
+    Started(NeutralisationPeriod),
+    /// A period has ended.
+    Ended(NeutralisationPeriod),
+}
+
+/// Tracks safety car, virtual safety car, red flag and formation lap
+/// periods of a single session.
+///
+/// Periods are detected from changes of the session packet's
+/// [`safety_car_status`](field@crate::packets::F1PacketSession::safety_car_status),
+/// increases of its red flag period counter (2023 format onwards),
+/// as well as [`SafetyCar`](variant@EventDetails::SafetyCar) (2024 format onwards)
+/// and [`RedFlag`](variant@EventDetails::RedFlag) (2023 format onwards) events,
+/// so a period is picked up by whichever source reports it first.
+/// Safety car and virtual safety car counters are ignored, since the status
+/// already reports these periods, and counting them could start a period
+/// after the status has ended it.
+/// A red flag period ends when the race is restarted,
+/// i.e. on a lights out or safety car deployment event,
+/// or at the end of the session.
+///
+/// Laps are counted using the race leader's lap number,
+/// which makes lap annotations approximate for lapped cars.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct NeutralisationTracker {
+    periods: Vec<NeutralisationPeriod>,
+    leader_lap_num: u8,
+    safety_car_status: Option<SafetyCarStatus>,
+    num_red_flag_periods: Option<u8>,
+}
+
+impl NeutralisationTracker {
+    /// Creates a tracker with no periods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a parsed packet into the tracker and returns periods
+    /// that have started or ended as a result.
+    pub fn update(&mut self, packet: &F1Packet) -> Vec<NeutralisationChange> {
+        let session_time = packet.header.session_time;
+        let mut changes = Vec::new();
+
+        if let Some(session) = &packet.session {
+            let status = session.safety_car_status;
+
+            if self.safety_car_status.unwrap_or(SafetyCarStatus::None) != status {
+                let started = NeutralisationKind::from_status(status);
+
+                for kind in [
+                    NeutralisationKind::SafetyCar,
+                    NeutralisationKind::VirtualSafetyCar,
+                    NeutralisationKind::FormationLap,
+                ] {
+                    if Some(kind) != started {
+                        changes.extend(self.end(kind, session_time));
+                    }
+                }
+
+                if let Some(kind) = started {
+                    changes.extend(self.start(kind, session_time));
+                }
+            }
+
+            self.safety_car_status = Some(status);
+
+            let num_red_flag_periods = session.num_red_flag_periods;
+
+            if self
+                .num_red_flag_periods
+                .replace(num_red_flag_periods)
+                .is_some_and(|previous| num_red_flag_periods > previous)
+            {
+                changes.extend(self.start(NeutralisationKind::RedFlag, session_time));
+            }
+        } else if let Some(laps) = &packet.laps {
+            self.leader_lap_num = laps
+                .data
+                .iter()
+                .map(|lap_data| lap_data.current_lap_num)
+                .max()
+                .unwrap_or(0);
+        } else if let Some(event) = &packet.event {
+            match event.details {
+                EventDetails::SafetyCar { safety_car_type, event_type } => {
+                    if let Some(kind) =
+                        NeutralisationKind::from_safety_car_type(safety_car_type)
+                    {
+                        match event_type {
+                            SafetyCarEventType::Deployed => {
+                                changes.extend(
+                                    self.end(NeutralisationKind::RedFlag, session_time),
+                                );
+                                changes.extend(self.start(kind, session_time));
+                            }
+                            SafetyCarEventType::Returned
+                            | SafetyCarEventType::ResumeRace => {
+                                changes.extend(self.end(kind, session_time));
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                EventDetails::RedFlag => {
+                    changes.extend(self.start(NeutralisationKind::RedFlag, session_time));
+                }
+                EventDetails::LightsOut => {
+                    changes.extend(self.end(NeutralisationKind::RedFlag, session_time));
+                }
+                EventDetails::SessionEnded => {
+                    for kind in NeutralisationKind::ALL {
+                        changes.extend(self.end(kind, session_time));
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        changes
+    }
+
+    /// Returns all periods detected so far, in order of starting.
+    pub fn periods(&self) -> &[NeutralisationPeriod] {
+        &self.periods
+    }
+
+    /// Returns ongoing periods.
+    pub fn active(&self) -> impl Iterator<Item = &NeutralisationPeriod> {
+        self.periods.iter().filter(|period| period.end_time.is_none())
+    }
+
+    /// Returns the types of periods that any part of the given lap has been run under.
+    pub fn lap_neutralisations(&self, lap_num: u8) -> BTreeSet<NeutralisationKind> {
+        self.periods
+            .iter()
+            .filter(|period| period.contains_lap(lap_num))
+            .map(|period| period.kind)
+            .collect()
+    }
+
+    /// Returns every lap run at least partially under neutralisation
+    /// along with the types of periods it's been run under,
+    /// e.g. to exclude them from lap time analysis.
+    pub fn neutralised_laps(&self) -> BTreeMap<u8, BTreeSet<NeutralisationKind>> {
+        let mut laps: BTreeMap<u8, BTreeSet<NeutralisationKind>> = BTreeMap::new();
+
+        for period in &self.periods {
+            let end_lap = period.end_lap.unwrap_or(self.leader_lap_num);
+
+            for lap_num in period.start_lap..=end_lap.max(period.start_lap) {
+                laps.entry(lap_num).or_default().insert(period.kind);
+            }
+        }
+
+        laps
+    }
+
+    fn start(
+        &mut self,
+        kind: NeutralisationKind,
+        session_time: f32,
+    ) -> Option<NeutralisationChange> {
+        if self.active().any(|period| period.kind == kind) {
+            return None;
+        }
+
+        let period = NeutralisationPeriod {
+            kind,
+            start_time: session_time,
+            end_time: None,
+            start_lap: self.leader_lap_num,
+            end_lap: None,
+        };
+
+        self.periods.push(period);
+        Some(NeutralisationChange::Started(period))
+    }
+
+    fn end(
+        &mut self,
+        kind: NeutralisationKind,
+        session_time: f32,
+    ) -> Option<NeutralisationChange> {
+        let period = self
+            .periods
+            .iter_mut()
+            .rev()
+            .find(|period| period.kind == kind && period.end_time.is_none())?;
+
+        period.end_time = Some(session_time);
+        period.end_lap = Some(self.leader_lap_num);
+        Some(NeutralisationChange::Ended(*period))
+    }
+}
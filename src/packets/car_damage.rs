@@ -1,10 +1,13 @@
 use super::u8_to_bool;
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::struct_excessive_bools)]
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct CarDamageData {
     /// Tyre wear percentage for all wheels.
@@ -1,8 +1,10 @@
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct CarSetupData {
     /// Front wing aero.
@@ -4,11 +4,14 @@ use crate::constants::{
     VehicleFiaFlag, VisualTyreCompound,
 };
 
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct CarStatusData {
     /// How much traction control is enabled.
@@ -1,7 +1,9 @@
 use super::u8_to_bool;
 use crate::constants::{RevLights, Surface};
 
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 // f32 range ends were offset by a tenth to account for values ever so slightly
@@ -10,7 +12,8 @@ use serde::{Deserialize, Serialize};
 // but this will do for now.
 
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct CarTelemetryData {
     /// Speed of the car in kilometres per hour.
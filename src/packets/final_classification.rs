@@ -1,13 +1,16 @@
 use super::u8_to_usize;
 use crate::constants::{ActualTyreCompound, ResultStatus, VisualTyreCompound};
 
+use alloc::vec::Vec;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 const MAX_NUM_TYRE_STINTS: usize = 8;
 
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct FinalClassificationData {
     /// Finishing position.
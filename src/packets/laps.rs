@@ -1,12 +1,15 @@
 use super::u8_to_bool;
 use crate::constants::{DriverStatus, PitStatus, ResultStatus, Sector};
 
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Lap data for a car on track.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct LapData {
     /// Last lap time in milliseconds.
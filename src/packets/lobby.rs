@@ -1,13 +1,15 @@
-use super::{read_name, u8_to_bool};
+use super::{is_name_lossy, is_placeholder_name, read_name, u8_to_bool};
 use crate::constants::{Nationality, Platform, ReadyStatus, YourTelemetry};
 
+use alloc::boxed::Box;
+use alloc::string::String;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct LobbyInfoData {
     /// Whether the vehicle is controlled by AI.
@@ -22,9 +24,13 @@ pub struct LobbyInfoData {
     /// Available from the 2023 format onwards.
     #[br(if(packet_format >= 2023))]
     pub platform: Option<Platform>,
-    /// Driver's name.
-    #[br(try_map(read_name))]
+    /// Driver's name. Invalid UTF-8 sequences get replaced with U+FFFD.
+    #[br(map(read_name), restore_position)]
     pub name: String,
+    /// Whether any invalid UTF-8 sequences in the name have been replaced.
+    #[br(map(is_name_lossy))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name_lossy: bool,
     /// Player's car number.
     pub car_number: u8,
     /// The player's "Your Telemetry" visibility setting.
@@ -42,3 +48,12 @@ pub struct LobbyInfoData {
     /// Readiness status.
     pub ready_status: ReadyStatus,
 }
+
+impl LobbyInfoData {
+    /// Whether the name is missing or is a generic one sent by the game
+    /// in place of an online name (e.g. `Player` or `Driver 12`),
+    /// which happens when [`show_online_names`](Self::show_online_names) is off.
+    pub fn has_placeholder_name(&self) -> bool {
+        self.name.is_empty() || is_placeholder_name(&self.name)
+    }
+}
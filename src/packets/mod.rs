@@ -43,15 +43,20 @@ use crate::packets::session_history::{
 use crate::packets::time_trial::TimeTrialDataSet;
 use crate::packets::tyre_sets::{TyreSetData, NUM_TYRE_SETS};
 
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use binrw::BinRead;
+use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
-use std::string::FromUtf8Error;
 
 /// The motion packet gives physics data for all the cars being driven.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketMotion {
     /// Motion data for all cars on track. Should have a size of 22.
@@ -67,7 +72,8 @@ pub struct F1PacketMotion {
 /// ## Example
 #[allow(clippy::struct_excessive_bools)]
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketSession {
     /// Current weather.
@@ -367,7 +373,8 @@ pub struct F1PacketSession {
 
 /// Data about all the lap times of cars in the session.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketLaps {
     /// Lap data for all cars on track. Should have a size of 22.
@@ -383,7 +390,8 @@ pub struct F1PacketLaps {
 
 /// Various notable events that happen during a session.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketEvent {
     /// 4-letter event code.
@@ -399,7 +407,8 @@ pub struct F1PacketEvent {
 
 /// Data of participants in the session, mostly relevant for multiplayer.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(
     little,
     import(packet_format: u16),
@@ -424,7 +433,8 @@ pub struct F1PacketParticipants {
 /// In multiplayer games, other player cars will appear as blank.
 /// You will only be able to see your car setup and AI cars.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketCarSetups {
     /// Setup data for all cars on track. Should have a size of 22.
@@ -438,7 +448,8 @@ pub struct F1PacketCarSetups {
 
 /// Telemetry (such as speed, DRS, throttle application, etc.)
 /// for all cars in the race.
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketCarTelemetry {
     /// Telemetry data for all cars on track. Should have a size of 22.
@@ -461,7 +472,8 @@ pub struct F1PacketCarTelemetry {
 
 /// Car status data for each car in the race.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketCarStatus {
     /// Car status data for all cars. Should have a size of 22.
@@ -471,7 +483,8 @@ pub struct F1PacketCarStatus {
 
 /// Final classification confirmation at the end of a race.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketFinalClassification {
     /// Number of cars in the final classification.
@@ -493,7 +506,8 @@ pub struct F1PacketFinalClassification {
 
 /// Packet detailing all the players that are currently in a multiplayer lobby.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketLobby {
     /// Number of players in the lobby.
@@ -515,7 +529,8 @@ pub struct F1PacketLobby {
 
 /// Car damage parameters for all cars in the session.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketCarDamage {
     /// Car damage data. Should have a size of 22.
@@ -525,7 +540,8 @@ pub struct F1PacketCarDamage {
 
 /// Packet detailing lap and tyre data history for a given driver in the session.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketSessionHistory {
     /// Index of the car this packet refers to.
@@ -588,7 +604,8 @@ pub struct F1PacketSessionHistory {
 /// In-depth details about tyre sets assigned to a vehicle during the session.
 /// Available from the 2023 format onwards.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketTyreSets {
     /// Index of the car this packet relates to.
@@ -613,7 +630,8 @@ pub struct F1PacketTyreSets {
 /// - part of [`F1PacketMotion`] in the 2022 format
 /// - standalone packet from the 2023 format onwards
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct F1PacketMotionEx {
     /// Positions of suspension for each wheel.
@@ -709,9 +727,8 @@ pub struct F1PacketMotionEx {
 /// Extra information that's only relevant to time trial game mode.
 /// Available from the 2024 format onwards.
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct F1PacketTimeTrial {
     /// Data set of player's best run this session.
@@ -731,6 +748,7 @@ impl fmt::Display for MapBoolError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for MapBoolError {}
 
 pub(crate) fn u8_to_bool(value: u8) -> Result<bool, MapBoolError> {
@@ -745,9 +763,34 @@ pub(crate) fn u8_to_usize(value: u8) -> usize {
     value as usize
 }
 
-pub(crate) fn read_name(bytes: [u8; 48]) -> Result<String, FromUtf8Error> {
+fn trim_name(bytes: &[u8]) -> &[u8] {
     let first_nul_index =
         bytes.iter().position(|&byte| byte == b'\0').unwrap_or(bytes.len());
 
-    String::from_utf8(bytes[..first_nul_index].to_vec())
+    &bytes[..first_nul_index]
+}
+
+/// Decodes a name, replacing invalid UTF-8 sequences with U+FFFD.
+pub(crate) fn read_name(bytes: [u8; 48]) -> String {
+    String::from_utf8_lossy(trim_name(&bytes)).into_owned()
+}
+
+/// Whether [`read_name`] has had to replace any invalid UTF-8 sequences.
+pub(crate) fn is_name_lossy(bytes: [u8; 48]) -> bool {
+    core::str::from_utf8(trim_name(&bytes)).is_err()
+}
+
+/// Whether a name is one of the generic names the game sends
+/// in place of online names, such as `Player` or `Driver 12`.
+pub(crate) fn is_placeholder_name(name: &str) -> bool {
+    let rest =
+        ["Player", "Driver"].into_iter().find_map(|prefix| name.strip_prefix(prefix));
+
+    match rest {
+        Some("") => true,
+        Some(rest) => rest.strip_prefix(' ').is_some_and(|number| {
+            !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit())
+        }),
+        None => false,
+    }
 }
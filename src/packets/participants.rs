@@ -1,13 +1,18 @@
-use super::{read_name, u8_to_bool};
+use super::{is_name_lossy, is_placeholder_name, read_name, u8_to_bool};
+use crate::constants::driver_id::driver_name;
 use crate::constants::{Nationality, Platform, YourTelemetry};
 
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct ParticipantsData {
     /// Whether the vehicle is controlled by AI.
@@ -29,9 +34,13 @@ pub struct ParticipantsData {
     pub race_number: u8,
     /// Driver's nationality.
     pub nationality: Nationality,
-    /// Driver's name.
-    #[br(try_map(read_name))]
+    /// Driver's name. Invalid UTF-8 sequences get replaced with U+FFFD.
+    #[br(map(read_name), restore_position)]
     pub name: String,
+    /// Whether any invalid UTF-8 sequences in the name have been replaced.
+    #[br(map(is_name_lossy))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name_lossy: bool,
     /// Player's UDP visibility setting.
     pub your_telemetry: Option<YourTelemetry>,
     /// Whether this player's "show online names" setting is on.
@@ -47,3 +56,31 @@ pub struct ParticipantsData {
     #[br(if(packet_format >= 2023))]
     pub platform: Option<Platform>,
 }
+
+impl ParticipantsData {
+    /// Whether the name is missing or is a generic one sent by the game
+    /// in place of an online name (e.g. `Player` or `Driver 12`),
+    /// which happens when [`show_online_names`](Self::show_online_names) is off.
+    pub fn has_placeholder_name(&self) -> bool {
+        self.name.is_empty() || is_placeholder_name(&self.name)
+    }
+
+    /// Returns a name suitable for display.
+    ///
+    /// Placeholder names of AI cars get replaced with the full name matching
+    /// their [`driver_id`](Self::driver_id), and missing names
+    /// are replaced with `Driver` followed by the race number.
+    pub fn display_name(&self, packet_format: u16) -> Cow<'_, str> {
+        if !self.has_placeholder_name() {
+            return Cow::Borrowed(&self.name);
+        }
+
+        match driver_name(self.driver_id, packet_format) {
+            Some(name) if self.ai_controlled => Cow::Borrowed(name),
+            _ if self.name.is_empty() => {
+                Cow::Owned(format!("Driver {}", self.race_number))
+            }
+            _ => Cow::Borrowed(&self.name),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::constants::{MarshalZoneFlag, TemperatureChange, Weather};
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub(super) const MAX_NUM_MARSHAL_ZONES: usize = 21;
@@ -10,7 +11,8 @@ pub(super) const MAX_NUM_SESSIONS: usize = 12;
 
 /// Section of the track supervised by marshals.
 #[non_exhaustive]
-#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(BinRead, PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(
     little,
     import(_packet_format: u16),
@@ -29,9 +31,8 @@ pub struct MarshalZone {
 
 #[non_exhaustive]
 /// Weather forecast sample for a given session.
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(
     little,
     import(_packet_format: u16),
@@ -2,15 +2,15 @@ use super::u8_to_usize;
 use crate::constants::{ActualTyreCompound, LapValid, VisualTyreCompound};
 
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub(super) const MAX_NUM_LAPS: usize = 100;
 pub(super) const MAX_NUM_TYRE_STINTS: usize = 8;
 
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(packet_format: u16))]
 pub struct LapHistoryData {
     /// Lap time in milliseconds.
@@ -39,9 +39,8 @@ pub struct LapHistoryData {
 }
 
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct TyreStintHistoryData {
     /// Lap the tyre usage ends on (255 if current tyre).
@@ -1,14 +1,15 @@
 use super::{u8_to_bool, u8_to_usize};
 use crate::constants::{GearboxAssist, TractionControl, MAX_NUM_CARS};
 
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::struct_excessive_bools)]
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(little, import(_packet_format: u16))]
 pub struct TimeTrialDataSet {
     /// Index of the car this data set relates to.
@@ -1,15 +1,16 @@
 use super::u8_to_bool;
 use crate::constants::{ActualTyreCompound, VisualTyreCompound};
 
+use alloc::boxed::Box;
 use binrw::BinRead;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub(super) const NUM_TYRE_SETS: usize = 20;
 
 #[non_exhaustive]
-#[derive(
-    BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize,
-)]
+#[derive(BinRead, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[br(
     little,
     import(_packet_format: u16),
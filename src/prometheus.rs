@@ -0,0 +1,400 @@
+use crate::constants::wheel_index::{FRONT_LEFT, FRONT_RIGHT, REAR_LEFT, REAR_RIGHT};
+use crate::constants::PacketId;
+use crate::{F1Packet, F1PacketHeader};
+
+use binrw::BinRead;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Cursor, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_CONCURRENT_SCRAPES: usize = 8;
+/// Longest request line and headers accepted, in bytes.
+const MAX_REQUEST_HEAD_LEN: u64 = 8 * 1024;
+const WHEELS: [(usize, &str); 4] = [
+    (REAR_LEFT, "rear_left"),
+    (REAR_RIGHT, "rear_right"),
+    (FRONT_LEFT, "front_left"),
+    (FRONT_RIGHT, "front_right"),
+];
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct PlayerGauges {
+    speed: Option<u16>,
+    gear: Option<i8>,
+    engine_rpm: Option<u16>,
+    throttle: Option<f32>,
+    brake: Option<f32>,
+    ers_store_energy: Option<f32>,
+    fuel_in_tank: Option<f32>,
+    fuel_remaining_laps: Option<f32>,
+    tyres_wear: Option<[f32; 4]>,
+    car_position: Option<u8>,
+    current_lap_num: Option<u8>,
+    current_lap_time_ms: Option<u32>,
+    last_lap_time_ms: Option<u32>,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct MetricsState {
+    gauges: PlayerGauges,
+    packets_received: BTreeMap<PacketId, u64>,
+    packets_failed: BTreeMap<Option<PacketId>, u64>,
+}
+
+/// Collects Prometheus metrics of the player's car and packet counts,
+/// and serves them over HTTP in the text exposition format.
+///
+/// Cloned exporters share the same metrics, so one clone can be fed with packets
+/// while another one serves them.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::parse;
+/// use f1_game_packet_parser::prometheus::PrometheusExporter;
+/// use std::net::UdpSocket;
+///
+/// let exporter = PrometheusExporter::new();
+/// let _server = exporter.serve("0.0.0.0:9100")?;
+/// let socket = UdpSocket::bind("0.0.0.0:20777")?;
+/// let mut buf = [0u8; 2048];
+///
+/// loop {
+///     let len = socket.recv(&mut buf)?;
+///
+///     match parse(&buf[..len]) {
+///         Ok(packet) => exporter.update(&packet),
+///         Err(_) => exporter.record_failure(&buf[..len]),
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PrometheusExporter {
+    state: Arc<Mutex<MetricsState>>,
+}
+
+impl PrometheusExporter {
+    /// Creates an exporter with no recorded metrics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts a received packet and updates the player car's gauges.
+    /// Only lap data, car telemetry, car status and car damage packets
+    /// affect the gauges.
+    pub fn update(&self, packet: &F1Packet) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let player = packet.header.player_car_index;
+        let gauges = &mut state.gauges;
+
+        if let Some(lap_data) = packet.laps.as_ref().and_then(|p| p.data.get(player)) {
+            gauges.car_position = Some(lap_data.car_position);
+            gauges.current_lap_num = Some(lap_data.current_lap_num);
+            gauges.current_lap_time_ms = Some(lap_data.current_lap_time_ms);
+            gauges.last_lap_time_ms = Some(lap_data.last_lap_time_ms);
+        } else if let Some(telemetry) =
+            packet.car_telemetry.as_ref().and_then(|p| p.data.get(player))
+        {
+            gauges.speed = Some(telemetry.speed);
+            gauges.gear = Some(telemetry.gear);
+            gauges.engine_rpm = Some(telemetry.engine_rpm);
+            gauges.throttle = Some(telemetry.throttle);
+            gauges.brake = Some(telemetry.brake);
+        } else if let Some(status) =
+            packet.car_status.as_ref().and_then(|p| p.data.get(player))
+        {
+            gauges.ers_store_energy = Some(status.ers_store_energy);
+            gauges.fuel_in_tank = Some(status.fuel_in_tank);
+            gauges.fuel_remaining_laps = Some(status.fuel_remaining_laps);
+        } else if let Some(damage) =
+            packet.car_damage.as_ref().and_then(|p| p.data.get(player))
+        {
+            gauges.tyres_wear = Some(damage.tyres_wear);
+        }
+
+        *state.packets_received.entry(packet.header.packet_id).or_default() += 1;
+    }
+
+    /// Counts a packet that has failed to parse. The packet type gets read
+    /// from the header, if the header itself is valid.
+    pub fn record_failure(&self, data: &[u8]) {
+        let packet_id = F1PacketHeader::read(&mut Cursor::new(data))
+            .ok()
+            .map(|header| header.packet_id);
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *state.packets_failed.entry(packet_id).or_default() += 1;
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let gauges = &state.gauges;
+        let mut out = String::new();
+
+        gauge(
+            &mut out,
+            "f1_speed_kmh",
+            "Speed of the player's car in km/h.",
+            gauges.speed,
+        );
+        gauge(
+            &mut out,
+            "f1_gear",
+            "Selected gear, 0 = neutral, -1 = reverse.",
+            gauges.gear,
+        );
+        gauge(&mut out, "f1_engine_rpm", "Engine speed in RPM.", gauges.engine_rpm);
+        gauge(
+            &mut out,
+            "f1_throttle_ratio",
+            "Throttle application, 0 to 1.",
+            gauges.throttle,
+        );
+        gauge(&mut out, "f1_brake_ratio", "Brake application, 0 to 1.", gauges.brake);
+        gauge(
+            &mut out,
+            "f1_ers_store_energy_joules",
+            "ERS energy store in Joules.",
+            gauges.ers_store_energy,
+        );
+        gauge(&mut out, "f1_fuel_in_tank_kg", "Current fuel mass.", gauges.fuel_in_tank);
+        gauge(
+            &mut out,
+            "f1_fuel_remaining_laps",
+            "Laps of fuel remaining according to the game.",
+            gauges.fuel_remaining_laps,
+        );
+
+        if let Some(wear) = gauges.tyres_wear {
+            header(&mut out, "f1_tyre_wear_percent", "gauge", "Tyre wear percentage.");
+
+            for (wheel, label) in WHEELS {
+                let _ = writeln!(
+                    out,
+                    "f1_tyre_wear_percent{{wheel=\"{}\"}} {}",
+                    label, wear[wheel]
+                );
+            }
+        }
+
+        gauge(&mut out, "f1_car_position", "Race position.", gauges.car_position);
+        gauge(&mut out, "f1_current_lap", "Current lap number.", gauges.current_lap_num);
+        gauge(
+            &mut out,
+            "f1_current_lap_time_seconds",
+            "Time spent on the current lap.",
+            gauges.current_lap_time_ms.map(|ms| f64::from(ms) / 1000.0),
+        );
+        gauge(
+            &mut out,
+            "f1_last_lap_time_seconds",
+            "Time of the last completed lap.",
+            gauges.last_lap_time_ms.map(|ms| f64::from(ms) / 1000.0),
+        );
+
+        header(
+            &mut out,
+            "f1_packets_received_total",
+            "counter",
+            "Packets parsed successfully by type.",
+        );
+
+        for (packet_id, count) in &state.packets_received {
+            let _ = writeln!(
+                out,
+                "f1_packets_received_total{{packet_id=\"{:?}\"}} {}",
+                packet_id, count
+            );
+        }
+
+        header(
+            &mut out,
+            "f1_packets_failed_total",
+            "counter",
+            "Packets that failed to parse by type.",
+        );
+
+        for (packet_id, count) in &state.packets_failed {
+            let _ = match packet_id {
+                Some(packet_id) => writeln!(
+                    out,
+                    "f1_packets_failed_total{{packet_id=\"{:?}\"}} {}",
+                    packet_id, count
+                ),
+                None => {
+                    writeln!(
+                        out,
+                        "f1_packets_failed_total{{packet_id=\"Unknown\"}} {}",
+                        count
+                    )
+                }
+            };
+        }
+
+        out
+    }
+
+    /// Starts serving metrics at `/metrics` on a background thread.
+    /// Each scrape runs on its own short-lived thread,
+    /// so slow clients don't hold up other scrapes.
+    /// Up to 8 scrapes are handled at a time,
+    /// with connections above that limit getting closed straight away.
+    /// Requests with more than 8 KiB of request line and headers
+    /// get a 431 response.
+    /// The server stops once the returned handle gets dropped.
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> io::Result<MetricsServer> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let running = Arc::new(AtomicBool::new(true));
+        let exporter = self.clone();
+        let thread_running = Arc::clone(&running);
+        let active_scrapes = Arc::new(AtomicUsize::new(0));
+
+        listener.set_nonblocking(true)?;
+
+        let thread = thread::spawn(move || {
+            while thread_running.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        // Dropping the stream closes connections above the limit.
+                        let Some(slot) = ScrapeSlot::acquire(&active_scrapes) else {
+                            continue;
+                        };
+                        let exporter = exporter.clone();
+
+                        // A failed response only affects a single scrape.
+                        thread::spawn(move || {
+                            let _slot = slot;
+                            exporter.respond(stream)
+                        });
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                        thread::sleep(POLL_INTERVAL);
+                    }
+                    Err(_) => thread::sleep(POLL_INTERVAL),
+                }
+            }
+        });
+
+        Ok(MetricsServer { local_addr, running, thread: Some(thread) })
+    }
+
+    fn respond(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+
+        // Clients that never finish their request mustn't make it grow without bound.
+        let mut reader = BufReader::new((&stream).take(MAX_REQUEST_HEAD_LEN));
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        // Skip the rest of the request headers.
+        let mut line = String::new();
+
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let too_large = reader.get_ref().limit() == 0 && line != "\r\n" && line != "\n";
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next();
+        // Scrapers may add query parameters, which don't affect the metrics.
+        let path = parts.next().and_then(|target| target.split('?').next());
+        let (status, content_type, body) = match (method, path) {
+            _ if too_large => (
+                "431 Request Header Fields Too Large",
+                "text/plain; charset=utf-8",
+                "Request Header Fields Too Large\n".to_owned(),
+            ),
+            (Some("GET"), Some("/metrics")) => {
+                ("200 OK", "text/plain; version=0.0.4; charset=utf-8", self.render())
+            }
+            _ => ("404 Not Found", "text/plain; charset=utf-8", "Not Found\n".to_owned()),
+        };
+        let mut stream = &stream;
+
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+}
+
+/// Place among the scrapes being handled, given back once it gets dropped.
+struct ScrapeSlot(Arc<AtomicUsize>);
+
+impl ScrapeSlot {
+    fn acquire(active: &Arc<AtomicUsize>) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < MAX_CONCURRENT_SCRAPES).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ScrapeSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Handle of an HTTP server started by [`PrometheusExporter::serve`].
+/// Dropping it stops the server.
+#[derive(Debug)]
+pub struct MetricsServer {
+    local_addr: SocketAddr,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MetricsServer {
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn gauge(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    value: Option<impl std::fmt::Display>,
+) {
+    if let Some(value) = value {
+        header(out, name, "gauge", help);
+        let _ = writeln!(out, "{} {}", name, value);
+    }
+}
@@ -0,0 +1,926 @@
+use crate::constants::{
+    InfringementType, PenaltyType, ResultStatus, TrackId, VisualTyreCompound,
+};
+use crate::neutralisations::{NeutralisationPeriod, NeutralisationTracker};
+use crate::packets::event::EventDetails;
+use crate::packets::final_classification::FinalClassificationData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+/// Row of a [`RaceReport`]'s results table.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReportResult {
+    /// Finishing position.
+    pub position: u8,
+    /// Index of the car.
+    pub vehicle_index: usize,
+    /// Driver's display name.
+    pub name: String,
+    /// Grid position.
+    pub grid_position: u8,
+    /// Number of laps completed.
+    pub num_laps: u8,
+    /// Result status.
+    pub result_status: ResultStatus,
+    /// Total race time in seconds, including penalties.
+    pub total_race_time: f64,
+    /// Best lap time in milliseconds.
+    pub best_lap_time_ms: u32,
+    /// Number of pit stops made.
+    pub num_pit_stops: u8,
+    /// Total penalties in seconds.
+    pub penalties_time: u8,
+    /// Number of points scored.
+    pub points: u8,
+    /// Tyre stints.
+    pub stints: Vec<ReportStint>,
+}
+
+/// Tyre stint of a [`ReportResult`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReportStint {
+    /// Visual compound of the stint's tyres.
+    pub compound: VisualTyreCompound,
+    /// First lap of the stint.
+    pub start_lap: u8,
+    /// Last lap of the stint.
+    pub end_lap: u8,
+}
+
+/// Penalty listed in a [`RaceReport`].
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReportPenalty {
+    /// Session timestamp of the penalty.
+    pub session_time: f32,
+    /// Lap the infringement occurred on.
+    pub lap_num: u8,
+    /// Driver the penalty has been applied to.
+    pub name: String,
+    /// Other driver involved.
+    pub other_name: Option<String>,
+    /// Penalty type.
+    pub penalty_type: PenaltyType,
+    /// Infringement type.
+    pub infringement_type: InfringementType,
+    /// Time gained or spent in seconds.
+    pub time: u8,
+}
+
+/// Overtake listed in a [`RaceReport`].
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReportOvertake {
+    /// Session timestamp of the overtake.
+    pub session_time: f32,
+    /// Lap the overtaking driver was on.
+    pub lap_num: u8,
+    /// Overtaking driver.
+    pub name: String,
+    /// Overtaken driver.
+    pub overtaken_name: String,
+}
+
+/// Fastest lap of a [`RaceReport`].
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReportFastestLap {
+    /// Driver who has set the lap.
+    pub name: String,
+    /// Lap time in milliseconds.
+    pub lap_time_ms: u32,
+    /// Number of the lap, if known.
+    pub lap_num: Option<u8>,
+}
+
+/// Lap chart row of a single car.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LapChartRow {
+    /// Driver's display name.
+    pub name: String,
+    /// Grid position followed by the car's position at the end of each lap,
+    /// including the final lap once the final classification has been received.
+    pub positions: Vec<u8>,
+}
+
+/// Report of a race, rendered by [`to_markdown`](Self::to_markdown)
+/// and [`to_html`](Self::to_html).
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RaceReport {
+    /// Title of the report.
+    pub title: String,
+    /// Track the race has been held on.
+    pub track_id: Option<TrackId>,
+    /// Results ordered by finishing position.
+    /// Empty if the final classification hasn't been received.
+    pub results: Vec<ReportResult>,
+    /// Lap chart rows ordered by finishing position, or by the latest position
+    /// if the final classification hasn't been received.
+    pub lap_chart: Vec<LapChartRow>,
+    /// Fastest lap of the race.
+    pub fastest_lap: Option<ReportFastestLap>,
+    /// Penalties in order of issue, not including warnings and lap invalidations.
+    pub penalties: Vec<ReportPenalty>,
+    /// Overtakes in order of occurrence. Available from the 2023 format onwards.
+    pub overtakes: Vec<ReportOvertake>,
+    /// Safety car, virtual safety car, red flag and formation lap periods
+    /// in order of starting.
+    pub neutralisations: Vec<NeutralisationPeriod>,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct CarHistory {
+    positions: Vec<u8>,
+    current_lap_num: u8,
+}
+
+/// Builds a [`RaceReport`] from all packets of a race session.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct RaceReportBuilder {
+    title: Option<String>,
+    track_id: Option<TrackId>,
+    names: Vec<String>,
+    cars: Vec<CarHistory>,
+    leader_lap_num: u8,
+    classification: Option<Vec<FinalClassificationData>>,
+    fastest_lap: Option<(usize, u32, Option<u8>)>,
+    penalties: Vec<(f32, usize, Option<usize>, EventDetails)>,
+    overtakes: Vec<(f32, u8, usize, usize)>,
+    neutralisations: NeutralisationTracker,
+}
+
+impl RaceReportBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the report's title. Defaults to the track's name.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Processes a packet of the race session.
+    pub fn update(&mut self, packet: &F1Packet) {
+        let session_time = packet.header.session_time;
+
+        self.neutralisations.update(packet);
+
+        if let Some(session) = &packet.session {
+            self.track_id = Some(session.track_id);
+        } else if let Some(laps) = &packet.laps {
+            if self.cars.len() < laps.data.len() {
+                self.cars.resize_with(laps.data.len(), CarHistory::default);
+            }
+
+            for (car, lap_data) in self.cars.iter_mut().zip(&laps.data) {
+                if lap_data.car_position == 0 || lap_data.current_lap_num == 0 {
+                    continue;
+                }
+
+                // Flashbacks can move cars back to previous laps.
+                car.positions.truncate(usize::from(lap_data.current_lap_num));
+
+                if car.positions.is_empty() {
+                    car.positions.push(lap_data.grid_position);
+                }
+
+                while car.positions.len() < usize::from(lap_data.current_lap_num) {
+                    car.positions.push(lap_data.car_position);
+                }
+
+                car.current_lap_num = lap_data.current_lap_num;
+            }
+
+            self.leader_lap_num =
+                self.cars.iter().map(|car| car.current_lap_num).max().unwrap_or(0);
+        } else if let Some(participants) = &packet.participants {
+            self.names = participants
+                .data
+                .iter()
+                .map(|participant| {
+                    participant.display_name(packet.header.packet_format).into_owned()
+                })
+                .collect();
+        } else if let Some(final_classification) = &packet.final_classification {
+            self.classification = Some(final_classification.data.clone());
+        } else if let Some(event) = &packet.event {
+            match event.details {
+                EventDetails::FastestLap { vehicle_index, lap_time } => {
+                    let lap_num = self
+                        .cars
+                        .get(vehicle_index)
+                        .map(|car| car.current_lap_num.saturating_sub(1))
+                        .filter(|&lap_num| lap_num > 0);
+
+                    self.fastest_lap = Some((
+                        vehicle_index,
+                        (lap_time * 1000.0).round() as u32,
+                        lap_num,
+                    ));
+                }
+                EventDetails::Penalty {
+                    penalty_type,
+                    vehicle_index,
+                    other_vehicle_index,
+                    ..
+                } if is_reported_penalty(penalty_type) => {
+                    let other =
+                        (other_vehicle_index != 255).then_some(other_vehicle_index);
+
+                    self.penalties.push((
+                        session_time,
+                        vehicle_index,
+                        other,
+                        event.details,
+                    ));
+                }
+                EventDetails::Overtake {
+                    overtaking_vehicle_index,
+                    overtaken_vehicle_index,
+                } => {
+                    let lap_num = self
+                        .cars
+                        .get(overtaking_vehicle_index)
+                        .map_or(self.leader_lap_num, |car| car.current_lap_num);
+
+                    self.overtakes.push((
+                        session_time,
+                        lap_num,
+                        overtaking_vehicle_index,
+                        overtaken_vehicle_index,
+                    ));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn name(&self, vehicle_index: usize) -> String {
+        self.names
+            .get(vehicle_index)
+            .cloned()
+            .unwrap_or_else(|| format!("Car {}", vehicle_index))
+    }
+
+    /// Builds the report from the packets processed so far.
+    pub fn build(&self) -> RaceReport {
+        let mut results: Vec<ReportResult> = self
+            .classification
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, data)| data.position > 0)
+            .map(|(vehicle_index, data)| ReportResult {
+                position: data.position,
+                vehicle_index,
+                name: self.name(vehicle_index),
+                grid_position: data.grid_position,
+                num_laps: data.num_laps,
+                result_status: data.result_status,
+                total_race_time: data.total_race_time + f64::from(data.penalties_time),
+                best_lap_time_ms: data.best_lap_time_ms,
+                num_pit_stops: data.num_pit_stops,
+                penalties_time: data.penalties_time,
+                points: data.points,
+                stints: stints(data),
+            })
+            .collect();
+
+        results.sort_by_key(|result| result.position);
+
+        let mut order: Vec<usize> =
+            results.iter().map(|result| result.vehicle_index).collect();
+
+        if order.is_empty() {
+            order = (0..self.cars.len())
+                .filter(|&index| !self.cars[index].positions.is_empty())
+                .collect();
+            order.sort_by_key(|&index| self.cars[index].positions.last().copied());
+        }
+
+        let lap_chart = order
+            .into_iter()
+            .filter_map(|index| {
+                let mut positions = self.cars.get(index)?.positions.clone();
+
+                // Lap data stops updating once a car finishes, so the position at
+                // the end of its final lap comes from the final classification.
+                let final_lap = self
+                    .classification
+                    .as_ref()
+                    .and_then(|classification| classification.get(index))
+                    .filter(|data| {
+                        data.position > 0 && positions.len() == usize::from(data.num_laps)
+                    });
+
+                if let Some(data) = final_lap {
+                    positions.push(data.position);
+                }
+
+                Some(LapChartRow { name: self.name(index), positions })
+            })
+            .collect();
+
+        let fastest_lap = match self.fastest_lap {
+            Some((vehicle_index, lap_time_ms, lap_num)) => Some(ReportFastestLap {
+                name: self.name(vehicle_index),
+                lap_time_ms,
+                lap_num,
+            }),
+            None => results
+                .iter()
+                .filter(|result| result.best_lap_time_ms > 0)
+                .min_by_key(|result| result.best_lap_time_ms)
+                .map(|result| ReportFastestLap {
+                    name: result.name.clone(),
+                    lap_time_ms: result.best_lap_time_ms,
+                    lap_num: None,
+                }),
+        };
+
+        let penalties = self
+            .penalties
+            .iter()
+            .filter_map(|&(session_time, vehicle_index, other, details)| match details {
+                EventDetails::Penalty {
+                    penalty_type,
+                    infringement_type,
+                    time,
+                    lap_num,
+                    ..
+                } => Some(ReportPenalty {
+                    session_time,
+                    lap_num,
+                    name: self.name(vehicle_index),
+                    other_name: other.map(|other| self.name(other)),
+                    penalty_type,
+                    infringement_type,
+                    time,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let overtakes = self
+            .overtakes
+            .iter()
+            .map(|&(session_time, lap_num, overtaking, overtaken)| ReportOvertake {
+                session_time,
+                lap_num,
+                name: self.name(overtaking),
+                overtaken_name: self.name(overtaken),
+            })
+            .collect();
+
+        RaceReport {
+            title: self.title.clone().unwrap_or_else(|| match self.track_id {
+                Some(track_id) => words(&format!("{:?}", track_id)),
+                None => "Race".to_owned(),
+            }),
+            track_id: self.track_id,
+            results,
+            lap_chart,
+            fastest_lap,
+            penalties,
+            overtakes,
+            neutralisations: self.neutralisations.periods().to_vec(),
+        }
+    }
+}
+
+fn is_reported_penalty(penalty_type: PenaltyType) -> bool {
+    !matches!(
+        penalty_type,
+        PenaltyType::Warning
+            | PenaltyType::ThisLapInvalidated
+            | PenaltyType::ThisAndNextLapInvalidated
+            | PenaltyType::ThisLapInvalidatedWithoutReason
+            | PenaltyType::ThisAndNextLapInvalidatedWithoutReason
+            | PenaltyType::ThisAndPreviousLapInvalidated
+            | PenaltyType::ThisAndPreviousLapInvalidatedWithoutReason
+    )
+}
+
+fn stints(data: &FinalClassificationData) -> Vec<ReportStint> {
+    let mut start_lap = 1;
+
+    data.tyre_stints_visual
+        .iter()
+        .zip(&data.tyre_stints_end_laps)
+        .map(|(&compound, &end_lap)| {
+            // The last stint's end lap is reported as 255.
+            let end_lap = if end_lap == 255 { data.num_laps } else { end_lap };
+            let stint = ReportStint { compound, start_lap, end_lap };
+
+            start_lap = end_lap.saturating_add(1);
+            stint
+        })
+        .collect()
+}
+
+/// Turns a `CamelCase` variant name into words, e.g. `Drive through`.
+fn words(name: &str) -> String {
+    let mut words = String::new();
+
+    for (index, c) in name.chars().enumerate() {
+        if index > 0 && c.is_ascii_uppercase() {
+            words.push(' ');
+            words.push(c.to_ascii_lowercase());
+        } else {
+            words.push(c);
+        }
+    }
+
+    words
+}
+
+fn compound_name(compound: VisualTyreCompound) -> &'static str {
+    match compound {
+        VisualTyreCompound::F1Soft | VisualTyreCompound::F2Soft => "Soft",
+        VisualTyreCompound::F1Medium | VisualTyreCompound::F2Medium => "Medium",
+        VisualTyreCompound::F1Hard | VisualTyreCompound::F2Hard => "Hard",
+        VisualTyreCompound::F1Inter => "Inter",
+        VisualTyreCompound::F1Wet | VisualTyreCompound::F2Wet => "Wet",
+        VisualTyreCompound::F2SuperSoft => "Super soft",
+        VisualTyreCompound::ClassicDry => "Dry",
+        VisualTyreCompound::ClassicWet => "Wet",
+        _ => "Unknown",
+    }
+}
+
+fn lap_time(ms: u32) -> String {
+    if ms == 0 {
+        return "-".to_owned();
+    }
+
+    format!("{}:{:02}.{:03}", ms / 60_000, ms / 1000 % 60, ms % 1000)
+}
+
+fn race_time(seconds: f64) -> String {
+    let ms = (seconds * 1000.0).round() as u64;
+    let (hours, minutes) = (ms / 3_600_000, ms / 60_000 % 60);
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}.{:03}", hours, minutes, ms / 1000 % 60, ms % 1000)
+    } else {
+        format!("{}:{:02}.{:03}", minutes, ms / 1000 % 60, ms % 1000)
+    }
+}
+
+impl RaceReport {
+    fn result_time(&self, result: &ReportResult) -> String {
+        if !matches!(result.result_status, ResultStatus::Finished | ResultStatus::Active)
+        {
+            return words(&format!("{:?}", result.result_status));
+        }
+
+        match self.results.first() {
+            Some(winner) if winner.position != result.position => {
+                let laps_down = winner.num_laps.saturating_sub(result.num_laps);
+
+                match laps_down {
+                    0 => format!(
+                        "+{:.3}s",
+                        result.total_race_time - winner.total_race_time
+                    ),
+                    1 => "+1 lap".to_owned(),
+                    _ => format!("+{} laps", laps_down),
+                }
+            }
+            _ => race_time(result.total_race_time),
+        }
+    }
+
+    fn stints_text(stints: &[ReportStint]) -> String {
+        stints
+            .iter()
+            .map(|stint| {
+                format!(
+                    "{} ({}-{})",
+                    compound_name(stint.compound),
+                    stint.start_lap,
+                    stint.end_lap
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn neutralisation_rows(&self) -> Vec<[String; 3]> {
+        self.neutralisations
+            .iter()
+            .map(|period| {
+                let laps = match period.end_lap {
+                    Some(end_lap) => format!("{}-{}", period.start_lap, end_lap),
+                    None => format!("{}-", period.start_lap),
+                };
+                let duration = period
+                    .duration()
+                    .map_or("-".to_owned(), |duration| format!("{:.1}s", duration));
+
+                [period.kind.to_string(), laps, duration]
+            })
+            .collect()
+    }
+
+    /// Renders the report as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+
+        self.write_markdown(&mut md).expect("Writing to a String can't fail");
+        md
+    }
+
+    fn write_markdown(&self, md: &mut String) -> fmt::Result {
+        writeln!(md, "# {}", markdown_escape(&self.title))?;
+        writeln!(md, "\n## Results\n")?;
+
+        if self.results.is_empty() {
+            writeln!(md, "No final classification.")?;
+        } else {
+            writeln!(
+                md,
+                "| Pos | Driver | Grid | Laps | Time | Best lap | Stops | Points |"
+            )?;
+            writeln!(md, "|---:|---|---:|---:|---|---|---:|---:|")?;
+
+            for result in &self.results {
+                writeln!(
+                    md,
+                    "| {} | {} | {} | {} | {} | {} | {} | {} |",
+                    result.position,
+                    markdown_escape(&result.name),
+                    result.grid_position,
+                    result.num_laps,
+                    self.result_time(result),
+                    lap_time(result.best_lap_time_ms),
+                    result.num_pit_stops,
+                    result.points
+                )?;
+            }
+        }
+
+        if let Some(fastest_lap) = &self.fastest_lap {
+            write!(
+                md,
+                "\n**Fastest lap:** {} - {}",
+                markdown_escape(&fastest_lap.name),
+                lap_time(fastest_lap.lap_time_ms)
+            )?;
+
+            match fastest_lap.lap_num {
+                Some(lap_num) => writeln!(md, " (lap {})", lap_num)?,
+                None => writeln!(md)?,
+            }
+        }
+
+        let num_laps = self.lap_chart.iter().map(|row| row.positions.len()).max();
+
+        if let Some(num_laps) = num_laps {
+            writeln!(md, "\n## Lap chart\n")?;
+            write!(md, "| Driver | Grid |")?;
+
+            for lap in 1..num_laps {
+                write!(md, " {} |", lap)?;
+            }
+
+            writeln!(md, "\n|---|{}", "---:|".repeat(num_laps))?;
+
+            for row in &self.lap_chart {
+                write!(md, "| {} |", markdown_escape(&row.name))?;
+
+                for lap in 0..num_laps {
+                    match row.positions.get(lap) {
+                        Some(position) => write!(md, " {} |", position)?,
+                        None => write!(md, " |")?,
+                    }
+                }
+
+                writeln!(md)?;
+            }
+        }
+
+        if self.results.iter().any(|result| !result.stints.is_empty()) {
+            writeln!(md, "\n## Tyre stints\n")?;
+            writeln!(md, "| Driver | Stints |")?;
+            writeln!(md, "|---|---|")?;
+
+            for result in &self.results {
+                writeln!(
+                    md,
+                    "| {} | {} |",
+                    markdown_escape(&result.name),
+                    Self::stints_text(&result.stints)
+                )?;
+            }
+        }
+
+        writeln!(md, "\n## Penalties\n")?;
+
+        if self.penalties.is_empty() {
+            writeln!(md, "No penalties.")?;
+        } else {
+            writeln!(md, "| Lap | Driver | Penalty | Infringement | Time |")?;
+            writeln!(md, "|---:|---|---|---|---:|")?;
+
+            for penalty in &self.penalties {
+                writeln!(
+                    md,
+                    "| {} | {} | {} | {} | {}s |",
+                    penalty.lap_num,
+                    markdown_escape(&penalty.name),
+                    words(&format!("{:?}", penalty.penalty_type)),
+                    words(&format!("{:?}", penalty.infringement_type)),
+                    penalty.time
+                )?;
+            }
+        }
+
+        if !self.overtakes.is_empty() {
+            writeln!(md, "\n## Overtakes\n")?;
+            writeln!(md, "| Lap | Driver | Overtook |")?;
+            writeln!(md, "|---:|---|---|")?;
+
+            for overtake in &self.overtakes {
+                writeln!(
+                    md,
+                    "| {} | {} | {} |",
+                    overtake.lap_num,
+                    markdown_escape(&overtake.name),
+                    markdown_escape(&overtake.overtaken_name)
+                )?;
+            }
+        }
+
+        writeln!(md, "\n## Neutralisations\n")?;
+
+        if self.neutralisations.is_empty() {
+            writeln!(md, "No neutralisations.")?;
+        } else {
+            writeln!(md, "| Type | Laps | Duration |")?;
+            writeln!(md, "|---|---|---:|")?;
+
+            for [kind, laps, duration] in self.neutralisation_rows() {
+                writeln!(md, "| {} | {} | {} |", kind, laps, duration)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders the report as a standalone HTML document with inline styles.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        self.write_html(&mut html).expect("Writing to a String can't fail");
+        html
+    }
+
+    fn write_html(&self, html: &mut String) -> fmt::Result {
+        let title = html_escape(&self.title);
+
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html lang=\"en\">")?;
+        writeln!(html, "<head>")?;
+        writeln!(html, "<meta charset=\"utf-8\">")?;
+        writeln!(html, "<title>{}</title>", title)?;
+        writeln!(html, "<style>{}</style>", HTML_STYLE)?;
+        writeln!(html, "</head>")?;
+        writeln!(html, "<body>")?;
+        writeln!(html, "<h1>{}</h1>", title)?;
+        writeln!(html, "<h2>Results</h2>")?;
+
+        if self.results.is_empty() {
+            writeln!(html, "<p>No final classification.</p>")?;
+        } else {
+            writeln!(html, "<table>")?;
+            writeln!(
+                html,
+                "<tr><th>Pos</th><th>Driver</th><th>Grid</th><th>Laps</th><th>Time</th>\
+                 <th>Best lap</th><th>Stops</th><th>Points</th></tr>"
+            )?;
+
+            for result in &self.results {
+                writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+                     <td>{}</td><td>{}</td><td>{}</td></tr>",
+                    result.position,
+                    html_escape(&result.name),
+                    result.grid_position,
+                    result.num_laps,
+                    self.result_time(result),
+                    lap_time(result.best_lap_time_ms),
+                    result.num_pit_stops,
+                    result.points
+                )?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        if let Some(fastest_lap) = &self.fastest_lap {
+            write!(
+                html,
+                "<p><strong>Fastest lap:</strong> {} - {}",
+                html_escape(&fastest_lap.name),
+                lap_time(fastest_lap.lap_time_ms)
+            )?;
+
+            if let Some(lap_num) = fastest_lap.lap_num {
+                write!(html, " (lap {})", lap_num)?;
+            }
+
+            writeln!(html, "</p>")?;
+        }
+
+        let num_laps = self.lap_chart.iter().map(|row| row.positions.len()).max();
+
+        if let Some(num_laps) = num_laps {
+            writeln!(html, "<h2>Lap chart</h2>")?;
+            write!(
+                html,
+                "<table class=\"lap-chart\">\n<tr><th>Driver</th><th>Grid</th>"
+            )?;
+
+            for lap in 1..num_laps {
+                write!(html, "<th>{}</th>", lap)?;
+            }
+
+            writeln!(html, "</tr>")?;
+
+            for row in &self.lap_chart {
+                write!(html, "<tr><td>{}</td>", html_escape(&row.name))?;
+
+                for lap in 0..num_laps {
+                    match row.positions.get(lap) {
+                        Some(position) => write!(html, "<td>{}</td>", position)?,
+                        None => write!(html, "<td></td>")?,
+                    }
+                }
+
+                writeln!(html, "</tr>")?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        if self.results.iter().any(|result| !result.stints.is_empty()) {
+            let total_laps = self.results.iter().map(|r| r.num_laps).max().unwrap_or(0);
+
+            writeln!(html, "<h2>Tyre stints</h2>")?;
+            writeln!(html, "<table class=\"stints\">")?;
+
+            for result in &self.results {
+                write!(html, "<tr><td>{}</td><td>", html_escape(&result.name))?;
+
+                for stint in &result.stints {
+                    let laps = stint.end_lap.saturating_sub(stint.start_lap) + 1;
+                    let width = f32::from(laps) * 100.0 / f32::from(total_laps.max(1));
+                    let compound = compound_name(stint.compound);
+
+                    write!(
+                        html,
+                        "<span class=\"stint {}\" style=\"width:{:.1}%\" title=\"{} ({}-{})\">{}</span>",
+                        compound.to_ascii_lowercase().replace(' ', "-"),
+                        width,
+                        compound,
+                        stint.start_lap,
+                        stint.end_lap,
+                        &compound[..1]
+                    )?;
+                }
+
+                writeln!(html, "</td></tr>")?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        writeln!(html, "<h2>Penalties</h2>")?;
+
+        if self.penalties.is_empty() {
+            writeln!(html, "<p>No penalties.</p>")?;
+        } else {
+            writeln!(html, "<table>")?;
+            writeln!(
+                html,
+                "<tr><th>Lap</th><th>Driver</th><th>Penalty</th><th>Infringement</th>\
+                 <th>Time</th></tr>"
+            )?;
+
+            for penalty in &self.penalties {
+                writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}s</td></tr>",
+                    penalty.lap_num,
+                    html_escape(&penalty.name),
+                    words(&format!("{:?}", penalty.penalty_type)),
+                    words(&format!("{:?}", penalty.infringement_type)),
+                    penalty.time
+                )?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        if !self.overtakes.is_empty() {
+            writeln!(html, "<h2>Overtakes</h2>")?;
+            writeln!(html, "<table>")?;
+            writeln!(html, "<tr><th>Lap</th><th>Driver</th><th>Overtook</th></tr>")?;
+
+            for overtake in &self.overtakes {
+                writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    overtake.lap_num,
+                    html_escape(&overtake.name),
+                    html_escape(&overtake.overtaken_name)
+                )?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        writeln!(html, "<h2>Neutralisations</h2>")?;
+
+        if self.neutralisations.is_empty() {
+            writeln!(html, "<p>No neutralisations.</p>")?;
+        } else {
+            writeln!(html, "<table>")?;
+            writeln!(html, "<tr><th>Type</th><th>Laps</th><th>Duration</th></tr>")?;
+
+            for [kind, laps, duration] in self.neutralisation_rows() {
+                writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    kind, laps, duration
+                )?;
+            }
+
+            writeln!(html, "</table>")?;
+        }
+
+        writeln!(html, "</body>")?;
+        writeln!(html, "</html>")
+    }
+}
+
+const HTML_STYLE: &str = "\
+body{font-family:sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse;margin-bottom:1em}\
+th,td{border:1px solid #ccc;padding:.25em .5em;text-align:left}\
+.lap-chart td{text-align:right}\
+.stints td:last-child{width:40em}\
+.stint{display:inline-block;box-sizing:border-box;border:1px solid #fff;text-align:center}\
+.soft,.super-soft{background:#e10600;color:#fff}.medium{background:#ffd12e}\
+.hard{background:#f0f0f0}.inter{background:#43b02a;color:#fff}\
+.wet{background:#0067ad;color:#fff}";
+
+/// Escapes text taken from the game, such as online names, so Markdown viewers
+/// render it as is, without formatting, HTML or broken table rows.
+fn markdown_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\r' | '\n' => escaped.push(' '),
+            '\\' | '*' | '_' | '[' | ']' | '#' | '`' | '|' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    // Entities don't contain any of the characters escaped above.
+    html_escape(&escaped)
+}
+
+fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
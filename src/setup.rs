@@ -0,0 +1,465 @@
+use crate::constants::{TrackId, Weather};
+use crate::packets::car_setups::CarSetupData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::io::{self, ErrorKind};
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::path::Path;
+
+/// Adjustable value of a car setup.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetupField {
+    FrontWing,
+    RearWing,
+    OnThrottle,
+    OffThrottle,
+    FrontCamber,
+    RearCamber,
+    FrontToe,
+    RearToe,
+    FrontSuspension,
+    RearSuspension,
+    FrontAntiRollBar,
+    RearAntiRollBar,
+    FrontSuspensionHeight,
+    RearSuspensionHeight,
+    BrakePressure,
+    BrakeBias,
+    EngineBraking,
+    RearLeftTyrePressure,
+    RearRightTyrePressure,
+    FrontLeftTyrePressure,
+    FrontRightTyrePressure,
+    Ballast,
+    FuelLoad,
+    /// Front wing value after the next pit stop.
+    /// Only reported for the player's car from the 2024 format onwards.
+    NextFrontWing,
+}
+
+impl SetupField {
+    /// All fields of [`CarSetupData`], in their declaration order.
+    pub const ALL: [SetupField; 23] = [
+        SetupField::FrontWing,
+        SetupField::RearWing,
+        SetupField::OnThrottle,
+        SetupField::OffThrottle,
+        SetupField::FrontCamber,
+        SetupField::RearCamber,
+        SetupField::FrontToe,
+        SetupField::RearToe,
+        SetupField::FrontSuspension,
+        SetupField::RearSuspension,
+        SetupField::FrontAntiRollBar,
+        SetupField::RearAntiRollBar,
+        SetupField::FrontSuspensionHeight,
+        SetupField::RearSuspensionHeight,
+        SetupField::BrakePressure,
+        SetupField::BrakeBias,
+        SetupField::EngineBraking,
+        SetupField::RearLeftTyrePressure,
+        SetupField::RearRightTyrePressure,
+        SetupField::FrontLeftTyrePressure,
+        SetupField::FrontRightTyrePressure,
+        SetupField::Ballast,
+        SetupField::FuelLoad,
+    ];
+
+    /// Returns the field's value in a setup,
+    /// or [`None`] for [`SetupField::NextFrontWing`].
+    pub fn value(self, setup: &CarSetupData) -> Option<f32> {
+        let value = match self {
+            SetupField::FrontWing => f32::from(setup.front_wing),
+            SetupField::RearWing => f32::from(setup.rear_wing),
+            SetupField::OnThrottle => f32::from(setup.on_throttle),
+            SetupField::OffThrottle => f32::from(setup.off_throttle),
+            SetupField::FrontCamber => setup.front_camber,
+            SetupField::RearCamber => setup.rear_camber,
+            SetupField::FrontToe => setup.front_toe,
+            SetupField::RearToe => setup.rear_toe,
+            SetupField::FrontSuspension => f32::from(setup.front_suspension),
+            SetupField::RearSuspension => f32::from(setup.rear_suspension),
+            SetupField::FrontAntiRollBar => f32::from(setup.front_anti_roll_bar),
+            SetupField::RearAntiRollBar => f32::from(setup.rear_anti_roll_bar),
+            SetupField::FrontSuspensionHeight => f32::from(setup.front_suspension_height),
+            SetupField::RearSuspensionHeight => f32::from(setup.rear_suspension_height),
+            SetupField::BrakePressure => f32::from(setup.brake_pressure),
+            SetupField::BrakeBias => f32::from(setup.brake_bias),
+            SetupField::EngineBraking => f32::from(setup.engine_braking),
+            SetupField::RearLeftTyrePressure => setup.rear_left_tyre_pressure,
+            SetupField::RearRightTyrePressure => setup.rear_right_tyre_pressure,
+            SetupField::FrontLeftTyrePressure => setup.front_left_tyre_pressure,
+            SetupField::FrontRightTyrePressure => setup.front_right_tyre_pressure,
+            SetupField::Ballast => f32::from(setup.ballast),
+            SetupField::FuelLoad => setup.fuel_load,
+            SetupField::NextFrontWing => return None,
+        };
+
+        Some(value)
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            SetupField::OnThrottle
+            | SetupField::OffThrottle
+            | SetupField::BrakePressure
+            | SetupField::BrakeBias
+            | SetupField::EngineBraking => "%",
+            SetupField::FrontCamber
+            | SetupField::RearCamber
+            | SetupField::FrontToe
+            | SetupField::RearToe => "°",
+            SetupField::RearLeftTyrePressure
+            | SetupField::RearRightTyrePressure
+            | SetupField::FrontLeftTyrePressure
+            | SetupField::FrontRightTyrePressure => " psi",
+            SetupField::FuelLoad => " kg",
+            _ => "",
+        }
+    }
+
+    fn precision(self) -> usize {
+        match self {
+            SetupField::FrontCamber
+            | SetupField::RearCamber
+            | SetupField::FrontToe
+            | SetupField::RearToe => 2,
+            SetupField::RearLeftTyrePressure
+            | SetupField::RearRightTyrePressure
+            | SetupField::FrontLeftTyrePressure
+            | SetupField::FrontRightTyrePressure
+            | SetupField::FuelLoad => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for SetupField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SetupField::FrontWing => "Front wing",
+            SetupField::RearWing => "Rear wing",
+            SetupField::OnThrottle => "Differential on throttle",
+            SetupField::OffThrottle => "Differential off throttle",
+            SetupField::FrontCamber => "Front camber",
+            SetupField::RearCamber => "Rear camber",
+            SetupField::FrontToe => "Front toe",
+            SetupField::RearToe => "Rear toe",
+            SetupField::FrontSuspension => "Front suspension",
+            SetupField::RearSuspension => "Rear suspension",
+            SetupField::FrontAntiRollBar => "Front anti-roll bar",
+            SetupField::RearAntiRollBar => "Rear anti-roll bar",
+            SetupField::FrontSuspensionHeight => "Front ride height",
+            SetupField::RearSuspensionHeight => "Rear ride height",
+            SetupField::BrakePressure => "Brake pressure",
+            SetupField::BrakeBias => "Brake bias",
+            SetupField::EngineBraking => "Engine braking",
+            SetupField::RearLeftTyrePressure => "Rear left tyre pressure",
+            SetupField::RearRightTyrePressure => "Rear right tyre pressure",
+            SetupField::FrontLeftTyrePressure => "Front left tyre pressure",
+            SetupField::FrontRightTyrePressure => "Front right tyre pressure",
+            SetupField::Ballast => "Ballast",
+            SetupField::FuelLoad => "Fuel load",
+            SetupField::NextFrontWing => "Front wing after next pit stop",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Difference in a single setup field.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetupChange {
+    /// Changed field.
+    pub field: SetupField,
+    /// Previous value.
+    pub from: f32,
+    /// New value.
+    pub to: f32,
+}
+
+impl fmt::Display for SetupChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = self.field.precision();
+        let unit = self.field.unit();
+
+        write!(
+            f,
+            "{}: {:.*}{} -> {:.*}{} ({:+.*})",
+            self.field,
+            precision,
+            self.from,
+            unit,
+            precision,
+            self.to,
+            unit,
+            precision,
+            self.to - self.from
+        )
+    }
+}
+
+/// Compares two setups field by field and returns the differences,
+/// in the order the fields are declared in [`CarSetupData`].
+pub fn diff(from: &CarSetupData, to: &CarSetupData) -> Vec<SetupChange> {
+    SetupField::ALL
+        .into_iter()
+        .filter_map(|field| {
+            let (from, to) = (field.value(from)?, field.value(to)?);
+            (from != to).then_some(SetupChange { field, from, to })
+        })
+        .collect()
+}
+
+/// A named car setup with the conditions it was used in,
+/// meant for saving to and loading from files.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SavedSetup {
+    /// Name of the setup.
+    pub name: String,
+    /// Packet format the setup was captured with.
+    pub packet_format: u16,
+    /// Track the setup was used on.
+    pub track_id: Option<TrackId>,
+    /// Weather at the time the setup was captured.
+    pub weather: Option<Weather>,
+    /// Track temperature in degrees Celsius.
+    pub track_temperature: Option<i8>,
+    /// Air temperature in degrees Celsius.
+    pub air_temperature: Option<i8>,
+    /// Front wing value after the next pit stop. Available from the 2024 format onwards.
+    pub next_front_wing_value: Option<f32>,
+    /// Setup values.
+    pub setup: CarSetupData,
+}
+
+impl SavedSetup {
+    /// Creates a named setup with no track or weather metadata.
+    pub fn new(name: &str, packet_format: u16, setup: CarSetupData) -> Self {
+        Self {
+            name: name.to_owned(),
+            packet_format,
+            track_id: None,
+            weather: None,
+            track_temperature: None,
+            air_temperature: None,
+            next_front_wing_value: None,
+            setup,
+        }
+    }
+
+    /// Compares this setup with another one. See [`diff`].
+    pub fn diff(&self, other: &SavedSetup) -> Vec<SetupChange> {
+        diff(&self.setup, &other.setup)
+    }
+
+    /// Serializes the setup into pretty-printed JSON.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserializes a setup from JSON.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the setup into TOML.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Deserializes a setup from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Saves the setup to a file, picking the format by its extension
+    /// (`.json` or `.toml`, depending on the enabled features).
+    #[cfg(any(feature = "json", feature = "toml"))]
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => self.to_json().map_err(invalid_data)?,
+            #[cfg(feature = "toml")]
+            Some("toml") => self.to_toml().map_err(invalid_data)?,
+            _ => return Err(unsupported_extension(path)),
+        };
+
+        std::fs::write(path, contents)
+    }
+
+    /// Loads a setup from a file, picking the format by its extension
+    /// (`.json` or `.toml`, depending on the enabled features).
+    #[cfg(any(feature = "json", feature = "toml"))]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => Self::from_json(&contents).map_err(invalid_data),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents).map_err(invalid_data),
+            _ => Err(unsupported_extension(path)),
+        }
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn invalid_data(error: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn unsupported_extension(path: &Path) -> io::Error {
+    io::Error::new(
+        ErrorKind::InvalidInput,
+        format!("Unsupported setup file extension: {}", path.display()),
+    )
+}
+
+/// Setup change detected by [`SetupTracker::update`].
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetupChangeEvent {
+    /// Session timestamp of the packet the change was detected in.
+    pub session_time: f32,
+    /// Lap the car was on, if a lap data packet has been received.
+    pub lap_num: Option<u8>,
+    /// Changed fields.
+    pub changes: Vec<SetupChange>,
+}
+
+impl fmt::Display for SetupChangeEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lap_num {
+            Some(lap_num) => write!(f, "Setup changed on lap {}", lap_num)?,
+            None => write!(f, "Setup changed at {:.3} s", self.session_time)?,
+        }
+
+        for change in &self.changes {
+            write!(f, "\n  {}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Tracks a car's setup over the course of a session, detecting changes
+/// (including front wing adjustments scheduled for the next pit stop)
+/// and capturing it along with track and weather metadata.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct SetupTracker {
+    vehicle_index: Option<usize>,
+    packet_format: u16,
+    setup: Option<CarSetupData>,
+    next_front_wing_value: Option<f32>,
+    lap_num: Option<u8>,
+    track_id: Option<TrackId>,
+    weather: Option<Weather>,
+    track_temperature: Option<i8>,
+    air_temperature: Option<i8>,
+}
+
+impl SetupTracker {
+    /// Creates a tracker of the player's car.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracks the given car instead of the player's one.
+    /// Note that the game only reveals setups of the player's car and AI cars.
+    pub fn with_vehicle_index(mut self, vehicle_index: usize) -> Self {
+        self.vehicle_index = Some(vehicle_index);
+        self
+    }
+
+    /// Returns the most recently received setup.
+    pub fn setup(&self) -> Option<&CarSetupData> {
+        self.setup.as_ref()
+    }
+
+    /// Returns the front wing value scheduled for the next pit stop,
+    /// if it differs from the current one.
+    pub fn pending_front_wing(&self) -> Option<f32> {
+        let current = f32::from(self.setup.as_ref()?.front_wing);
+        self.next_front_wing_value.filter(|&next| next != current)
+    }
+
+    /// Processes a packet. Returns an event if the setup has changed
+    /// since the previous car setups packet.
+    pub fn update(&mut self, packet: &F1Packet) -> Option<SetupChangeEvent> {
+        let vehicle_index = self.vehicle_index.unwrap_or(packet.header.player_car_index);
+
+        if let Some(session) = &packet.session {
+            self.track_id = Some(session.track_id);
+            self.weather = Some(session.weather);
+            self.track_temperature = Some(session.track_temperature);
+            self.air_temperature = Some(session.air_temperature);
+        } else if let Some(lap_data) =
+            packet.laps.as_ref().and_then(|laps| laps.data.get(vehicle_index))
+        {
+            self.lap_num = Some(lap_data.current_lap_num);
+        } else if let Some(car_setups) = &packet.car_setups {
+            let setup = *car_setups.data.get(vehicle_index)?;
+            let next_front_wing_value = (packet.header.packet_format >= 2024
+                && vehicle_index == packet.header.player_car_index)
+                .then_some(car_setups.next_front_wing_value);
+
+            self.packet_format = packet.header.packet_format;
+
+            let mut changes = match self.setup.replace(setup) {
+                Some(previous) => diff(&previous, &setup),
+                None => Vec::new(),
+            };
+
+            match (self.next_front_wing_value, next_front_wing_value) {
+                (Some(from), Some(to)) if from != to => changes.push(SetupChange {
+                    field: SetupField::NextFrontWing,
+                    from,
+                    to,
+                }),
+                _ => (),
+            }
+
+            self.next_front_wing_value = next_front_wing_value;
+
+            if !changes.is_empty() {
+                return Some(SetupChangeEvent {
+                    session_time: packet.header.session_time,
+                    lap_num: self.lap_num,
+                    changes,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Captures the current setup along with the track and weather metadata.
+    /// Returns [`None`] if no car setups packet has been received yet.
+    pub fn snapshot(&self, name: &str) -> Option<SavedSetup> {
+        Some(SavedSetup {
+            track_id: self.track_id,
+            weather: self.weather,
+            track_temperature: self.track_temperature,
+            air_temperature: self.air_temperature,
+            next_front_wing_value: self.next_front_wing_value,
+            ..SavedSetup::new(name, self.packet_format, *self.setup.as_ref()?)
+        })
+    }
+}
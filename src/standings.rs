@@ -0,0 +1,434 @@
+use crate::constants::ResultStatus;
+use crate::packets::{F1PacketFinalClassification, F1PacketParticipants};
+use crate::weekend::{DriverKey, SessionKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Rules for awarding championship points.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointsSystem {
+    /// Points for each race finishing position, starting with P1.
+    pub race: Vec<u32>,
+    /// Points for each sprint finishing position, starting with P1.
+    pub sprint: Vec<u32>,
+    /// Bonus points for the fastest lap of a race.
+    pub fastest_lap: u32,
+    /// Lowest finishing position still eligible for the fastest lap bonus.
+    pub fastest_lap_max_position: u8,
+    /// Whether to use the points awarded by the game instead of the tables above.
+    /// The fastest lap is then credited to whichever classified car set it,
+    /// regardless of the fastest lap bonus settings.
+    pub use_game_points: bool,
+}
+
+impl Default for PointsSystem {
+    fn default() -> Self {
+        Self::f1_2024()
+    }
+}
+
+impl PointsSystem {
+    /// Points system of the 2024 Formula 1 season: 25-18-15-12-10-8-6-4-2-1
+    /// for races, 8 down to 1 for sprints, and a point for the fastest lap
+    /// if set by a driver finishing in the top 10.
+    pub fn f1_2024() -> Self {
+        Self {
+            race: vec![25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+            sprint: vec![8, 7, 6, 5, 4, 3, 2, 1],
+            fastest_lap: 1,
+            fastest_lap_max_position: 10,
+            use_game_points: false,
+        }
+    }
+
+    /// Uses the points awarded by the game, as reported in
+    /// [`FinalClassificationData::points`](crate::packets::final_classification::FinalClassificationData::points).
+    pub fn game() -> Self {
+        Self { use_game_points: true, ..Self::f1_2024() }
+    }
+
+    /// Sets the race points table.
+    pub fn with_race_points(mut self, points: impl IntoIterator<Item = u32>) -> Self {
+        self.race = points.into_iter().collect();
+        self
+    }
+
+    /// Sets the sprint points table.
+    pub fn with_sprint_points(mut self, points: impl IntoIterator<Item = u32>) -> Self {
+        self.sprint = points.into_iter().collect();
+        self
+    }
+
+    /// Sets the fastest lap bonus and the lowest position eligible for it.
+    /// Pass 0 points to disable the bonus.
+    pub fn with_fastest_lap(mut self, points: u32, max_position: u8) -> Self {
+        self.fastest_lap = points;
+        self.fastest_lap_max_position = max_position;
+        self
+    }
+
+    fn position_points(&self, kind: SessionKind, position: u8) -> u32 {
+        let table = match kind {
+            SessionKind::Sprint => &self.sprint,
+            _ => &self.race,
+        };
+
+        usize::from(position)
+            .checked_sub(1)
+            .and_then(|index| table.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Points scored by a car in a single session, returned by [`Standings::add_session`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionScore {
+    /// Driver's display name.
+    pub name: String,
+    /// Team's ID.
+    /// See [`team_id`](mod@crate::constants::team_id) for possible values.
+    pub team_id: Option<u8>,
+    /// Finishing position.
+    pub position: u8,
+    /// Points scored, including the fastest lap bonus.
+    pub points: u32,
+    /// Whether the driver has set the fastest lap of a race and was eligible
+    /// for the bonus. With [`PointsSystem::use_game_points`], any classified
+    /// driver who set it.
+    pub fastest_lap: bool,
+}
+
+/// A driver's championship standing.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DriverStanding {
+    /// Championship position.
+    pub position: usize,
+    /// Driver's display name.
+    pub name: String,
+    /// ID of the team the driver has most recently driven for.
+    pub team_id: Option<u8>,
+    /// Total number of points.
+    pub points: u32,
+    /// Points scored in sprints.
+    pub sprint_points: u32,
+    /// Number of races started.
+    pub starts: u32,
+    /// Number of race wins.
+    pub wins: u32,
+    /// Number of race podiums.
+    pub podiums: u32,
+    /// Number of fastest lap bonuses scored. With
+    /// [`PointsSystem::use_game_points`], the number of fastest laps set.
+    pub fastest_laps: u32,
+    /// Number of race finishes in each position, starting with P1.
+    /// Used to break ties by count-back.
+    pub finishes: Vec<u32>,
+}
+
+/// A team's championship standing.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamStanding {
+    /// Championship position.
+    pub position: usize,
+    /// Team's ID.
+    /// See [`team_id`](mod@crate::constants::team_id) for possible values.
+    pub team_id: u8,
+    /// Total number of points.
+    pub points: u32,
+    /// Number of race wins.
+    pub wins: u32,
+    /// Number of race finishes in each position, starting with P1.
+    /// Used to break ties by count-back.
+    pub finishes: Vec<u32>,
+}
+
+/// Driver and team standings returned by [`Standings::table`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandingsTable {
+    /// Driver standings, ordered by championship position.
+    pub drivers: Vec<DriverStanding>,
+    /// Team standings, ordered by championship position.
+    pub teams: Vec<TeamStanding>,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct Tally {
+    team_id: Option<u8>,
+    points: u32,
+    sprint_points: u32,
+    starts: u32,
+    fastest_laps: u32,
+    finishes: Vec<u32>,
+}
+
+impl Tally {
+    fn add_finish(&mut self, position: u8) {
+        let index = usize::from(position - 1);
+
+        if self.finishes.len() <= index {
+            self.finishes.resize(index + 1, 0);
+        }
+
+        self.finishes[index] += 1;
+    }
+
+    fn count(&self, position: usize) -> u32 {
+        self.finishes.get(position - 1).copied().unwrap_or(0)
+    }
+}
+
+/// Compares points first and then finishes by count-back:
+/// most wins, then most second places, and so on.
+fn compare_tallies(a: &Tally, b: &Tally) -> Ordering {
+    let positions = a.finishes.len().max(b.finishes.len());
+
+    b.points.cmp(&a.points).then_with(|| {
+        (1..=positions)
+            .map(|position| b.count(position).cmp(&a.count(position)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+/// Championship standings engine that accumulates final classifications
+/// of many races and sprints.
+///
+/// Human drivers are keyed by their online names, so they may join each race
+/// in a different lobby slot, and AI drivers by their driver and network IDs.
+/// Humans whose online names are hidden fall back to their network IDs,
+/// which only identify them as long as they keep the same slot.
+/// The most recent [display name](crate::packets::participants::ParticipantsData::display_name)
+/// of a driver is used in the standings.
+/// Ties are broken by count-back of race (not sprint) finishing positions.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Standings {
+    points_system: PointsSystem,
+    drivers: BTreeMap<DriverKey, (String, Tally)>,
+    teams: BTreeMap<u8, Tally>,
+}
+
+impl Standings {
+    /// Creates empty standings using the given points system.
+    pub fn new(points_system: PointsSystem) -> Self {
+        Self { points_system, ..Self::default() }
+    }
+
+    /// Returns the points system in use.
+    pub fn points_system(&self) -> &PointsSystem {
+        &self.points_system
+    }
+
+    /// Adds a session's final classification to the standings.
+    /// Sessions other than [`SessionKind::Race`] and [`SessionKind::Sprint`]
+    /// are ignored. Returns the points each car has scored.
+    pub fn add_session(
+        &mut self,
+        kind: SessionKind,
+        packet_format: u16,
+        participants: &F1PacketParticipants,
+        classification: &F1PacketFinalClassification,
+    ) -> Vec<SessionScore> {
+        if !matches!(kind, SessionKind::Race | SessionKind::Sprint) {
+            return Vec::new();
+        }
+
+        let system = &self.points_system;
+        let fastest_lap = classification
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, data)| data.best_lap_time_ms > 0)
+            .min_by_key(|(_, data)| data.best_lap_time_ms)
+            .map(|(vehicle_index, _)| vehicle_index)
+            .filter(|_| kind == SessionKind::Race);
+        let mut scores = Vec::new();
+
+        for (vehicle_index, data) in classification.data.iter().enumerate() {
+            if data.position == 0 {
+                continue;
+            }
+
+            let participant = participants.data.get(vehicle_index);
+            let name = participant.map_or_else(
+                || format!("Car {}", vehicle_index),
+                |participant| participant.display_name(packet_format).into_owned(),
+            );
+            let team_id = participant.map(|participant| participant.team_id);
+            let classified = data.result_status == ResultStatus::Finished;
+            // The game's own points already include any bonus it has awarded,
+            // so the fastest lap isn't subject to the bonus rules in that mode.
+            let has_fastest_lap = fastest_lap == Some(vehicle_index)
+                && classified
+                && (system.use_game_points
+                    || data.position <= system.fastest_lap_max_position);
+            let points = if system.use_game_points {
+                u32::from(data.points)
+            } else if classified {
+                system.position_points(kind, data.position)
+                    + if has_fastest_lap { system.fastest_lap } else { 0 }
+            } else {
+                0
+            };
+
+            let (driver_name, driver) = self
+                .drivers
+                .entry(DriverKey::new(participant, vehicle_index))
+                .or_default();
+
+            driver_name.clone_from(&name);
+
+            let mut tallies = vec![driver];
+
+            tallies[0].team_id = team_id.or(tallies[0].team_id);
+            tallies[0].starts += u32::from(kind == SessionKind::Race);
+            tallies[0].fastest_laps += u32::from(
+                has_fastest_lap && (system.use_game_points || system.fastest_lap > 0),
+            );
+
+            if let Some(team_id) = team_id {
+                tallies.push(self.teams.entry(team_id).or_default());
+            }
+
+            for tally in tallies {
+                tally.points += points;
+
+                match kind {
+                    SessionKind::Sprint => tally.sprint_points += points,
+                    _ if classified => tally.add_finish(data.position),
+                    _ => (),
+                }
+            }
+
+            scores.push(SessionScore {
+                name,
+                team_id,
+                position: data.position,
+                points,
+                fastest_lap: has_fastest_lap,
+            });
+        }
+
+        scores.sort_by_key(|score| score.position);
+        scores
+    }
+
+    /// Returns driver standings, ordered by championship position.
+    pub fn drivers(&self) -> Vec<DriverStanding> {
+        let mut drivers: Vec<&(String, Tally)> = self.drivers.values().collect();
+
+        drivers.sort_by(|(a_name, a), (b_name, b)| {
+            compare_tallies(a, b).then_with(|| a_name.cmp(b_name))
+        });
+
+        drivers
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, tally))| DriverStanding {
+                position: index + 1,
+                name: name.clone(),
+                team_id: tally.team_id,
+                points: tally.points,
+                sprint_points: tally.sprint_points,
+                starts: tally.starts,
+                wins: tally.count(1),
+                podiums: (1..=3).map(|position| tally.count(position)).sum(),
+                fastest_laps: tally.fastest_laps,
+                finishes: tally.finishes.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns team standings, ordered by championship position.
+    pub fn teams(&self) -> Vec<TeamStanding> {
+        let mut teams: Vec<(&u8, &Tally)> = self.teams.iter().collect();
+
+        teams.sort_by(|(a_id, a), (b_id, b)| {
+            compare_tallies(a, b).then_with(|| a_id.cmp(b_id))
+        });
+
+        teams
+            .into_iter()
+            .enumerate()
+            .map(|(index, (&team_id, tally))| TeamStanding {
+                position: index + 1,
+                team_id,
+                points: tally.points,
+                wins: tally.count(1),
+                finishes: tally.finishes.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns both driver and team standings.
+    pub fn table(&self) -> StandingsTable {
+        StandingsTable { drivers: self.drivers(), teams: self.teams() }
+    }
+
+    /// Serializes driver and team standings into pretty-printed JSON.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.table())
+    }
+
+    /// Writes driver standings as CSV with a header row.
+    pub fn write_drivers_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(
+            writer,
+            "position,name,team_id,points,sprint_points,starts,wins,podiums,fastest_laps"
+        )?;
+
+        for driver in self.drivers() {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{}",
+                driver.position,
+                csv_field(&driver.name),
+                driver.team_id.map_or(String::new(), |team_id| team_id.to_string()),
+                driver.points,
+                driver.sprint_points,
+                driver.starts,
+                driver.wins,
+                driver.podiums,
+                driver.fastest_laps
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes team standings as CSV with a header row.
+    pub fn write_teams_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "position,team_id,points,wins")?;
+
+        for team in self.teams() {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                team.position, team.team_id, team.points, team.wins
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
@@ -0,0 +1,504 @@
+use crate::constants::{VisualTyreCompound, MAX_NUM_CARS};
+use crate::packets::session_history::TyreStintHistoryData;
+use crate::packets::tyre_sets::TyreSetData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const DEFAULT_PIT_LOSS: f32 = 22.0;
+const DEFAULT_WEAR_LIMIT: f32 = 70.0;
+const DEFAULT_WEAR_TIME_COST: f32 = 0.03;
+
+/// Observed tyre wear rate of a compound.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DegradationRate {
+    /// Visual compound this rate applies to.
+    pub visual_tyre_compound: VisualTyreCompound,
+    /// Number of laps the rate has been averaged over.
+    pub laps_sampled: u32,
+    /// Average wear (percentage) of the most worn tyre per lap.
+    pub wear_per_lap: f32,
+}
+
+/// Remaining life projection of a single tyre set.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TyreSetProjection {
+    /// Index of the set in [`F1PacketTyreSets::data`](field@crate::packets::F1PacketTyreSets::data).
+    pub set_index: usize,
+    /// Raw data of the set.
+    pub set: TyreSetData,
+    /// Number of laps left according to the game.
+    pub game_laps_left: u8,
+    /// Number of laps left until the wear limit is reached,
+    /// based on the observed degradation rate of the set's compound.
+    /// Set to [`None`] if the compound hasn't been sampled yet.
+    pub projected_laps_left: Option<f32>,
+}
+
+/// Single stint of a [`StrategyPlan`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlannedStint {
+    /// Index of the tyre set to use.
+    pub set_index: usize,
+    /// Visual compound of the tyre set.
+    pub visual_tyre_compound: VisualTyreCompound,
+    /// First lap of the stint.
+    pub start_lap: u8,
+    /// Last lap of the stint (the pit stop happens at the end of it).
+    pub end_lap: u8,
+}
+
+/// Suggested race strategy from the current lap to the chequered flag.
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StrategyPlan {
+    /// Number of pit stops.
+    pub num_pit_stops: usize,
+    /// Stints to run, starting with the one on the currently fitted set.
+    pub stints: Vec<PlannedStint>,
+    /// Estimated time lost to pit stops, slower compounds
+    /// and tyre wear in seconds. Only meant for comparing plans.
+    pub estimated_time_loss: f32,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+struct CarTyreState {
+    current_lap_num: u8,
+    num_pit_stops: u8,
+    visual_tyre_compound: Option<VisualTyreCompound>,
+    tyres_wear: Option<[f32; 4]>,
+    lap_start_wear: Option<[f32; 4]>,
+    /// Whether the start of the current lap hasn't been observed
+    /// or the lap has been started on another compound,
+    /// so its wear doesn't cover a full lap on the fitted one.
+    partial_lap: bool,
+    tyre_sets: Option<(Vec<TyreSetData>, usize)>,
+    stints: Vec<TyreStintHistoryData>,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+struct WearStats {
+    laps: u32,
+    total_wear: f32,
+}
+
+/// Tyre strategy model built from tyre sets, stint history
+/// and tyre wear observed over the course of a session.
+///
+/// Degradation rates are worked out by comparing
+/// [`CarDamageData::tyres_wear`](field@crate::packets::car_damage::CarDamageData::tyres_wear)
+/// at the start and end of every lap where the car didn't change tyres.
+/// Laps whose start hasn't been observed, such as the one the model
+/// got attached in or one rewound by a flashback, aren't sampled.
+#[derive(PartialEq, Clone, Debug)]
+pub struct TyreStrategy {
+    pit_loss: f32,
+    wear_limit: f32,
+    wear_time_cost: f32,
+    total_laps: Option<u8>,
+    pit_stop_window: Option<(u8, u8)>,
+    cars: Vec<CarTyreState>,
+    degradation: BTreeMap<VisualTyreCompound, WearStats>,
+}
+
+impl Default for TyreStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TyreStrategy {
+    /// Creates a model that assumes a 22 second pit loss,
+    /// a 70% wear limit and 0.03 seconds lost per lap for each percent of wear.
+    pub fn new() -> Self {
+        Self {
+            pit_loss: DEFAULT_PIT_LOSS,
+            wear_limit: DEFAULT_WEAR_LIMIT,
+            wear_time_cost: DEFAULT_WEAR_TIME_COST,
+            total_laps: None,
+            pit_stop_window: None,
+            cars: vec![CarTyreState::default(); MAX_NUM_CARS],
+            degradation: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the time lost by making a pit stop in seconds.
+    pub fn with_pit_loss(mut self, seconds: f32) -> Self {
+        self.pit_loss = seconds;
+        self
+    }
+
+    /// Sets the tyre wear percentage a set is considered worn out at.
+    pub fn with_wear_limit(mut self, percentage: f32) -> Self {
+        self.wear_limit = percentage;
+        self
+    }
+
+    /// Sets the time lost per lap for each percent of tyre wear in seconds.
+    pub fn with_wear_time_cost(mut self, seconds: f32) -> Self {
+        self.wear_time_cost = seconds;
+        self
+    }
+
+    /// Feeds a parsed packet into the model. Session, lap data, car status,
+    /// car damage, session history and tyre sets packets are taken into account.
+    pub fn update(&mut self, packet: &F1Packet) {
+        if let Some(session) = &packet.session {
+            self.total_laps = Some(session.total_laps).filter(|&laps| laps > 0);
+            self.pit_stop_window = Some((
+                session.pit_stop_window_ideal_lap,
+                session.pit_stop_window_latest_lap,
+            ))
+            .filter(|&(ideal, latest)| ideal > 0 && latest >= ideal);
+        } else if let Some(laps) = &packet.laps {
+            for (vehicle_index, lap_data) in laps.data.iter().enumerate() {
+                self.on_lap_data(
+                    vehicle_index,
+                    lap_data.current_lap_num,
+                    lap_data.num_pit_stops,
+                );
+            }
+        } else if let Some(car_status) = &packet.car_status {
+            for (car, status) in self.cars.iter_mut().zip(&car_status.data) {
+                if car.visual_tyre_compound != Some(status.visual_tyre_compound) {
+                    car.partial_lap |= car.visual_tyre_compound.is_some();
+                    car.visual_tyre_compound = Some(status.visual_tyre_compound);
+                    car.lap_start_wear = None;
+                }
+            }
+        } else if let Some(car_damage) = &packet.car_damage {
+            for (car, damage) in self.cars.iter_mut().zip(&car_damage.data) {
+                car.tyres_wear = Some(damage.tyres_wear);
+                car.lap_start_wear.get_or_insert(damage.tyres_wear);
+            }
+        } else if let Some(session_history) = &packet.session_history {
+            if let Some(car) = self.cars.get_mut(session_history.vehicle_index) {
+                car.stints.clone_from(&session_history.tyre_stint_history_data);
+            }
+        } else if let Some(tyre_sets) = &packet.tyre_sets {
+            if let Some(car) = self.cars.get_mut(tyre_sets.vehicle_index) {
+                car.tyre_sets = Some((tyre_sets.data.clone(), tyre_sets.fitted_index));
+            }
+        }
+    }
+
+    /// Returns observed degradation rates of all sampled compounds.
+    pub fn degradation_rates(&self) -> Vec<DegradationRate> {
+        self.degradation
+            .iter()
+            .map(|(&visual_tyre_compound, stats)| DegradationRate {
+                visual_tyre_compound,
+                laps_sampled: stats.laps,
+                wear_per_lap: stats.total_wear / stats.laps as f32,
+            })
+            .collect()
+    }
+
+    /// Returns the observed degradation rate of a compound, if it's been sampled.
+    pub fn degradation_rate(&self, compound: VisualTyreCompound) -> Option<f32> {
+        self.degradation.get(&compound).map(|stats| stats.total_wear / stats.laps as f32)
+    }
+
+    /// Returns the latest tyre stint history of a car.
+    pub fn stints(&self, vehicle_index: usize) -> &[TyreStintHistoryData] {
+        self.cars.get(vehicle_index).map_or(&[], |car| &car.stints)
+    }
+
+    /// Returns remaining life projections of all of a car's tyre sets.
+    /// The list is empty if no tyre sets packet has been received for the car.
+    pub fn projections(&self, vehicle_index: usize) -> Vec<TyreSetProjection> {
+        let Some((sets, _)) =
+            self.cars.get(vehicle_index).and_then(|car| car.tyre_sets.as_ref())
+        else {
+            return Vec::new();
+        };
+
+        sets.iter()
+            .enumerate()
+            .map(|(set_index, &set)| TyreSetProjection {
+                set_index,
+                set,
+                game_laps_left: set.life_span,
+                projected_laps_left: self
+                    .degradation_rate(set.visual_tyre_compound)
+                    .filter(|&rate| rate > 0.0)
+                    .map(|rate| {
+                        ((self.wear_limit - f32::from(set.wear)) / rate).max(0.0)
+                    }),
+            })
+            .collect()
+    }
+
+    /// Suggests the best one-stop and two-stop plans for a car,
+    /// ordered by estimated time loss. The first stop is kept within
+    /// the pit stop window if the session packet has provided one.
+    ///
+    /// In dry conditions, plans have to include at least two different
+    /// dry compounds, unless the car's stint history already does.
+    pub fn suggest_plans(&self, vehicle_index: usize) -> Vec<StrategyPlan> {
+        let Some(car) = self.cars.get(vehicle_index) else {
+            return Vec::new();
+        };
+        let (Some(total_laps), Some((_, fitted_index))) =
+            (self.total_laps, &car.tyre_sets)
+        else {
+            return Vec::new();
+        };
+
+        let current_lap = car.current_lap_num.max(1);
+
+        if current_lap >= total_laps {
+            return Vec::new();
+        }
+
+        let projections = self.projections(vehicle_index);
+        let Some(fitted) = projections.get(*fitted_index).copied() else {
+            return Vec::new();
+        };
+        let mut candidates: Vec<TyreSetProjection> = Vec::new();
+
+        // Sets with identical properties lead to identical plans,
+        // so keeping two of each is enough to cover every combination.
+        for projection in projections {
+            let duplicates = candidates
+                .iter()
+                .filter(|c| {
+                    c.set.visual_tyre_compound == projection.set.visual_tyre_compound
+                        && c.set.wear == projection.set.wear
+                        && c.set.life_span == projection.set.life_span
+                        && c.set.lap_delta_time == projection.set.lap_delta_time
+                })
+                .count();
+
+            if projection.set.available
+                && projection.set_index != fitted.set_index
+                && duplicates < 2
+            {
+                candidates.push(projection);
+            }
+        }
+
+        let (first_stop_min, first_stop_max) = self
+            .pit_stop_window
+            .map_or((current_lap, total_laps - 1), |(ideal, latest)| {
+                (ideal.max(current_lap), latest.min(total_laps - 1))
+            });
+
+        let mut plans: Vec<StrategyPlan> = [
+            self.best_one_stop(
+                car,
+                &fitted,
+                &candidates,
+                current_lap,
+                total_laps,
+                (first_stop_min, first_stop_max),
+            ),
+            self.best_two_stop(
+                car,
+                &fitted,
+                &candidates,
+                current_lap,
+                total_laps,
+                (first_stop_min, first_stop_max),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        plans.sort_by(|a, b| a.estimated_time_loss.total_cmp(&b.estimated_time_loss));
+        plans
+    }
+
+    fn on_lap_data(
+        &mut self,
+        vehicle_index: usize,
+        current_lap_num: u8,
+        num_pit_stops: u8,
+    ) {
+        let Some(car) = self.cars.get_mut(vehicle_index) else {
+            return;
+        };
+
+        if current_lap_num > car.current_lap_num && car.current_lap_num > 0 {
+            let no_stop = num_pit_stops == car.num_pit_stops;
+
+            if let (true, false, Some(compound), Some(start), Some(end)) = (
+                no_stop,
+                car.partial_lap,
+                car.visual_tyre_compound,
+                car.lap_start_wear,
+                car.tyres_wear,
+            ) {
+                let wear = (0..4).map(|i| end[i] - start[i]).fold(f32::MIN, f32::max);
+
+                if wear >= 0.0 {
+                    let stats = self.degradation.entry(compound).or_default();
+                    stats.laps += 1;
+                    stats.total_wear += wear;
+                }
+            }
+
+            car.lap_start_wear = car.tyres_wear;
+            car.partial_lap = false;
+        } else if current_lap_num != car.current_lap_num {
+            // Either the first observation of the car or a flashback,
+            // both of which land somewhere in the middle of a lap.
+            car.lap_start_wear = car.tyres_wear;
+            car.partial_lap = true;
+        }
+
+        car.current_lap_num = current_lap_num;
+        car.num_pit_stops = num_pit_stops;
+    }
+
+    fn life(&self, projection: &TyreSetProjection) -> f32 {
+        projection
+            .projected_laps_left
+            .unwrap_or_else(|| f32::from(projection.game_laps_left))
+    }
+
+    /// Estimated time lost by running a set for a number of laps.
+    fn stint_loss(&self, projection: &TyreSetProjection, laps: u8) -> f32 {
+        let rate =
+            self.degradation_rate(projection.set.visual_tyre_compound).unwrap_or(0.0);
+        let start_wear = f32::from(projection.set.wear);
+        let laps = f32::from(laps);
+        let average_wear = start_wear + rate * (laps - 1.0).max(0.0) / 2.0;
+
+        laps * (f32::from(projection.set.lap_delta_time) / 1000.0
+            + average_wear * self.wear_time_cost)
+    }
+
+    fn plan(
+        &self,
+        car: &CarTyreState,
+        stints: &[(&TyreSetProjection, u8, u8)],
+    ) -> Option<StrategyPlan> {
+        let mut estimated_time_loss = self.pit_loss * (stints.len() - 1) as f32;
+
+        for (projection, start_lap, end_lap) in stints {
+            let laps = end_lap - start_lap + 1;
+
+            if f32::from(laps) > self.life(projection) {
+                return None;
+            }
+
+            estimated_time_loss += self.stint_loss(projection, laps);
+        }
+
+        let mut compounds = stints
+            .iter()
+            .map(|(p, _, _)| p.set.visual_tyre_compound)
+            .chain(car.stints.iter().map(|stint| stint.visual_tyre_compound));
+        let first_compound = compounds.next()?;
+        let mut all_dry = is_dry(first_compound);
+        let mut single_compound = true;
+
+        for compound in compounds {
+            all_dry &= is_dry(compound);
+            single_compound &= compound == first_compound;
+        }
+
+        if all_dry && single_compound {
+            return None;
+        }
+
+        Some(StrategyPlan {
+            num_pit_stops: stints.len() - 1,
+            stints: stints
+                .iter()
+                .map(|(projection, start_lap, end_lap)| PlannedStint {
+                    set_index: projection.set_index,
+                    visual_tyre_compound: projection.set.visual_tyre_compound,
+                    start_lap: *start_lap,
+                    end_lap: *end_lap,
+                })
+                .collect(),
+            estimated_time_loss,
+        })
+    }
+
+    fn best_one_stop(
+        &self,
+        car: &CarTyreState,
+        fitted: &TyreSetProjection,
+        candidates: &[TyreSetProjection],
+        current_lap: u8,
+        total_laps: u8,
+        (first_stop_min, first_stop_max): (u8, u8),
+    ) -> Option<StrategyPlan> {
+        let mut best: Option<StrategyPlan> = None;
+
+        for stop in first_stop_min..=first_stop_max {
+            for set in candidates {
+                let stints = [(fitted, current_lap, stop), (set, stop + 1, total_laps)];
+
+                if let Some(plan) = self.plan(car, &stints) {
+                    best = pick_better(best, plan);
+                }
+            }
+        }
+
+        best
+    }
+
+    fn best_two_stop(
+        &self,
+        car: &CarTyreState,
+        fitted: &TyreSetProjection,
+        candidates: &[TyreSetProjection],
+        current_lap: u8,
+        total_laps: u8,
+        (first_stop_min, first_stop_max): (u8, u8),
+    ) -> Option<StrategyPlan> {
+        let mut best: Option<StrategyPlan> = None;
+
+        for first_stop in first_stop_min..=first_stop_max {
+            for second_stop in first_stop + 1..total_laps {
+                for first_set in candidates {
+                    for second_set in candidates {
+                        if first_set.set_index == second_set.set_index {
+                            continue;
+                        }
+
+                        let stints = [
+                            (fitted, current_lap, first_stop),
+                            (first_set, first_stop + 1, second_stop),
+                            (second_set, second_stop + 1, total_laps),
+                        ];
+
+                        if let Some(plan) = self.plan(car, &stints) {
+                            best = pick_better(best, plan);
+                        }
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+fn pick_better(best: Option<StrategyPlan>, plan: StrategyPlan) -> Option<StrategyPlan> {
+    match best {
+        Some(best) if best.estimated_time_loss <= plan.estimated_time_loss => Some(best),
+        _ => Some(plan),
+    }
+}
+
+fn is_dry(compound: VisualTyreCompound) -> bool {
+    !matches!(
+        compound,
+        VisualTyreCompound::Unknown
+            | VisualTyreCompound::F1Inter
+            | VisualTyreCompound::F1Wet
+            | VisualTyreCompound::ClassicWet
+            | VisualTyreCompound::F2Wet
+    )
+}
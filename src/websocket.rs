@@ -0,0 +1,564 @@
+use crate::constants::VisualTyreCompound;
+use crate::{F1Packet, F1PacketHeader};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tungstenite::handshake::server::{Request, Response};
+use tungstenite::protocol::WebSocketConfig;
+use tungstenite::{Message, WebSocket};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_PENDING_HANDSHAKES: usize = 8;
+const DEFAULT_MAX_WRITE_BUFFER: usize = 1 << 20;
+const STATE_MESSAGE_TYPE: &str = "state";
+
+/// What a client receives.
+#[non_exhaustive]
+#[derive(
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamMode {
+    /// Parsed packets, sent as `{"type": "car_telemetry", "header": {...}, "data": {...}}`.
+    /// The type matches the name of the [`F1Packet`] field holding the payload.
+    #[default]
+    Raw,
+    /// [`OverlayState`] of the player's car, sent as `{"type": "state", "state": {...}}`
+    /// whenever a subscribed packet type gets received.
+    Compact,
+}
+
+/// Client's choice of messages and their maximum rates.
+///
+/// Clients pick their subscription when connecting through query parameters, e.g.
+/// `ws://localhost:9001/?packets=motion,car_telemetry&mode=raw&rate.motion=10`,
+/// which may be percent-encoded (e.g. `packets=motion%2Ccar_telemetry`),
+/// and can change it at any time by sending this struct as a JSON text message.
+#[non_exhaustive]
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Subscription {
+    /// Packet types to receive, named after [`F1Packet`] fields.
+    /// All packet types are received if set to [`None`].
+    pub packets: Option<Vec<String>>,
+    /// Whether to receive raw packets or the compact state.
+    pub mode: StreamMode,
+    /// Maximum number of messages per second for each packet type.
+    /// In compact mode, the rate of state messages is limited by the `state` entry.
+    pub max_rates: BTreeMap<String, f32>,
+}
+
+impl Subscription {
+    fn from_query(query: &str, defaults: &Subscription) -> Self {
+        let mut subscription = defaults.clone();
+
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            let (key, value) = (percent_decode(key), percent_decode(value));
+            let (key, value) = (key.as_str(), value.as_str());
+
+            match key {
+                "packets" => {
+                    subscription.packets = Some(
+                        value
+                            .split(',')
+                            .filter(|name| !name.is_empty())
+                            .map(str::to_owned)
+                            .collect(),
+                    );
+                }
+                "mode" if value == "compact" => subscription.mode = StreamMode::Compact,
+                "mode" if value == "raw" => subscription.mode = StreamMode::Raw,
+                _ => {
+                    if let (Some(packet_type), Ok(rate)) =
+                        (key.strip_prefix("rate."), value.parse())
+                    {
+                        subscription.max_rates.insert(packet_type.to_owned(), rate);
+                    }
+                }
+            }
+        }
+
+        subscription
+    }
+
+    fn includes(&self, packet_type: &str) -> bool {
+        self.packets
+            .as_ref()
+            .map_or(true, |packets| packets.iter().any(|p| p == packet_type))
+    }
+}
+
+/// Compact snapshot of the player car's state, meant for stream overlays.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OverlayState {
+    /// Index of the player's car.
+    pub player_car_index: usize,
+    /// Speed in km/h.
+    pub speed: Option<u16>,
+    /// Selected gear.
+    pub gear: Option<i8>,
+    /// Engine speed in RPM.
+    pub engine_rpm: Option<u16>,
+    /// Throttle application, from 0 to 1.
+    pub throttle: Option<f32>,
+    /// Brake application, from 0 to 1.
+    pub brake: Option<f32>,
+    /// Whether DRS is open.
+    pub drs_enabled: Option<bool>,
+    /// Percentage of lit rev lights.
+    pub rev_lights_percent: Option<u8>,
+    /// Race position.
+    pub car_position: Option<u8>,
+    /// Current lap number.
+    pub current_lap_num: Option<u8>,
+    /// Total number of laps in the session.
+    pub total_laps: Option<u8>,
+    /// Time spent on the current lap in milliseconds.
+    pub current_lap_time_ms: Option<u32>,
+    /// Time of the last completed lap in milliseconds.
+    pub last_lap_time_ms: Option<u32>,
+    /// Current fuel mass.
+    pub fuel_in_tank: Option<f32>,
+    /// ERS energy store in Joules.
+    pub ers_store_energy: Option<f32>,
+    /// Visual compound of the fitted tyres.
+    pub visual_tyre_compound: Option<VisualTyreCompound>,
+    /// Age of the fitted tyres in laps.
+    pub tyres_age_laps: Option<u8>,
+}
+
+impl OverlayState {
+    fn update(&mut self, packet: &F1Packet) {
+        let player = packet.header.player_car_index;
+
+        self.player_car_index = player;
+
+        if let Some(session) = &packet.session {
+            self.total_laps = Some(session.total_laps);
+        } else if let Some(lap_data) =
+            packet.laps.as_ref().and_then(|p| p.data.get(player))
+        {
+            self.car_position = Some(lap_data.car_position);
+            self.current_lap_num = Some(lap_data.current_lap_num);
+            self.current_lap_time_ms = Some(lap_data.current_lap_time_ms);
+            self.last_lap_time_ms = Some(lap_data.last_lap_time_ms);
+        } else if let Some(telemetry) =
+            packet.car_telemetry.as_ref().and_then(|p| p.data.get(player))
+        {
+            self.speed = Some(telemetry.speed);
+            self.gear = Some(telemetry.gear);
+            self.engine_rpm = Some(telemetry.engine_rpm);
+            self.throttle = Some(telemetry.throttle);
+            self.brake = Some(telemetry.brake);
+            self.drs_enabled = Some(telemetry.drs_enabled);
+            self.rev_lights_percent = Some(telemetry.rev_lights_percent);
+        } else if let Some(status) =
+            packet.car_status.as_ref().and_then(|p| p.data.get(player))
+        {
+            self.fuel_in_tank = Some(status.fuel_in_tank);
+            self.ers_store_energy = Some(status.ers_store_energy);
+            self.visual_tyre_compound = Some(status.visual_tyre_compound);
+            self.tyres_age_laps = Some(status.tyres_age_laps);
+        }
+    }
+}
+
+struct Client {
+    socket: WebSocket<TcpStream>,
+    subscription: Subscription,
+    last_sent: BTreeMap<String, Instant>,
+}
+
+impl Client {
+    /// Handles messages sent by the client. Returns `false` once the connection is closed.
+    fn receive(&mut self) -> bool {
+        loop {
+            match self.socket.read() {
+                Ok(Message::Text(text)) => {
+                    if let Ok(subscription) = serde_json::from_str(&text) {
+                        self.subscription = subscription;
+                    }
+                }
+                Ok(Message::Close(_)) => return false,
+                Ok(_) => (),
+                Err(tungstenite::Error::Io(e)) if e.kind() == ErrorKind::WouldBlock => {
+                    return true
+                }
+                Err(_) => return false,
+            }
+        }
+    }
+
+    fn is_due(&mut self, message_type: &str, now: Instant) -> bool {
+        let Some(&rate) = self.subscription.max_rates.get(message_type) else {
+            return true;
+        };
+        let interval = Duration::from_secs_f32(1.0 / rate.max(f32::EPSILON));
+        let due = self
+            .last_sent
+            .get(message_type)
+            .map_or(true, |&last| now.duration_since(last) >= interval);
+
+        if due {
+            self.last_sent.insert(message_type.to_owned(), now);
+        }
+
+        due
+    }
+
+    /// Sends a message. Returns `false` if the connection is broken,
+    /// or if the client has stalled and its write buffer has filled up.
+    fn send(&mut self, message: &str) -> bool {
+        match self.socket.send(Message::text(message)) {
+            Ok(()) => true,
+            // The rest of the message stays buffered until the next write,
+            // which fails with `WriteBufferFull` once the buffer's limit is reached.
+            Err(tungstenite::Error::Io(e)) if e.kind() == ErrorKind::WouldBlock => true,
+            Err(_) => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    clients: Vec<Client>,
+    state: OverlayState,
+}
+
+/// Broadcasts packets to WebSocket clients, such as browser sources of stream overlays.
+///
+/// ## Example
+///
+/// ```no_run
+/// use f1_game_packet_parser::parse;
+/// use f1_game_packet_parser::websocket::WebSocketServer;
+/// use std::net::UdpSocket;
+///
+/// let server = WebSocketServer::new().with_max_rate("motion", 10.0).bind("0.0.0.0:9001")?;
+/// let socket = UdpSocket::bind("0.0.0.0:20777")?;
+/// let mut buf = [0u8; 2048];
+///
+/// loop {
+///     let len = socket.recv(&mut buf)?;
+///
+///     if let Ok(packet) = parse(&buf[..len]) {
+///         server.broadcast(&packet);
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct WebSocketServer {
+    defaults: Arc<Subscription>,
+    max_write_buffer: usize,
+    shared: Arc<Mutex<Shared>>,
+    local_addr: Option<SocketAddr>,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Default for WebSocketServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebSocketServer {
+    /// Creates a server that isn't listening for connections yet.
+    pub fn new() -> Self {
+        Self {
+            defaults: Arc::new(Subscription::default()),
+            max_write_buffer: DEFAULT_MAX_WRITE_BUFFER,
+            shared: Arc::new(Mutex::new(Shared::default())),
+            local_addr: None,
+            running: Arc::new(AtomicBool::new(false)),
+            thread: None,
+        }
+    }
+
+    /// Sets the subscription new clients start with,
+    /// before their query parameters get applied.
+    pub fn with_default_subscription(mut self, subscription: Subscription) -> Self {
+        self.defaults = Arc::new(subscription);
+        self
+    }
+
+    /// Sets the default maximum number of messages per second for a packet type.
+    pub fn with_max_rate(mut self, packet_type: &str, hz: f32) -> Self {
+        Arc::make_mut(&mut self.defaults).max_rates.insert(packet_type.to_owned(), hz);
+        self
+    }
+
+    /// Sets how many bytes of unsent messages a client can have buffered
+    /// before it gets disconnected. Defaults to 1 MiB.
+    ///
+    /// Clients that can't keep up, e.g. because their browser tab is in the background,
+    /// would otherwise make the buffer grow without bound.
+    pub fn with_max_write_buffer(mut self, bytes: usize) -> Self {
+        self.max_write_buffer = bytes.max(1);
+        self
+    }
+
+    /// Starts accepting clients on a background thread.
+    /// Each handshake runs on its own short-lived thread,
+    /// so slow clients don't hold up new connections.
+    /// Connections arriving while 8 handshakes are still pending get closed.
+    /// The server stops once it gets dropped.
+    pub fn bind(mut self, addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let defaults = Arc::clone(&self.defaults);
+        let shared = Arc::clone(&self.shared);
+        let running = Arc::clone(&self.running);
+        let config = WebSocketConfig {
+            write_buffer_size: 0,
+            max_write_buffer_size: self.max_write_buffer,
+            ..WebSocketConfig::default()
+        };
+
+        let pending = Arc::new(AtomicUsize::new(0));
+
+        listener.set_nonblocking(true)?;
+        running.store(true, Ordering::Relaxed);
+        self.local_addr = Some(listener.local_addr()?);
+        self.thread = Some(thread::spawn(move || {
+            while running.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let Some(slot) = PendingHandshake::start(&pending) else {
+                            continue;
+                        };
+                        let defaults = Arc::clone(&defaults);
+                        let shared = Arc::clone(&shared);
+
+                        thread::spawn(move || {
+                            let _slot = slot;
+
+                            if let Ok(client) = handshake(stream, &defaults, config) {
+                                lock(&shared).clients.push(client);
+                            }
+                        });
+                    }
+                    Err(_) => thread::sleep(POLL_INTERVAL),
+                }
+            }
+        }));
+
+        Ok(self)
+    }
+
+    /// Returns the address the server is listening on,
+    /// or [`None`] if it hasn't been bound yet.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Returns the number of connected clients.
+    pub fn client_count(&self) -> usize {
+        lock(&self.shared).clients.len()
+    }
+
+    /// Sends the packet, or the state derived from it, to clients subscribed to its type.
+    /// Clients that have disconnected get dropped.
+    /// Packets are only serialized if a client is due to receive them.
+    pub fn broadcast(&self, packet: &F1Packet) {
+        let Some(packet_type) = packet_type(packet) else {
+            return;
+        };
+        let now = Instant::now();
+        let mut shared = lock(&self.shared);
+        let Shared { clients, state } = &mut *shared;
+        let mut raw_message = None;
+        let mut state_message = None;
+
+        state.update(packet);
+
+        clients.retain_mut(|client| {
+            if !client.receive() {
+                return false;
+            }
+
+            if !client.subscription.includes(packet_type) {
+                return true;
+            }
+
+            let (message_type, message) = match client.subscription.mode {
+                StreamMode::Raw => (packet_type, &mut raw_message),
+                StreamMode::Compact => (STATE_MESSAGE_TYPE, &mut state_message),
+            };
+
+            if !client.is_due(message_type, now) {
+                return true;
+            }
+
+            let message = message.get_or_insert_with(|| match client.subscription.mode {
+                StreamMode::Raw => {
+                    raw_json(packet_type, &packet.header, &payload(packet))
+                }
+                StreamMode::Compact => {
+                    json!({ "type": STATE_MESSAGE_TYPE, "state": state }).to_string()
+                }
+            });
+
+            client.send(message)
+        });
+    }
+}
+
+impl Drop for WebSocketServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Counts a handshake as pending until it gets dropped.
+struct PendingHandshake(Arc<AtomicUsize>);
+
+impl PendingHandshake {
+    fn start(pending: &Arc<AtomicUsize>) -> Option<Self> {
+        pending
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < MAX_PENDING_HANDSHAKES).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(pending)))
+    }
+}
+
+impl Drop for PendingHandshake {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn lock(shared: &Mutex<Shared>) -> std::sync::MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// The callback's error type is dictated by tungstenite.
+#[allow(clippy::result_large_err)]
+fn handshake(
+    stream: TcpStream,
+    defaults: &Subscription,
+    config: WebSocketConfig,
+) -> Result<Client, ()> {
+    let mut subscription = defaults.clone();
+
+    stream.set_nonblocking(false).map_err(drop)?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(drop)?;
+    stream.set_write_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(drop)?;
+
+    let socket = tungstenite::accept_hdr_with_config(
+        stream,
+        |request: &Request, response: Response| {
+            subscription = Subscription::from_query(
+                request.uri().query().unwrap_or_default(),
+                defaults,
+            );
+            Ok(response)
+        },
+        Some(config),
+    )
+    .map_err(drop)?;
+
+    socket.get_ref().set_read_timeout(None).map_err(drop)?;
+    socket.get_ref().set_write_timeout(None).map_err(drop)?;
+    socket.get_ref().set_nonblocking(true).map_err(drop)?;
+
+    Ok(Client { socket, subscription, last_sent: BTreeMap::new() })
+}
+
+fn raw_json(packet_type: &str, header: &F1PacketHeader, data: &Value) -> String {
+    json!({ "type": packet_type, "header": header, "data": data }).to_string()
+}
+
+/// Decodes `%XX` sequences and `+` signs of a query string component.
+fn percent_decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(if bytes[i] == b'+' { b' ' } else { bytes[i] });
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+macro_rules! payloads {
+    ($($field:ident),*) => {
+        /// Name of the [`F1Packet`] field holding the packet's payload.
+        fn packet_type(packet: &F1Packet) -> Option<&'static str> {
+            $(
+                if packet.$field.is_some() {
+                    return Some(stringify!($field));
+                }
+            )*
+
+            None
+        }
+
+        fn payload(packet: &F1Packet) -> Value {
+            $(
+                if let Some(payload) = &packet.$field {
+                    return serde_json::to_value(payload).unwrap_or_default();
+                }
+            )*
+
+            Value::Null
+        }
+    };
+}
+
+payloads!(
+    motion,
+    session,
+    laps,
+    event,
+    participants,
+    car_setups,
+    car_telemetry,
+    car_status,
+    final_classification,
+    lobby,
+    car_damage,
+    session_history,
+    tyre_sets,
+    motion_ex,
+    time_trial
+);
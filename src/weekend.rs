@@ -0,0 +1,484 @@
+use crate::constants::{session_type, ResultStatus, TrackId};
+use crate::packets::final_classification::FinalClassificationData;
+use crate::packets::participants::ParticipantsData;
+use crate::F1Packet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Kind of a session within a race weekend.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SessionKind {
+    Practice,
+    Qualifying,
+    /// Sprint shootout. Available from the 2024 format onwards.
+    SprintQualifying,
+    Sprint,
+    Race,
+    TimeTrial,
+    Unknown,
+}
+
+impl SessionKind {
+    /// Classifies a session type given all session types of its weekend.
+    ///
+    /// A race session followed by another race session in the same weekend
+    /// is considered a sprint.
+    pub fn new(
+        session_type: u8,
+        packet_format: u16,
+        weekend_session_types: &[u8],
+    ) -> Self {
+        let races = race_session_types(packet_format);
+
+        if races.contains(&session_type) {
+            let is_sprint = weekend_session_types
+                .iter()
+                .any(|other| races.contains(other) && *other > session_type);
+
+            return if is_sprint { SessionKind::Sprint } else { SessionKind::Race };
+        }
+
+        match (session_type, packet_format) {
+            (session_type::PRACTICE_1..=session_type::SHORT_PRACTICE, _) => {
+                SessionKind::Practice
+            }
+            (session_type::QUALIFYING_1..=session_type::ONE_SHOT_QUALIFYING, _) => {
+                SessionKind::Qualifying
+            }
+            (session_type::SPRINT_1..=session_type::ONE_SHOT_SPRINT, 2024..) => {
+                SessionKind::SprintQualifying
+            }
+            (session_type::TIME_TRIAL_2024, 2024..) => SessionKind::TimeTrial,
+            (session_type::TIME_TRIAL, ..=2023) => SessionKind::TimeTrial,
+            _ => SessionKind::Unknown,
+        }
+    }
+}
+
+fn race_session_types(packet_format: u16) -> RangeInclusive<u8> {
+    if packet_format >= 2024 {
+        session_type::RACE_2024..=session_type::RACE_3_2024
+    } else {
+        session_type::RACE..=session_type::RACE_3
+    }
+}
+
+/// Classified result of a single car in a session.
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionResult {
+    /// Index of the car in the session.
+    pub vehicle_index: usize,
+    /// Driver's display name, resolved from participants data.
+    pub name: String,
+    /// Finishing position.
+    pub position: u8,
+    /// Grid position.
+    pub grid_position: u8,
+    /// Number of points scored.
+    pub points: u8,
+    /// Number of laps completed.
+    pub num_laps: u8,
+    /// Result status.
+    pub result_status: ResultStatus,
+    /// Best lap time in milliseconds.
+    pub best_lap_time_ms: u32,
+    /// Total race time in seconds, including penalties.
+    pub total_race_time: f64,
+}
+
+impl SessionResult {
+    fn new(vehicle_index: usize, name: String, data: &FinalClassificationData) -> Self {
+        Self {
+            vehicle_index,
+            name,
+            position: data.position,
+            grid_position: data.grid_position,
+            points: data.points,
+            num_laps: data.num_laps,
+            result_status: data.result_status,
+            best_lap_time_ms: data.best_lap_time_ms,
+            total_race_time: data.total_race_time + f64::from(data.penalties_time),
+        }
+    }
+}
+
+/// Session of a [`Weekend`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeekendSession {
+    /// `session_uid` of the most recent attempt at the session.
+    pub session_uid: u64,
+    /// Identifier shared by all attempts at the session.
+    pub session_link_identifier: u32,
+    /// See [`session_type`](mod@crate::constants::session_type) for possible values.
+    pub session_type: u8,
+    /// Results ordered by finishing position,
+    /// available once the final classification has been received.
+    pub results: Option<Vec<SessionResult>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    names: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    drivers: Vec<DriverKey>,
+}
+
+/// Identity of a driver across sessions, since vehicle indexes
+/// and display names may change from one session to another.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Hash)]
+pub(crate) enum DriverKey {
+    /// Online name of a human driver. Network IDs are lobby slots,
+    /// so they don't identify a human from one lobby to the next.
+    Human(String),
+    /// Driver and network IDs from participants data.
+    Participant { driver_id: u8, network_id: u8 },
+    /// Vehicle index, used if no participants data has been received.
+    Car(usize),
+}
+
+impl DriverKey {
+    pub(crate) fn new(
+        participant: Option<&ParticipantsData>,
+        vehicle_index: usize,
+    ) -> Self {
+        match participant {
+            Some(participant)
+                if (!participant.ai_controlled || participant.driver_id == 255)
+                    && !participant.has_placeholder_name() =>
+            {
+                Self::Human(participant.name.clone())
+            }
+            Some(participant) => Self::Participant {
+                driver_id: participant.driver_id,
+                network_id: participant.network_id,
+            },
+            None => Self::Car(vehicle_index),
+        }
+    }
+}
+
+/// Starting position derived from a qualifying session.
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridSlot {
+    /// Position on the grid.
+    pub position: u8,
+    /// Index of the car in the qualifying session.
+    pub vehicle_index: usize,
+    /// Driver's display name.
+    pub name: String,
+    /// Best qualifying lap time in milliseconds.
+    pub best_lap_time_ms: u32,
+}
+
+/// Points a driver has scored over a weekend.
+///
+/// Human drivers are told apart by their online names and AI drivers
+/// by their driver and network IDs, falling back to the vehicle index
+/// without participants data,
+/// so drivers sharing a display name don't get their points merged.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DriverPoints {
+    /// Driver's display name.
+    pub name: String,
+    /// Points scored in sprints.
+    pub sprint_points: u32,
+    /// Points scored in races.
+    pub race_points: u32,
+}
+
+impl DriverPoints {
+    /// Total number of points.
+    pub fn total(&self) -> u32 {
+        self.sprint_points + self.race_points
+    }
+}
+
+/// Summary of a [`Weekend`] returned by [`Weekend::summary`].
+#[non_exhaustive]
+#[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeekendSummary {
+    /// Track of the weekend.
+    pub track_id: Option<TrackId>,
+    /// Sprint grid from the latest classified sprint shootout.
+    pub sprint_grid: Vec<GridSlot>,
+    /// Sprint results from the latest classified sprint.
+    pub sprint_results: Vec<SessionResult>,
+    /// Race grid from the latest classified qualifying session.
+    pub grid: Vec<GridSlot>,
+    /// Race results from the latest classified race.
+    pub race_results: Vec<SessionResult>,
+    /// Points scored in sprints and races, sorted by total in descending order.
+    pub points: Vec<DriverPoints>,
+}
+
+/// Sessions sharing a `weekend_link_identifier`, with their final classifications.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Weekend {
+    weekend_link_identifier: u32,
+    packet_format: u16,
+    track_id: Option<TrackId>,
+    weekend_structure: Vec<u8>,
+    sessions: Vec<WeekendSession>,
+}
+
+impl Weekend {
+    fn new(weekend_link_identifier: u32, packet_format: u16) -> Self {
+        Self {
+            weekend_link_identifier,
+            packet_format,
+            track_id: None,
+            weekend_structure: Vec::new(),
+            sessions: Vec::new(),
+        }
+    }
+
+    /// Identifier shared by the weekend's sessions.
+    pub fn weekend_link_identifier(&self) -> u32 {
+        self.weekend_link_identifier
+    }
+
+    /// Track of the weekend.
+    pub fn track_id(&self) -> Option<TrackId> {
+        self.track_id
+    }
+
+    /// Session types the weekend consists of.
+    /// Only available from the 2024 format onwards.
+    pub fn weekend_structure(&self) -> &[u8] {
+        &self.weekend_structure
+    }
+
+    /// Sessions in order of discovery.
+    pub fn sessions(&self) -> &[WeekendSession] {
+        &self.sessions
+    }
+
+    /// Returns the kind of a weekend's session.
+    pub fn session_kind(&self, session: &WeekendSession) -> SessionKind {
+        SessionKind::new(session.session_type, self.packet_format, &self.session_types())
+    }
+
+    /// Whether every session of the [`weekend_structure`](Self::weekend_structure)
+    /// has been classified. Always `false` before the 2024 format.
+    pub fn is_complete(&self) -> bool {
+        !self.weekend_structure.is_empty()
+            && self.weekend_structure.iter().all(|&session_type| {
+                self.sessions.iter().any(|session| {
+                    session.session_type == session_type && session.results.is_some()
+                })
+            })
+    }
+
+    /// Summarises the weekend's classified sessions.
+    pub fn summary(&self) -> WeekendSummary {
+        let latest = |kind: SessionKind| {
+            self.sessions
+                .iter()
+                .rev()
+                .filter(|session| self.session_kind(session) == kind)
+                .find_map(|session| session.results.as_ref())
+                .cloned()
+                .unwrap_or_default()
+        };
+        let mut points: BTreeMap<DriverKey, DriverPoints> = BTreeMap::new();
+
+        for session in &self.sessions {
+            let kind = self.session_kind(session);
+
+            if !matches!(kind, SessionKind::Sprint | SessionKind::Race) {
+                continue;
+            }
+
+            for result in session.results.iter().flatten() {
+                let key = session
+                    .drivers
+                    .get(result.vehicle_index)
+                    .cloned()
+                    .unwrap_or(DriverKey::Car(result.vehicle_index));
+                let entry = points.entry(key).or_insert_with(|| DriverPoints {
+                    name: String::new(),
+                    sprint_points: 0,
+                    race_points: 0,
+                });
+
+                // Names are only used for display, so the most recent one is kept.
+                entry.name.clone_from(&result.name);
+
+                match kind {
+                    SessionKind::Sprint => {
+                        entry.sprint_points += u32::from(result.points)
+                    }
+                    _ => entry.race_points += u32::from(result.points),
+                }
+            }
+        }
+
+        let mut points: Vec<DriverPoints> = points.into_values().collect();
+
+        points
+            .sort_by(|a, b| b.total().cmp(&a.total()).then_with(|| a.name.cmp(&b.name)));
+
+        WeekendSummary {
+            track_id: self.track_id,
+            sprint_grid: grid(&latest(SessionKind::SprintQualifying)),
+            sprint_results: latest(SessionKind::Sprint),
+            grid: grid(&latest(SessionKind::Qualifying)),
+            race_results: latest(SessionKind::Race),
+            points,
+        }
+    }
+
+    fn session_types(&self) -> Vec<u8> {
+        if self.weekend_structure.is_empty() {
+            self.sessions.iter().map(|session| session.session_type).collect()
+        } else {
+            self.weekend_structure.clone()
+        }
+    }
+}
+
+fn grid(results: &[SessionResult]) -> Vec<GridSlot> {
+    results
+        .iter()
+        .map(|result| GridSlot {
+            position: result.position,
+            vehicle_index: result.vehicle_index,
+            name: result.name.clone(),
+            best_lap_time_ms: result.best_lap_time_ms,
+        })
+        .collect()
+}
+
+/// Collects sessions into [`Weekend`]s by their `weekend_link_identifier`,
+/// carrying final classifications forward so that a weekend summary
+/// can be produced after every session.
+///
+/// Packets of a session only get processed once its session packet
+/// has been received. Restarting a session replaces its previous attempt.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct WeekendTracker {
+    weekends: BTreeMap<u32, Weekend>,
+    session_weekends: BTreeMap<u64, u32>,
+}
+
+impl WeekendTracker {
+    /// Creates a tracker with no weekends.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a weekend by its `weekend_link_identifier`.
+    pub fn weekend(&self, weekend_link_identifier: u32) -> Option<&Weekend> {
+        self.weekends.get(&weekend_link_identifier)
+    }
+
+    /// Returns all weekends, ordered by their `weekend_link_identifier`.
+    pub fn weekends(&self) -> impl Iterator<Item = &Weekend> {
+        self.weekends.values()
+    }
+
+    /// Processes a packet. Returns the `weekend_link_identifier`
+    /// of the weekend a session has just been classified in.
+    pub fn update(&mut self, packet: &F1Packet) -> Option<u32> {
+        let session_uid = packet.header.session_uid;
+
+        if let Some(session) = &packet.session {
+            let weekend = self
+                .weekends
+                .entry(session.weekend_link_identifier)
+                .or_insert_with(|| {
+                    Weekend::new(
+                        session.weekend_link_identifier,
+                        packet.header.packet_format,
+                    )
+                });
+
+            weekend.track_id = Some(session.track_id);
+
+            if !session.weekend_structure.is_empty() {
+                weekend.weekend_structure.clone_from(&session.weekend_structure);
+            }
+
+            if self
+                .session_weekends
+                .insert(session_uid, session.weekend_link_identifier)
+                .is_none()
+            {
+                let attempt = WeekendSession {
+                    session_uid,
+                    session_link_identifier: session.session_link_identifier,
+                    session_type: session.session_type,
+                    results: None,
+                    names: Vec::new(),
+                    drivers: Vec::new(),
+                };
+
+                match weekend.sessions.iter_mut().find(|other| {
+                    other.session_link_identifier == session.session_link_identifier
+                }) {
+                    Some(previous) => *previous = attempt,
+                    None => weekend.sessions.push(attempt),
+                }
+            }
+
+            return None;
+        }
+
+        let weekend_link_identifier = *self.session_weekends.get(&session_uid)?;
+        let weekend = self.weekends.get_mut(&weekend_link_identifier)?;
+        let session = weekend
+            .sessions
+            .iter_mut()
+            .find(|session| session.session_uid == session_uid)?;
+
+        if let Some(participants) = &packet.participants {
+            session.names = participants
+                .data
+                .iter()
+                .map(|participant| {
+                    participant.display_name(packet.header.packet_format).into_owned()
+                })
+                .collect();
+            session.drivers = participants
+                .data
+                .iter()
+                .enumerate()
+                .map(|(vehicle_index, participant)| {
+                    DriverKey::new(Some(participant), vehicle_index)
+                })
+                .collect();
+        } else if let Some(final_classification) = &packet.final_classification {
+            let mut results: Vec<SessionResult> = final_classification
+                .data
+                .iter()
+                .enumerate()
+                .filter(|(_, data)| data.position > 0)
+                .map(|(vehicle_index, data)| {
+                    let name = session
+                        .names
+                        .get(vehicle_index)
+                        .cloned()
+                        .unwrap_or_else(|| format!("Car {}", vehicle_index));
+
+                    SessionResult::new(vehicle_index, name, data)
+                })
+                .collect();
+
+            results.sort_by_key(|result| result.position);
+            session.results = Some(results);
+
+            return Some(weekend_link_identifier);
+        }
+
+        None
+    }
+}
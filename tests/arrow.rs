@@ -0,0 +1,196 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::{Array, Float64Array, Int64Array, StringArray, UInt64Array};
+use f1_game_packet_parser::export::arrow::{
+    ArrowConverter, ParquetExportError, ParquetExporter,
+};
+use f1_game_packet_parser::parse;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::fs::File;
+
+#[test]
+fn one_table_per_packet_type() {
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let session = parse(include_bytes!("data/2024-01.bin")).unwrap();
+    let collision = parse(include_bytes!("data/2024-03-COLL.bin")).unwrap();
+    let mut converter = ArrowConverter::new();
+
+    converter.push(&telemetry).unwrap();
+    converter.push(&telemetry).unwrap();
+    converter.push(&session).unwrap();
+    converter.push(&collision).unwrap();
+
+    assert_eq!(converter.table_names(), ["car_telemetry", "event_coll", "session"]);
+    assert_eq!(converter.num_rows("car_telemetry"), 44);
+
+    let batches = converter.finish().unwrap();
+    let (_, telemetry_batch) = &batches[0];
+    let (_, event_batch) = &batches[1];
+    let (_, session_batch) = &batches[2];
+    let schema = telemetry_batch.schema();
+    let speed = telemetry_batch
+        .column_by_name("speed")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Int64Array>();
+    let vehicle_index = telemetry_batch
+        .column_by_name("vehicle_index")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<UInt64Array>();
+    let pressure = telemetry_batch
+        .column_by_name("tyres_pressure_3")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Float64Array>();
+    let data = &telemetry.car_telemetry.as_ref().unwrap().data[19];
+
+    assert_eq!(schema.field(0).name(), "frame_identifier");
+    assert!(schema.field_with_name("session_uid").is_ok());
+    assert!(schema.field_with_name("tyres_pressure_3").is_ok());
+
+    assert_eq!(speed.unwrap().value(19), i64::from(data.speed));
+    assert_eq!(pressure.unwrap().value(19), f64::from(data.tyres_pressure[3]));
+    assert_eq!(vehicle_index.unwrap().value(23), 1);
+    assert_eq!(converter.table_names(), Vec::<&str>::new());
+
+    let details = event_batch.column_by_name("details").unwrap();
+    let other = event_batch.column_by_name("details_other_vehicle_index").unwrap();
+
+    assert_eq!(
+        details.as_any().downcast_ref::<StringArray>().unwrap().value(0),
+        "Collision"
+    );
+    assert_eq!(other.as_any().downcast_ref::<Int64Array>().unwrap().value(0), 1);
+
+    let forecast = session_batch.column_by_name("weather_forecast_samples").unwrap();
+    let forecast = forecast.as_any().downcast_ref::<StringArray>().unwrap().value(0);
+
+    assert_eq!(session_batch.num_rows(), 1);
+    assert!(forecast.starts_with("[{"));
+    assert!(forecast.contains("\"session_type\":15,\"time_offset\":0"));
+    assert!(forecast.contains("\"weather\":\"Clear\""));
+}
+
+#[test]
+fn parquet_round_trip() {
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let directory = std::env::temp_dir()
+        .join(format!("f1-game-packet-parser-parquet-{}", std::process::id()));
+    let mut exporter = ParquetExporter::new(&directory).with_batch_size(30);
+
+    for _ in 0..3 {
+        exporter.write_packet(&laps).unwrap();
+    }
+
+    let paths = exporter.finish().unwrap();
+
+    assert_eq!(paths, [directory.join("2024").join("laps.parquet")]);
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&paths[0]).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(Result::unwrap).collect();
+
+    assert_eq!(batches.iter().map(|batch| batch.num_rows()).sum::<usize>(), 66);
+    assert!(batches[0].schema().field_with_name("lap_distance").is_ok());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn upgrades_all_null_columns() {
+    let participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut offline = participants.clone();
+    let directory = std::env::temp_dir()
+        .join(format!("f1-game-packet-parser-parquet-null-{}", std::process::id()));
+    let mut exporter = ParquetExporter::new(&directory).with_batch_size(1);
+
+    for participant in &mut offline.participants.as_mut().unwrap().data {
+        participant.platform = None;
+    }
+
+    exporter.write_packet(&offline).unwrap();
+    exporter.write_packet(&participants).unwrap();
+
+    let paths = exporter.finish().unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&paths[0]).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(Result::unwrap).collect();
+    let platforms: Vec<Option<&str>> = batches
+        .iter()
+        .flat_map(|batch| {
+            let platform = batch.column_by_name("platform").unwrap();
+            let platform = platform.as_any().downcast_ref::<StringArray>().unwrap();
+
+            platform.iter().collect::<Vec<_>>()
+        })
+        .collect();
+    let rows = platforms.len() / 2;
+
+    assert!(platforms[..rows].iter().all(Option::is_none));
+    assert!(platforms[rows..].iter().all(Option::is_some));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn separate_files_per_packet_format() {
+    let directory = std::env::temp_dir()
+        .join(format!("f1-game-packet-parser-parquet-formats-{}", std::process::id()));
+    let mut exporter = ParquetExporter::new(&directory).with_batch_size(1);
+
+    // Motion payloads of the 2022 format have no extra data of the player's car.
+    exporter.write_packet(&parse(include_bytes!("data/2022-00.bin")).unwrap()).unwrap();
+    exporter.write_packet(&parse(include_bytes!("data/2024-00.bin")).unwrap()).unwrap();
+    exporter.write_packet(&parse(include_bytes!("data/2022-00.bin")).unwrap()).unwrap();
+
+    let paths = exporter.finish().unwrap();
+
+    assert_eq!(
+        paths,
+        [
+            directory.join("2022").join("motion.parquet"),
+            directory.join("2024").join("motion.parquet")
+        ]
+    );
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&paths[0]).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(Result::unwrap).collect();
+
+    assert_eq!(batches.iter().map(|batch| batch.num_rows()).sum::<usize>(), 44);
+    assert!(batches[0]
+        .schema()
+        .field_with_name("motion_ex_angular_acceleration_x")
+        .is_ok());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn new_columns_are_typed_errors() {
+    let motion = parse(include_bytes!("data/2022-00.bin")).unwrap();
+    let mut without_ex = motion.clone();
+    let directory = std::env::temp_dir()
+        .join(format!("f1-game-packet-parser-parquet-columns-{}", std::process::id()));
+    let mut exporter = ParquetExporter::new(&directory).with_batch_size(1);
+
+    without_ex.motion.as_mut().unwrap().motion_ex = None;
+
+    exporter.write_packet(&without_ex).unwrap();
+
+    let error = exporter.write_packet(&motion).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ParquetExportError::UnknownColumn { ref table, .. } if table == "motion"
+    ));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::buttons::{ButtonEventKind, ButtonTracker, ButtonTrigger};
+use f1_game_packet_parser::constants::ButtonStatus;
+use f1_game_packet_parser::packets::event::EventDetails;
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet_at;
+
+#[derive(PartialEq, Clone, Debug)]
+enum Macro {
+    MarkLap,
+    ToggleRecording,
+    CycleOverlay,
+}
+
+fn buttons(button_status: ButtonStatus, session_time: f32) -> F1Packet {
+    let mut packet = packet_at(include_bytes!("data/2024-03-BUTN.bin"), session_time);
+
+    packet.event.as_mut().unwrap().details = EventDetails::Buttons { button_status };
+    packet
+}
+
+fn telemetry(session_time: f32) -> F1Packet {
+    packet_at(include_bytes!("data/2024-06.bin"), session_time)
+}
+
+#[test]
+fn presses_holds_and_releases() {
+    let action_1 = ButtonStatus::UDP_ACTION_1;
+    let mut tracker = ButtonTracker::new()
+        .with_binding(action_1, ButtonTrigger::Press, Macro::MarkLap)
+        .with_binding(action_1, ButtonTrigger::Hold(2.0), Macro::ToggleRecording);
+
+    let pressed = tracker.update(&buttons(action_1, 10.0));
+
+    assert_eq!(pressed.len(), 1);
+    assert_eq!(pressed[0].kind, ButtonEventKind::Pressed);
+    assert_eq!(pressed[0].buttons, action_1);
+    assert_eq!(pressed[0].actions, [Macro::MarkLap]);
+
+    assert_eq!(tracker.update(&telemetry(10.25)), []);
+
+    let held = tracker.update(&telemetry(12.5));
+    let kinds: Vec<_> = held.iter().map(|event| event.kind).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            ButtonEventKind::Held { duration: 0.5 },
+            ButtonEventKind::Held { duration: 2.0 }
+        ]
+    );
+    assert!(held[0].actions.is_empty());
+    assert_eq!(held[1].actions, [Macro::ToggleRecording]);
+    assert_eq!(tracker.update(&telemetry(13.0)), []);
+
+    let released = tracker.update(&buttons(ButtonStatus::empty(), 13.5));
+
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].kind, ButtonEventKind::Released { held_for: 3.5 });
+    assert_eq!(tracker.status(), ButtonStatus::empty());
+}
+
+#[test]
+fn double_presses_and_chords() {
+    let (action_1, action_2) = (ButtonStatus::UDP_ACTION_1, ButtonStatus::UDP_ACTION_2);
+    let mut tracker = ButtonTracker::new()
+        .with_binding(action_2, ButtonTrigger::DoublePress, Macro::MarkLap)
+        .with_binding(action_1 | action_2, ButtonTrigger::Chord, Macro::CycleOverlay);
+
+    tracker.update(&buttons(action_2, 1.0));
+    tracker.update(&buttons(ButtonStatus::empty(), 1.1));
+
+    let double = tracker.update(&buttons(action_2, 1.25));
+
+    assert_eq!(double.len(), 2);
+    assert_eq!(double[1].kind, ButtonEventKind::DoublePressed);
+    assert_eq!(double[1].actions, [Macro::MarkLap]);
+
+    let chord = tracker.update(&buttons(action_1 | action_2, 1.3));
+
+    assert_eq!(chord.len(), 2);
+    assert_eq!(chord[0].kind, ButtonEventKind::Pressed);
+    assert_eq!(chord[0].buttons, action_1);
+    assert_eq!(chord[1].kind, ButtonEventKind::Chord);
+    assert_eq!(chord[1].buttons, action_1 | action_2);
+    assert_eq!(chord[1].actions, [Macro::CycleOverlay]);
+
+    // A third quick press doesn't count as another double press.
+    tracker.update(&buttons(action_1, 1.35));
+
+    let third = tracker.update(&buttons(action_1 | action_2, 1.4));
+
+    assert_eq!(third.len(), 2);
+    assert_eq!(third[0].kind, ButtonEventKind::Pressed);
+    assert_eq!(third[1].kind, ButtonEventKind::Chord);
+}
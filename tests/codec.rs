@@ -0,0 +1,142 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::codec::{
+    CodecError, CodecReader, CodecWriter, DeltaDecoder, DeltaEncoder, MAX_PACKET_LEN,
+};
+use f1_game_packet_parser::parse;
+
+const SAMPLES: [&[u8]; 6] = [
+    include_bytes!("data/2024-00.bin"),
+    include_bytes!("data/2024-01.bin"),
+    include_bytes!("data/2024-03-COLL.bin"),
+    include_bytes!("data/2024-04.bin"),
+    include_bytes!("data/2024-05.bin"),
+    include_bytes!("data/2024-06.bin"),
+];
+
+/// Returns a copy of the packet with a different frame identifier and session time,
+/// which are stored at the same offsets in every 2024 header.
+fn next_frame(raw: &[u8], frame: u32) -> Vec<u8> {
+    let mut raw = raw.to_vec();
+
+    raw[15..19].copy_from_slice(&(frame as f32 / 60.0).to_le_bytes());
+    raw[19..23].copy_from_slice(&frame.to_le_bytes());
+    raw
+}
+
+#[test]
+fn stream_round_trip_is_byte_exact() {
+    let mut packets = Vec::new();
+
+    for frame in 0..20 {
+        for sample in SAMPLES {
+            packets.push(next_frame(sample, frame));
+        }
+    }
+
+    let mut writer = CodecWriter::new(Vec::new());
+
+    for raw in &packets {
+        writer.write_packet(raw).unwrap();
+    }
+
+    let encoded = writer.into_inner();
+    let raw_len: usize = packets.iter().map(Vec::len).sum();
+
+    assert!(encoded.len() * 10 < raw_len);
+
+    let decoded: Vec<Vec<u8>> =
+        CodecReader::new(encoded.as_slice()).collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(decoded, packets);
+
+    let first = CodecReader::new(encoded.as_slice()).read_packet().unwrap().unwrap();
+
+    assert_eq!(first, parse(&packets[0]).unwrap());
+}
+
+#[test]
+fn keyframes_and_missing_references() {
+    let mut encoder = DeltaEncoder::new().with_keyframe_interval(3);
+    let frames: Vec<Vec<u8>> = (0..4)
+        .map(|frame| encoder.encode(&next_frame(SAMPLES[5], frame)).unwrap())
+        .collect();
+    let kinds: Vec<u8> = frames.iter().map(|frame| frame[0]).collect();
+
+    assert_eq!(kinds, [0, 1, 1, 0]);
+
+    let mut decoder = DeltaDecoder::new();
+
+    assert!(matches!(decoder.decode(&frames[1]), Err(CodecError::MissingReference(6))));
+    assert_eq!(
+        decoder.decode_packet(&frames[3]).unwrap(),
+        parse(next_frame(SAMPLES[5], 3)).unwrap()
+    );
+    assert!(matches!(encoder.encode(&[0u8; 4]), Err(CodecError::InvalidPacket(_))));
+    assert!(matches!(
+        CodecReader::new(&b"F1XX\x01"[..]).read_raw(),
+        Err(CodecError::InvalidMagic)
+    ));
+}
+
+#[test]
+fn empty_and_truncated_streams() {
+    assert!(matches!(CodecReader::new(&b""[..]).read_raw(), Ok(None)));
+
+    for len in 1..5 {
+        assert!(matches!(
+            CodecReader::new(&b"F1DC\x01"[..len]).read_raw(),
+            Err(CodecError::Truncated)
+        ));
+    }
+
+    assert!(matches!(CodecReader::new(&b"F1DC\x01"[..]).read_raw(), Ok(None)));
+}
+
+#[test]
+fn rejects_oversized_lengths() {
+    // A frame length of 2^35 bytes, which mustn't get allocated.
+    let stream = b"F1DC\x01\x80\x80\x80\x80\x80\x01";
+
+    assert!(matches!(
+        CodecReader::new(&stream[..]).read_raw(),
+        Err(CodecError::TooLarge { max: 2928, .. })
+    ));
+
+    // A keyframe claiming to decode into a 2^28 byte packet.
+    let frame = [0, 6, 0x80, 0x80, 0x80, 0x80, 0x01, 0x00, 0x7f];
+
+    assert!(matches!(
+        DeltaDecoder::new().decode(&frame),
+        Err(CodecError::TooLarge { len: 268_435_456, max: MAX_PACKET_LEN })
+    ));
+
+    let mut oversized = SAMPLES[0].to_vec();
+
+    oversized.resize(MAX_PACKET_LEN + 1, 0);
+    assert!(matches!(
+        DeltaEncoder::new().encode(&oversized),
+        Err(CodecError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn deltas_are_field_wise() {
+    let mut encoder = DeltaEncoder::new();
+    let mut decoder = DeltaDecoder::new();
+    let mut previous = next_frame(SAMPLES[5], 0xffff);
+    let mut raw = next_frame(SAMPLES[5], 0xffff);
+
+    // Speed of the first car, right after the 29-byte header.
+    previous[29..31].copy_from_slice(&255u16.to_le_bytes());
+    raw[19..23].copy_from_slice(&0x1_0000u32.to_le_bytes());
+    raw[29..31].copy_from_slice(&256u16.to_le_bytes());
+
+    decoder.decode(&encoder.encode(&previous).unwrap()).unwrap();
+
+    let frame = encoder.encode(&raw).unwrap();
+
+    // Both carries leave a difference of 1 in the field's lowest byte only.
+    assert_eq!(frame, [1, 6, 0xc8, 0x0a, 0, 19, 1, 1, 9, 1, 1, 0xaa, 0x0a]);
+    assert_eq!(decoder.decode(&frame).unwrap(), raw);
+}
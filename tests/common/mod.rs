@@ -0,0 +1,45 @@
+//! Fixture helpers shared by the integration tests.
+
+// Each test binary only uses some of the helpers.
+#![allow(dead_code)]
+
+use f1_game_packet_parser::constants::SafetyCarStatus;
+use f1_game_packet_parser::parse;
+use f1_game_packet_parser::F1Packet;
+
+/// Index of the player's car in the 2024 fixtures.
+pub const PLAYER: usize = 19;
+
+pub fn packet(data: &[u8]) -> F1Packet {
+    parse(data).unwrap()
+}
+
+pub fn packet_at(data: &[u8], session_time: f32) -> F1Packet {
+    let mut packet = packet(data);
+
+    packet.header.session_time = session_time;
+    packet
+}
+
+/// Session packet with the given safety car status.
+pub fn session(
+    data: &[u8],
+    safety_car_status: SafetyCarStatus,
+    session_time: f32,
+) -> F1Packet {
+    let mut packet = packet_at(data, session_time);
+
+    packet.session.as_mut().unwrap().safety_car_status = safety_car_status;
+    packet
+}
+
+/// Lap data packet with every car on the same lap.
+pub fn laps(data: &[u8], lap_num: u8) -> F1Packet {
+    let mut packet = packet(data);
+
+    for lap_data in &mut packet.laps.as_mut().unwrap().data {
+        lap_data.current_lap_num = lap_num;
+    }
+
+    packet
+}
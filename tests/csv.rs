@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::export::csv::{CsvExporter, CsvSource};
+use f1_game_packet_parser::parse;
+
+#[test]
+fn telemetry_with_selected_channels_and_cars() {
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let mut exporter = CsvExporter::new(Vec::new(), CsvSource::CarTelemetry)
+        .with_channels(["speed", "brakes_temperature", "tyres_pressure_fl"])
+        .with_cars([1, 19]);
+
+    assert_eq!(exporter.write_packet(&laps).unwrap(), 0);
+    assert_eq!(exporter.write_packet(&telemetry).unwrap(), 2);
+
+    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    let data = &telemetry.car_telemetry.as_ref().unwrap().data[19];
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        "session_time,frame_identifier,vehicle_index,speed,brakes_temperature_rl,\
+         brakes_temperature_rr,brakes_temperature_fl,brakes_temperature_fr,\
+         tyres_pressure_fl"
+    );
+    assert_eq!(lines[1].split(',').nth(2), Some("1"));
+    assert_eq!(
+        lines[2],
+        format!(
+            "{},{},19,{},{},{},{},{},{}",
+            telemetry.header.session_time,
+            telemetry.header.frame_identifier,
+            data.speed,
+            data.brakes_temperature[0],
+            data.brakes_temperature[1],
+            data.brakes_temperature[2],
+            data.brakes_temperature[3],
+            data.tyres_pressure[2]
+        )
+    );
+}
+
+#[test]
+fn header_matches_row_width() {
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let mut exporter = CsvExporter::new(Vec::new(), CsvSource::Laps);
+
+    assert_eq!(exporter.write_packet(&laps).unwrap(), 22);
+    assert_eq!(exporter.write_packet(&laps).unwrap(), 22);
+
+    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+    let widths: Vec<usize> = csv.lines().map(|line| line.split(',').count()).collect();
+
+    assert_eq!(widths.len(), 45);
+    assert!(widths.iter().all(|&width| width == 36));
+    assert!(csv.lines().nth(20).unwrap().contains(",Active,"));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::Sector;
+use f1_game_packet_parser::damage::{DamageComponent, DamageEventKind, DamageMonitor};
+
+mod common;
+
+use common::packet;
+
+#[test]
+fn damage_after_collision() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let collision = packet(include_bytes!("data/2024-03-COLL.bin"));
+    let mut car_damage = packet(include_bytes!("data/2024-10.bin"));
+    let mut monitor = DamageMonitor::new();
+
+    laps.laps.as_mut().unwrap().data[19].current_lap_num = 3;
+    laps.laps.as_mut().unwrap().data[19].sector = Sector::Second;
+    car_damage.header.session_time = 11.5;
+
+    assert_eq!(monitor.update(&laps), vec![]);
+    assert_eq!(monitor.update(&car_damage), vec![]);
+    assert_eq!(monitor.update(&collision), vec![]);
+
+    let data = car_damage.car_damage.as_mut().unwrap();
+    data.data[19].front_left_wing_damage = 25;
+    data.data[1].drs_fault = true;
+    car_damage.header.session_time = 12.0;
+
+    let events = monitor.update(&car_damage);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].vehicle_index, 1);
+    assert_eq!(events[0].kind, DamageEventKind::DrsFault);
+    assert_eq!(events[0].collision_with, Some(19));
+    assert_eq!(events[1].vehicle_index, 19);
+    assert_eq!(
+        events[1].kind,
+        DamageEventKind::Increased {
+            component: DamageComponent::FrontLeftWing,
+            from: 0,
+            to: 25
+        }
+    );
+    assert_eq!(events[1].lap_num, 3);
+    assert_eq!(events[1].sector, Sector::Second);
+    assert_eq!(events[1].collision_with, Some(1));
+    assert_eq!(
+        events[1].to_string(),
+        "Car 19: front-left wing +25% (lap 3, sector 2) after contact with car 1"
+    );
+    assert_eq!(monitor.events(), events);
+}
+
+#[test]
+fn small_and_late_changes() {
+    let collision = packet(include_bytes!("data/2024-03-COLL.bin"));
+    let mut car_damage = packet(include_bytes!("data/2024-10.bin"));
+    let mut monitor = DamageMonitor::new().with_min_increase(5);
+
+    monitor.update(&car_damage);
+    monitor.update(&collision);
+
+    let data = &mut car_damage.car_damage.as_mut().unwrap().data[19];
+    data.engine_ice_wear = 2;
+    data.tyres_damage[2] = 10;
+    data.engine_blown = true;
+    car_damage.header.session_time = 20.0;
+
+    let events = monitor.update(&car_damage);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].to_string(), "Car 19: front left tyre +10% (lap 0, sector 1)");
+    assert_eq!(events[1].kind, DamageEventKind::EngineBlown);
+    assert!(events.iter().all(|event| event.collision_with.is_none()));
+}
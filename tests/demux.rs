@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::demux::{SessionBoundary, SessionDemux};
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+fn packet(data: &[u8], session_uid: u64) -> F1Packet {
+    let mut packet = common::packet(data);
+
+    packet.header.session_uid = session_uid;
+    packet
+}
+
+fn session(session_uid: u64, weekend: u32, session: u32) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2024-01.bin"), session_uid);
+    let session_packet = packet.session.as_mut().unwrap();
+
+    session_packet.season_link_identifier = 1;
+    session_packet.weekend_link_identifier = weekend;
+    session_packet.session_link_identifier = session;
+    packet
+}
+
+#[test]
+fn routes_interleaved_sessions() {
+    let mut demux = SessionDemux::<Vec<F1Packet>>::new();
+    let telemetry = include_bytes!("data/2024-06.bin");
+
+    assert_eq!(
+        demux.update(&packet(include_bytes!("data/2024-03-SSTA.bin"), 1)),
+        [SessionBoundary::Discovered(1), SessionBoundary::Started(1)]
+    );
+    assert_eq!(demux.update(&packet(telemetry, 2)), [SessionBoundary::Discovered(2)]);
+    assert_eq!(demux.update(&packet(telemetry, 1)), []);
+    assert_eq!(demux.update(&packet(telemetry, 2)), []);
+    assert_eq!(
+        demux.update(&packet(include_bytes!("data/2024-03-SEND.bin"), 1)),
+        [SessionBoundary::Ended(1)]
+    );
+
+    let first = demux.session(1).unwrap();
+
+    assert_eq!(first.sink.len(), 3);
+    assert_eq!(first.info.num_packets, 3);
+    assert!(first.info.started_at.is_some() && first.info.ended_at.is_some());
+    assert!(first.sink.iter().all(|packet| packet.header.session_uid == 1));
+
+    let second = demux.remove(2).unwrap();
+
+    assert_eq!(second.sink.len(), 2);
+    assert_eq!(second.info.started_at, None);
+    assert!(demux.session(2).is_none());
+    assert_eq!(demux.sessions().count(), 1);
+}
+
+#[test]
+fn builds_weekend_tree() {
+    let mut demux = SessionDemux::<Vec<F1Packet>>::new();
+
+    demux.update(&session(10, 100, 1));
+    demux.update(&session(11, 100, 2));
+    // Restarted qualifying keeps its session link identifier.
+    demux.update(&session(12, 100, 2));
+    demux.update(&session(13, 200, 3));
+    demux.update(&packet(include_bytes!("data/2024-06.bin"), 14));
+
+    let tree = demux.weekend_tree();
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].season_link_identifier, 1);
+    assert_eq!(tree[0].weekends.len(), 2);
+
+    let weekend = &tree[0].weekends[0];
+
+    assert_eq!(weekend.weekend_link_identifier, 100);
+    assert_eq!(weekend.sessions.len(), 2);
+    assert_eq!(weekend.sessions[0].session_uids, [10]);
+    assert_eq!(weekend.sessions[1].session_uids, [11, 12]);
+    assert_eq!(tree[0].weekends[1].sessions[0].session_uids, [13]);
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::ers::ErsTracker;
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::{packet, PLAYER};
+
+fn laps(lap_num: u8, lap_distance: f32) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2024-02.bin"));
+    let lap_data = &mut packet.laps.as_mut().unwrap().data[PLAYER];
+
+    lap_data.current_lap_num = lap_num;
+    lap_data.lap_distance = lap_distance;
+    packet
+}
+
+fn status(
+    store: f32,
+    harvested_mguk: f32,
+    harvested_mguh: f32,
+    deployed: f32,
+) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2024-07.bin"));
+    let status = &mut packet.car_status.as_mut().unwrap().data[PLAYER];
+
+    status.ers_store_energy = store;
+    status.ers_harvested_this_lap_mguk = harvested_mguk;
+    status.ers_harvested_this_lap_mguh = harvested_mguh;
+    status.ers_deployed_this_lap = deployed;
+    packet
+}
+
+fn first_lap(tracker: &mut ErsTracker) {
+    tracker.update(&laps(1, 50.0));
+    tracker.update(&status(4_000_000.0, 0.0, 0.0, 0.0));
+    tracker.update(&laps(1, 150.0));
+    tracker.update(&status(3_970_000.0, 50_000.0, 20_000.0, 100_000.0));
+    tracker.update(&laps(1, 250.0));
+    tracker.update(&status(3_810_000.0, 80_000.0, 30_000.0, 300_000.0));
+}
+
+fn assert_first_lap(tracker: &ErsTracker) {
+    let lap = &tracker.laps(PLAYER)[0];
+
+    assert_eq!(lap.lap_num, 1);
+    assert_eq!(lap.store_energy, 3_810_000.0);
+    assert_eq!(lap.deployed, 300_000.0);
+    assert_eq!(lap.net_balance, -190_000.0);
+    assert_eq!(lap.deployment_by_distance, vec![0.0, 100_000.0, 200_000.0]);
+    assert!(lap.unused_energy);
+}
+
+#[test]
+fn counters_reset_before_lap_change() {
+    let mut tracker = ErsTracker::new();
+
+    first_lap(&mut tracker);
+
+    let completed = tracker.update(&status(3_800_000.0, 0.0, 0.0, 0.0));
+
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].0, PLAYER);
+    assert_first_lap(&tracker);
+
+    // Energy deployed before the lap number changes belongs to the new lap.
+    tracker.update(&status(3_750_000.0, 0.0, 0.0, 50_000.0));
+    assert_eq!(tracker.update(&laps(2, 10.0)), []);
+    tracker.update(&status(3_700_000.0, 0.0, 0.0, 100_000.0));
+    tracker.update(&laps(3, 10.0));
+
+    let second_lap = &tracker.laps(PLAYER)[1];
+
+    assert_eq!(tracker.laps(PLAYER).len(), 2);
+    assert_eq!(second_lap.lap_num, 2);
+    assert_eq!(second_lap.deployed, 100_000.0);
+    assert_eq!(second_lap.deployment_by_distance, vec![100_000.0]);
+}
+
+#[test]
+fn counters_reset_after_lap_change() {
+    let mut tracker = ErsTracker::new();
+
+    first_lap(&mut tracker);
+    tracker.update(&laps(2, 10.0));
+    assert_first_lap(&tracker);
+
+    tracker.update(&status(3_800_000.0, 0.0, 0.0, 0.0));
+    tracker.update(&status(200_000.0, 100_000.0, 0.0, 3_700_000.0));
+    tracker.update(&laps(3, 10.0));
+
+    let second_lap = &tracker.laps(PLAYER)[1];
+
+    assert_eq!(second_lap.deployed, 3_700_000.0);
+    assert!(!second_lap.unused_energy);
+    assert_eq!(tracker.laps_with_unused_energy(PLAYER).len(), 1);
+}
+
+#[test]
+fn flashbacks_dont_complete_laps() {
+    let mut tracker = ErsTracker::new();
+
+    first_lap(&mut tracker);
+
+    let mut lap_data = laps(1, 300.0);
+
+    lap_data.header.session_time = 60.0;
+    tracker.update(&lap_data);
+
+    // A flashback to earlier in the lap takes the counters back down.
+    let mut rewound = status(3_900_000.0, 10_000.0, 0.0, 100_000.0);
+
+    rewound.header.session_time = 30.0;
+    assert_eq!(tracker.update(&rewound), []);
+    assert_eq!(tracker.laps(PLAYER), []);
+}
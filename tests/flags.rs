@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::{MarshalZoneFlag, Sector, VehicleFiaFlag};
+use f1_game_packet_parser::flags::{FlagEventKind, FlagMonitor};
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet;
+
+fn session(yellow_zones: &[usize]) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2022-01.bin"));
+
+    for &zone_index in yellow_zones {
+        packet.session.as_mut().unwrap().marshal_zones[zone_index].zone_flag =
+            MarshalZoneFlag::Yellow;
+    }
+
+    packet
+}
+
+fn laps(sector: Sector, lap_distance: f32) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2022-02.bin"));
+    let lap_data = &mut packet.laps.as_mut().unwrap().data[0];
+
+    lap_data.sector = sector;
+    lap_data.lap_distance = lap_distance;
+    packet
+}
+
+#[test]
+fn zone_flags_by_sector_and_distance() {
+    let mut monitor = FlagMonitor::new();
+
+    assert_eq!(monitor.update(&session(&[])), vec![]);
+
+    monitor.update(&laps(Sector::First, 1000.0));
+    monitor.update(&laps(Sector::Second, 1800.0));
+    monitor.update(&laps(Sector::Third, 3600.0));
+    assert_eq!(monitor.sector_starts(), [Some(1800.0), Some(3600.0)]);
+
+    let events = monitor.update(&session(&[3]));
+
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        events[0].kind,
+        FlagEventKind::Zone { zone, previous: MarshalZoneFlag::None }
+            if zone.zone_index == 3 && zone.sector == Some(Sector::First)
+    ));
+    assert_eq!(events[0].to_string(), "Zone 3 (1372-1734 m, sector 1): None -> Yellow");
+    assert_eq!(monitor.sector_flag(Sector::First), MarshalZoneFlag::Yellow);
+    assert_eq!(monitor.sector_flag(Sector::Second), MarshalZoneFlag::None);
+    assert_eq!(monitor.approaching_yellow(0, 500.0), None);
+
+    monitor.update(&laps(Sector::First, 1200.0));
+
+    let approaching = monitor.approaching_yellow(0, 500.0).unwrap();
+
+    assert_eq!(approaching.zone.zone_index, 3);
+    assert!((approaching.distance - 172.4).abs() < 0.1);
+
+    monitor.update(&laps(Sector::First, 1500.0));
+    assert_eq!(monitor.approaching_yellow(0, 0.0).unwrap().distance, 0.0);
+
+    // The first zone starts before the line and wraps around into the next lap.
+    monitor.update(&session(&[0]));
+    monitor.update(&laps(Sector::First, 100.0));
+    assert_eq!(monitor.approaching_yellow(0, 0.0).unwrap().zone.zone_index, 0);
+}
+
+#[test]
+fn car_flag_changes() {
+    let mut car_status = packet(include_bytes!("data/2022-07.bin"));
+    let mut monitor = FlagMonitor::new();
+
+    assert_eq!(monitor.update(&car_status), vec![]);
+
+    car_status.car_status.as_mut().unwrap().data[3].vehicle_fia_flag =
+        VehicleFiaFlag::Blue;
+    car_status.header.session_time = 42.0;
+
+    let events = monitor.update(&car_status);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].session_time, 42.0);
+    assert!(matches!(
+        events[0].kind,
+        FlagEventKind::Car { vehicle_index: 3, flag: VehicleFiaFlag::Blue, .. }
+    ));
+    assert_eq!(monitor.car_flag(3), Some(VehicleFiaFlag::Blue));
+    assert_eq!(monitor.car_flag(25), None);
+}
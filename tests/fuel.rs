@@ -0,0 +1,123 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::FuelMix;
+use f1_game_packet_parser::fuel::FuelAnalyser;
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::{packet, PLAYER};
+
+fn with_fuel(packet: &F1Packet, fuel_in_tank: f32, fuel_mix: FuelMix) -> F1Packet {
+    let mut packet = packet.clone();
+    let status = &mut packet.car_status.as_mut().unwrap().data[PLAYER];
+
+    status.fuel_in_tank = fuel_in_tank;
+    status.fuel_mix = fuel_mix;
+    packet
+}
+
+fn with_lap(packet: &F1Packet, lap_num: u8) -> F1Packet {
+    let mut packet = packet.clone();
+    packet.laps.as_mut().unwrap().data[PLAYER].current_lap_num = lap_num;
+    packet
+}
+
+fn with_lap_distance(packet: &F1Packet, lap_distance: f32) -> F1Packet {
+    let mut packet = packet.clone();
+    packet.laps.as_mut().unwrap().data[PLAYER].lap_distance = lap_distance;
+    packet
+}
+
+#[test]
+fn lap_usage_split_by_mix() {
+    let laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut analyser = FuelAnalyser::new();
+
+    analyser.update(&packet(include_bytes!("data/2024-01.bin")));
+    analyser.update(&with_lap_distance(&laps, -50.0));
+    analyser.update(&with_fuel(&car_status, 13.0, FuelMix::Standard));
+    analyser.update(&with_fuel(&car_status, 12.0, FuelMix::Rich));
+    analyser.update(&with_fuel(&car_status, 10.5, FuelMix::Rich));
+    analyser.update(&with_lap(&laps, 2));
+
+    let usage = &analyser.laps(PLAYER)[0];
+
+    assert_eq!(usage.lap_num, 1);
+    assert!((usage.fuel_used - 2.5).abs() < 1e-4);
+    assert!((usage.fuel_used_by_mix[&FuelMix::Standard] - 1.0).abs() < 1e-4);
+    assert!((usage.fuel_used_by_mix[&FuelMix::Rich] - 1.5).abs() < 1e-4);
+    assert_eq!(analyser.average_burn_per_lap(PLAYER), Some(usage.fuel_used));
+}
+
+#[test]
+fn warns_once_when_projection_goes_negative() {
+    let laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut analyser = FuelAnalyser::new();
+
+    analyser.update(&packet(include_bytes!("data/2024-01.bin")));
+    analyser.update(&with_lap_distance(&laps, -50.0));
+    analyser.update(&with_fuel(&car_status, 13.0, FuelMix::Standard));
+    analyser.update(&with_fuel(&car_status, 10.0, FuelMix::Standard));
+    analyser.update(&with_lap(&laps, 2));
+
+    let warnings = analyser.update(&with_fuel(&car_status, 10.0, FuelMix::Standard));
+    let player_warning = warnings.iter().find(|w| w.projection.vehicle_index == PLAYER);
+    let projection = player_warning.unwrap().projection;
+    let target = projection.lift_and_coast.unwrap();
+
+    assert!(projection.fuel_at_finish < 0.0);
+    assert!((target.fuel_to_save + projection.fuel_at_finish).abs() < 1e-4);
+    assert!(target.target_burn_per_lap * projection.laps_remaining <= 10.0 + 1e-3);
+
+    let warnings = analyser.update(&with_fuel(&car_status, 10.0, FuelMix::Standard));
+    assert!(warnings.iter().all(|w| w.projection.vehicle_index != PLAYER));
+}
+
+#[test]
+fn flashback_discards_laps() {
+    let laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut analyser = FuelAnalyser::new();
+
+    analyser.update(&with_lap_distance(&laps, -50.0));
+    analyser.update(&with_fuel(&car_status, 13.0, FuelMix::Standard));
+    analyser.update(&with_fuel(&car_status, 11.0, FuelMix::Standard));
+    analyser.update(&with_lap(&laps, 2));
+    assert_eq!(analyser.laps(PLAYER).len(), 1);
+
+    analyser.update(&with_lap(&laps, 1));
+    assert!(analyser.laps(PLAYER).is_empty());
+    assert_eq!(analyser.projection(PLAYER), None);
+
+    // The rewound lap is only partially observed, so it's not recorded again.
+    analyser.update(&with_fuel(&car_status, 10.0, FuelMix::Standard));
+    analyser.update(&with_lap(&laps, 2));
+    assert!(analyser.laps(PLAYER).is_empty());
+}
+
+#[test]
+fn skips_lap_joined_mid_way() {
+    let laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut analyser = FuelAnalyser::new();
+
+    analyser.update(&packet(include_bytes!("data/2024-01.bin")));
+    analyser.update(&with_lap(&laps, 3));
+    analyser.update(&with_fuel(&car_status, 13.0, FuelMix::Standard));
+    analyser.update(&with_fuel(&car_status, 12.5, FuelMix::Standard));
+    analyser.update(&with_lap(&laps, 4));
+
+    assert!(analyser.laps(PLAYER).is_empty());
+    assert_eq!(analyser.average_burn_per_lap(PLAYER), None);
+
+    analyser.update(&with_fuel(&car_status, 10.5, FuelMix::Standard));
+    analyser.update(&with_lap(&laps, 5));
+
+    let usage = &analyser.laps(PLAYER)[0];
+
+    assert_eq!(usage.lap_num, 4);
+    assert!((usage.fuel_used - 2.0).abs() < 1e-4);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::PitStatus;
+use f1_game_packet_parser::gaps::GapCalculator;
+use f1_game_packet_parser::packets::laps::LapData;
+use f1_game_packet_parser::parse;
+
+const SPEED: f32 = 50.0;
+
+fn lap_data() -> Vec<LapData> {
+    let data = include_bytes!("data/2024-02.bin");
+    parse(data).unwrap().laps.unwrap().data
+}
+
+fn calculator_after(seconds: u32, tweak: impl Fn(&mut [LapData])) -> GapCalculator {
+    let session = parse(include_bytes!("data/2024-01.bin")).unwrap();
+    let initial = lap_data();
+    let mut calculator = GapCalculator::new();
+
+    calculator.update(&session);
+
+    for step in 0..=seconds * 2 {
+        let session_time = step as f32 * 0.5;
+        let mut data = initial.clone();
+
+        for car in data.iter_mut() {
+            car.total_distance += SPEED * session_time;
+            car.lap_distance += SPEED * session_time;
+        }
+
+        tweak(&mut data);
+        calculator.push_lap_data(session_time, &data);
+    }
+
+    calculator
+}
+
+#[test]
+fn gap_between_cars() {
+    let calculator = calculator_after(10, |_| ());
+    let expected = (243.94902 - 235.87796) / SPEED;
+
+    assert!((calculator.gap(1, 19).unwrap() - expected).abs() < 1e-3);
+    assert!((calculator.gap(19, 1).unwrap() + expected).abs() < 1e-3);
+    assert_eq!(calculator.gap(1, 20), None);
+    assert_eq!(calculator.leader_index(), Some(19));
+    assert_eq!(calculator.gap_matrix()[1][19], calculator.gap(1, 19));
+}
+
+#[test]
+fn gap_outside_history_is_unknown() {
+    let calculator = calculator_after(1, |_| ());
+
+    assert_eq!(calculator.gap(18, 19), None);
+}
+
+#[test]
+fn laps_down() {
+    let calculator = calculator_after(2, |data| data[0].total_distance -= 5408.0);
+    let gaps = calculator.car_gaps();
+    let car = gaps.iter().find(|gap| gap.vehicle_index == 0).unwrap();
+
+    assert_eq!(car.laps_down, 1);
+    assert_eq!(calculator.laps_down(1), Some(0));
+    assert_eq!(gaps[0].vehicle_index, 19);
+}
+
+#[test]
+fn intervals_skip_pit_lane_cars() {
+    let calculator = calculator_after(10, |data| data[4].pit_status = PitStatus::Pitting);
+
+    let ahead = calculator.interval_ahead(1).unwrap();
+    let behind = calculator.interval_behind(1).unwrap();
+
+    assert_eq!(ahead.vehicle_index, 19);
+    assert_eq!(behind.vehicle_index, 17);
+    assert!((behind.seconds - (235.87796 - 219.9108) / SPEED).abs() < 1e-3);
+    assert_eq!(calculator.interval_ahead(4), None);
+}
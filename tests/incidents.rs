@@ -0,0 +1,244 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::{InfringementType, PenaltyType};
+use f1_game_packet_parser::incidents::{IncidentKind, IncidentTracker};
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet;
+
+fn at_frame(
+    packet: &mut F1Packet,
+    session_time: f32,
+    frame_identifier: u32,
+    overall_frame_identifier: u32,
+) {
+    packet.header.session_time = session_time;
+    packet.header.frame_identifier = frame_identifier;
+    packet.header.overall_frame_identifier = overall_frame_identifier;
+}
+
+#[test]
+fn collision_and_penalty_with_car_context() {
+    let laps = packet(include_bytes!("data/2024-02.bin"));
+    let motion = packet(include_bytes!("data/2024-00.bin"));
+    let mut car_telemetry = packet(include_bytes!("data/2024-06.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    car_telemetry.car_telemetry.as_mut().unwrap().data[19].speed = 142;
+
+    assert_eq!(tracker.update(&laps), vec![]);
+    assert_eq!(tracker.update(&motion), vec![]);
+    assert_eq!(tracker.update(&car_telemetry), vec![]);
+
+    let collision = tracker.update(&packet(include_bytes!("data/2024-03-COLL.bin")));
+
+    assert_eq!(collision.len(), 1);
+    assert_eq!(collision[0].kind, IncidentKind::Collision);
+    assert_eq!(collision[0].car.vehicle_index, 19);
+    assert_eq!(collision[0].car.car_position, 1);
+    assert_eq!(collision[0].car.speed, Some(142));
+    assert!(collision[0].car.world_position.is_some());
+    assert_eq!(collision[0].other_car.unwrap().vehicle_index, 1);
+    assert_eq!(collision[0].other_car.unwrap().car_position, 2);
+
+    let penalty = tracker.update(&packet(include_bytes!("data/2024-03-PENA.bin")));
+
+    assert_eq!(
+        penalty[0].kind,
+        IncidentKind::Penalty {
+            penalty_type: PenaltyType::Warning,
+            infringement_type: InfringementType::SmallCollision,
+            time: 255,
+            places_gained: 0,
+        }
+    );
+    assert_eq!(
+        penalty[0].to_string(),
+        "Lap 1, car 19 (P1, 142 km/h): Warning for SmallCollision \
+         involving car 1 (P2, 0 km/h)"
+    );
+    assert_eq!(tracker.incidents().len(), 2);
+    assert_eq!(tracker.driver_incidents(1).count(), 2);
+    assert_eq!(tracker.driver_incidents(0).count(), 0);
+}
+
+#[test]
+fn lap_data_counter_increases() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    assert_eq!(tracker.update(&laps), vec![]);
+
+    let lap_data = &mut laps.laps.as_mut().unwrap().data[1];
+    lap_data.total_warnings = 2;
+    lap_data.corner_cutting_warnings = 1;
+    lap_data.num_unserved_drive_through_pens = 1;
+    laps.header.session_time = 30.0;
+
+    let kinds: Vec<IncidentKind> =
+        tracker.update(&laps).iter().map(|incident| incident.kind).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            IncidentKind::Warning { total_warnings: 2 },
+            IncidentKind::CornerCuttingWarning { corner_cutting_warnings: 1 },
+            IncidentKind::UnservedDriveThrough { num_unserved: 1 },
+        ]
+    );
+
+    // Counters going back down (e.g. after a flashback) aren't incidents.
+    laps.laps.as_mut().unwrap().data[1].num_unserved_drive_through_pens = 0;
+    assert_eq!(tracker.update(&laps), vec![]);
+
+    let served = tracker.update(&packet(include_bytes!("data/2024-03-DTSV.bin")));
+
+    assert_eq!(served[0].kind, IncidentKind::DriveThroughServed);
+    assert_eq!(served[0].car.vehicle_index, 1);
+    assert!(served[0].other_car.is_none());
+    assert_eq!(tracker.driver_incidents(1).count(), 4);
+}
+
+#[test]
+fn flashbacks_drop_rewound_incidents() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    at_frame(&mut laps, 10.0, 600, 600);
+    tracker.update(&laps);
+    at_frame(&mut laps, 30.0, 1800, 1800);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    laps.laps.as_mut().unwrap().data[1].num_unserved_stop_go_pens = 1;
+    assert_eq!(tracker.update(&laps).len(), 2);
+
+    // The flashback rewinds the session to before the warning and penalty.
+    at_frame(&mut laps, 20.0, 1200, 1801);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 0;
+    laps.laps.as_mut().unwrap().data[1].num_unserved_stop_go_pens = 0;
+    assert_eq!(tracker.update(&laps), vec![]);
+    assert_eq!(tracker.incidents(), []);
+
+    // Replaying the same incidents logs them once.
+    at_frame(&mut laps, 30.0, 1800, 2401);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    laps.laps.as_mut().unwrap().data[1].num_unserved_stop_go_pens = 1;
+    assert_eq!(tracker.update(&laps).len(), 2);
+    assert_eq!(tracker.incidents().len(), 2);
+
+    // Counters that don't go back down after a flashback are logged again.
+    at_frame(&mut laps, 25.0, 1500, 2402);
+
+    let kinds: Vec<IncidentKind> =
+        tracker.update(&laps).iter().map(|incident| incident.kind).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            IncidentKind::Warning { total_warnings: 1 },
+            IncidentKind::UnservedStopGo { num_unserved: 1 },
+        ]
+    );
+    assert_eq!(tracker.incidents().len(), 2);
+}
+
+#[test]
+fn late_packets_arent_flashbacks() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut collision = packet(include_bytes!("data/2024-03-COLL.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    at_frame(&mut laps, 10.0, 600, 600);
+    tracker.update(&laps);
+    at_frame(&mut laps, 10.02, 601, 601);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    assert_eq!(tracker.update(&laps).len(), 1);
+
+    // A collision event of the previous frame arrives after its lap data.
+    at_frame(&mut collision, 10.0, 600, 600);
+    assert_eq!(tracker.update(&collision).len(), 1);
+
+    // So does an older lap data packet, whose counters are out of date.
+    at_frame(&mut laps, 10.0, 600, 600);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 0;
+    assert_eq!(tracker.update(&laps), vec![]);
+
+    at_frame(&mut laps, 10.04, 602, 602);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    assert_eq!(tracker.update(&laps), vec![]);
+    assert_eq!(tracker.incidents().len(), 2);
+}
+
+#[test]
+fn late_packets_before_2023_use_a_threshold() {
+    let mut laps = packet(include_bytes!("data/2022-02.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    at_frame(&mut laps, 30.0, 1800, 0);
+    tracker.update(&laps);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    at_frame(&mut laps, 30.02, 1801, 0);
+    assert_eq!(tracker.update(&laps).len(), 1);
+
+    at_frame(&mut laps, 30.0, 1800, 0);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 0;
+    tracker.update(&laps);
+    assert_eq!(tracker.incidents().len(), 1);
+
+    at_frame(&mut laps, 20.0, 1200, 0);
+    tracker.update(&laps);
+    assert_eq!(tracker.incidents(), []);
+}
+
+#[test]
+fn positions_after_flashbacks_come_from_new_lap_data() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut collision = packet(include_bytes!("data/2024-03-COLL.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    at_frame(&mut laps, 10.0, 600, 600);
+    tracker.update(&laps);
+    at_frame(&mut laps, 90.0, 5400, 5400);
+    laps.laps.as_mut().unwrap().data[19].current_lap_num = 2;
+    tracker.update(&laps);
+
+    at_frame(&mut collision, 80.0, 4800, 5401);
+
+    let rewound = tracker.update(&collision);
+
+    assert_eq!(rewound[0].car.car_position, 0);
+    assert_eq!(rewound[0].car.lap_num, 0);
+
+    at_frame(&mut laps, 80.02, 4801, 5402);
+    tracker.update(&laps);
+    at_frame(&mut collision, 80.02, 4801, 5402);
+
+    let collision = tracker.update(&collision);
+
+    assert_eq!(collision[0].car.car_position, 1);
+    assert_eq!(collision[0].car.lap_num, 2);
+}
+
+#[test]
+fn new_sessions_reset_the_log() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut tracker = IncidentTracker::new();
+
+    laps.header.session_time = 600.0;
+    tracker.update(&laps);
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    assert_eq!(tracker.update(&laps).len(), 1);
+
+    // The next session's clock restarts, which isn't a flashback.
+    laps.header.session_uid += 1;
+    laps.header.session_time = 0.0;
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 0;
+    assert_eq!(tracker.update(&laps), vec![]);
+    assert_eq!(tracker.incidents(), []);
+
+    laps.header.session_time = 30.0;
+    laps.laps.as_mut().unwrap().data[1].total_warnings = 1;
+    assert_eq!(tracker.update(&laps).len(), 1);
+    assert_eq!(tracker.incidents().len(), 1);
+}
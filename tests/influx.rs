@@ -0,0 +1,137 @@
+#![cfg(feature = "json")]
+
+use f1_game_packet_parser::export::influx::InfluxEncoder;
+use f1_game_packet_parser::packets::event::EventDetails;
+use f1_game_packet_parser::parse;
+
+#[test]
+fn points_with_tags_and_timestamps() {
+    let session = parse(include_bytes!("data/2024-01.bin")).unwrap();
+    let participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let mut encoder = InfluxEncoder::new().with_session_start(1_700_000_000_000_000_000);
+
+    telemetry.header.session_time = 1.5;
+    encoder.encode(&session);
+    encoder.encode(&participants);
+
+    let encoded = encoder.encode(&telemetry);
+    let lines: Vec<&str> = encoded.lines().collect();
+    let data = &telemetry.car_telemetry.as_ref().unwrap().data[19];
+    let uid = telemetry.header.session_uid;
+
+    assert_eq!(lines.len(), 22);
+    assert!(lines[19].starts_with(&format!(
+        "car_telemetry,car_index=19,driver=LECLERC,session_uid={},track_id=Sakhir \
+         frame_identifier={}i,",
+        uid, telemetry.header.frame_identifier
+    )));
+    assert!(lines[19].contains(&format!(",speed={}i,", data.speed)));
+    assert!(lines[19].contains(&format!(",tyres_pressure_0={},", data.tyres_pressure[0])));
+    assert!(!lines[19].contains("surface_type"));
+    assert!(lines[19].ends_with(" 1700000001500000000"));
+}
+
+#[test]
+fn resets_for_new_sessions() {
+    let session = parse(include_bytes!("data/2024-01.bin")).unwrap();
+    let participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let mut encoder = InfluxEncoder::new().with_session_start(1_700_000_000_000_000_000);
+
+    telemetry.header.session_time = 3600.0;
+    encoder.encode(&session);
+    encoder.encode(&participants);
+    encoder.encode(&telemetry);
+
+    telemetry.header.session_uid += 1;
+    telemetry.header.session_time = 1.5;
+
+    let encoded = encoder.encode(&telemetry);
+    let timestamp: i64 =
+        encoded.lines().next().unwrap().rsplit(' ').next().unwrap().parse().unwrap();
+
+    assert!(encoded.starts_with("car_telemetry,car_index=0,session_uid="));
+    assert!(!encoded.contains("track_id="));
+    assert!(timestamp > 1_700_000_000_000_000_000 + 3_600_000_000_000);
+}
+
+#[test]
+fn events_and_escaping() {
+    let collision = parse(include_bytes!("data/2024-03-COLL.bin")).unwrap();
+    let mut participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut encoder = InfluxEncoder::new().with_session_start(0);
+
+    participants.participants.as_mut().unwrap().data[1].name =
+        "Max Verstappen, Jr".to_owned();
+    participants.participants.as_mut().unwrap().data[2].name =
+        "C:\\Drivers\nNorris\r".to_owned();
+    participants.participants.as_mut().unwrap().data[3].name = "\n".to_owned();
+    participants.participants.as_mut().unwrap().data[4].name = "Player".to_owned();
+
+    let encoded = encoder.encode(&participants);
+    let encoded_collision = encoder.encode(&collision);
+
+    assert!(encoded.contains(",driver=Max\\ Verstappen\\,\\ Jr,"));
+    assert!(encoded.contains(",driver=C:\\\\DriversNorris,"));
+    assert!(encoded.contains("participants,car_index=3,session_uid="));
+    assert!(encoded.contains("participants,car_index=4,driver=Sergio\\ Perez,"));
+    assert_eq!(encoded.lines().count(), 20);
+    assert!(encoded_collision.starts_with(&format!(
+        "event,car_index=19,code=COLL,driver=LECLERC,session_uid={} \
+         frame_identifier={}i,details_other_vehicle_index=1i ",
+        collision.header.session_uid, collision.header.frame_identifier
+    )));
+}
+
+#[test]
+fn single_car_payloads_get_car_tags() {
+    let participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let session_history = parse(include_bytes!("data/2024-11.bin")).unwrap();
+    let tyre_sets = parse(include_bytes!("data/2024-12.bin")).unwrap();
+    let penalty = parse(include_bytes!("data/2024-03-PENA.bin")).unwrap();
+    let mut encoder = InfluxEncoder::new().with_session_start(0);
+
+    encoder.encode(&participants);
+
+    let driver = |vehicle_index: usize| {
+        participants.participants.as_ref().unwrap().data[vehicle_index]
+            .display_name(participants.header.packet_format)
+            .replace(' ', "\\ ")
+    };
+    let car_tags = |vehicle_index: usize| {
+        format!("car_index={},driver={}", vehicle_index, driver(vehicle_index))
+    };
+
+    let history_index = session_history.session_history.as_ref().unwrap().vehicle_index;
+    let encoded = encoder.encode(&session_history);
+
+    assert_eq!(encoded.lines().count(), 1);
+    assert!(encoded.starts_with(&format!("session_history,{},", car_tags(history_index))));
+    assert!(!encoded.contains("vehicle_index="));
+
+    let sets_index = tyre_sets.tyre_sets.as_ref().unwrap().vehicle_index;
+    let encoded = encoder.encode(&tyre_sets);
+
+    assert_eq!(encoded.lines().count(), 20);
+    assert!(encoded.lines().nth(3).unwrap().starts_with(&format!(
+        "tyre_sets,{},session_uid={},set_index=3 ",
+        car_tags(sets_index),
+        tyre_sets.header.session_uid
+    )));
+    assert!(!encoded.contains("vehicle_index="));
+
+    let encoded = encoder.encode(&penalty);
+    let penalty_index = match penalty.event.as_ref().unwrap().details {
+        EventDetails::Penalty { vehicle_index, .. } => vehicle_index,
+        _ => unreachable!(),
+    };
+
+    assert!(encoded.starts_with(&format!(
+        "event,car_index={},code=PENA,driver={},",
+        penalty_index,
+        driver(penalty_index)
+    )));
+    assert!(!encoded.contains("details_vehicle_index="));
+    assert!(encoded.contains("details_other_vehicle_index="));
+}
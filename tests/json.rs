@@ -0,0 +1,85 @@
+#![cfg(feature = "json")]
+
+use f1_game_packet_parser::json::{self, SCHEMA_VERSION};
+use f1_game_packet_parser::parse;
+
+const SAMPLES: [&[u8]; 5] = [
+    include_bytes!("data/2024-01.bin"),
+    include_bytes!("data/2024-03-COLL.bin"),
+    include_bytes!("data/2024-06.bin"),
+    include_bytes!("data/2024-12.bin"),
+    include_bytes!("data/2023-03-BUTN.bin"),
+];
+
+#[test]
+fn versioned_round_trip() {
+    for sample in SAMPLES {
+        let packet = parse(sample).unwrap();
+        let encoded = json::to_string(&packet).unwrap();
+
+        assert_eq!(json::from_str(&encoded).unwrap(), packet);
+    }
+
+    let collision = parse(include_bytes!("data/2024-03-COLL.bin")).unwrap();
+    let value: serde_json::Value =
+        serde_json::from_str(&json::to_string(&collision).unwrap()).unwrap();
+
+    assert_eq!(value["schema_version"], SCHEMA_VERSION);
+    assert_eq!(value["packet_type"], "event");
+    assert_eq!(value["payload"]["details"]["Collision"]["other_vehicle_index"], 1);
+    assert_eq!(value.as_object().unwrap().len(), 4);
+
+    let future = json::to_string(&collision).unwrap().replacen(
+        r#""schema_version":1"#,
+        r#""schema_version":2"#,
+        1,
+    );
+
+    assert_eq!(
+        json::from_str(&future).unwrap_err().to_string(),
+        "Unsupported schema version: 2, expected 1"
+    );
+}
+
+#[test]
+fn reads_documents_without_optional_fields() {
+    let packet = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut value: serde_json::Value =
+        serde_json::from_str(&json::to_string(&packet).unwrap()).unwrap();
+
+    // Documents written before `name_lossy` got added must still be readable.
+    for participant in value["payload"]["data"].as_array_mut().unwrap() {
+        participant.as_object_mut().unwrap().remove("name_lossy");
+    }
+
+    let decoded = json::from_str(&value.to_string()).unwrap();
+
+    assert_eq!(decoded, packet);
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn schema_is_stable() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/schema/f1-packet-v1.json");
+    let generated = serde_json::to_string_pretty(&json::schema()).unwrap() + "\n";
+
+    if std::env::var_os("UPDATE_SCHEMA").is_some() {
+        std::fs::write(path, &generated).unwrap();
+    }
+
+    // Breaking changes to the committed schema require bumping SCHEMA_VERSION.
+    assert_eq!(
+        generated,
+        std::fs::read_to_string(path).unwrap(),
+        "JSON Schema has changed, rerun with UPDATE_SCHEMA=1 to update it"
+    );
+
+    let payloads = json::payload_schemas();
+    let events = json::event_schemas();
+
+    assert_eq!(payloads.len(), 15);
+    assert_eq!(payloads["car_telemetry"].get("title").unwrap(), "F1PacketCarTelemetry");
+    assert_eq!(events["Collision"].get("required").unwrap()[0], "Collision");
+    assert_eq!(events["SessionStarted"].get("const").unwrap(), "SessionStarted");
+    assert!(events["Penalty"].get("$defs").unwrap().get("PenaltyType").is_some());
+}
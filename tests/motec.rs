@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::export::motec::{MotecLogInfo, MotecLogger};
+
+mod common;
+
+use common::packet_at;
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn logger() -> MotecLogger {
+    let info = MotecLogInfo { date: "18/10/2026".to_owned(), ..Default::default() };
+    let mut logger = MotecLogger::new().with_sample_rate(10).with_info(info);
+    let mut laps = packet_at(include_bytes!("data/2024-02.bin"), 10.0);
+    let mut telemetry = packet_at(include_bytes!("data/2024-06.bin"), 10.0);
+
+    logger.update(&packet_at(include_bytes!("data/2024-01.bin"), 10.0));
+    logger.update(&packet_at(include_bytes!("data/2024-04.bin"), 10.0));
+    logger.update(&laps);
+    logger.update(&telemetry);
+    logger.update(&packet_at(include_bytes!("data/2024-13.bin"), 10.05));
+
+    let lap_data = &mut laps.laps.as_mut().unwrap().data[19];
+    lap_data.current_lap_num += 1;
+    lap_data.current_lap_time_ms = 250;
+    lap_data.last_lap_time_ms = 93_456;
+    laps.header.session_time = 11.0;
+    telemetry.car_telemetry.as_mut().unwrap().data[19].speed = 300;
+    telemetry.header.session_time = 10.5;
+
+    logger.update(&telemetry);
+    logger.update(&laps);
+    logger
+}
+
+#[test]
+fn ld_layout() {
+    let logger = logger();
+    let mut ld = Vec::new();
+
+    logger.write_ld(&mut ld).unwrap();
+
+    let num_channels = u32_at(&ld, 86) as usize;
+    let meta_ptr = u32_at(&ld, 8) as usize;
+    let data_ptr = u32_at(&ld, 12) as usize;
+
+    assert_eq!(u32_at(&ld, 0), 0x40);
+    assert_eq!(&ld[94..104], b"18/10/2026");
+    assert_eq!(&ld[158..165], b"LECLERC");
+    assert_eq!(&ld[350..356], b"Sakhir");
+    assert_eq!(num_channels, 3 + 6 + 16 + 12);
+    assert_eq!(data_ptr, meta_ptr + num_channels * 124);
+    assert_eq!(ld.len(), data_ptr + num_channels * 11 * 4);
+
+    let speed_meta = (0..num_channels)
+        .map(|i| meta_ptr + i * 124)
+        .find(|&meta| ld[meta + 32..meta + 44].starts_with(b"Ground Speed"))
+        .unwrap();
+    let speed_data = u32_at(&ld, speed_meta + 8) as usize;
+    let speed: Vec<f32> = (0..11)
+        .map(|i| f32::from_le_bytes(u32_at(&ld, speed_data + i * 4).to_le_bytes()))
+        .collect();
+
+    assert_eq!(u32_at(&ld, speed_meta + 12), 11);
+    assert_eq!(&ld[speed_meta + 72..speed_meta + 76], b"km/h");
+    assert_eq!(speed[4], speed[0]);
+    assert_eq!(speed[5], 300.0);
+    assert_eq!(speed[10], 300.0);
+}
+
+#[test]
+fn ldx_lap_markers() {
+    let logger = logger();
+    let mut ldx = Vec::new();
+
+    logger.write_ldx(&mut ldx).unwrap();
+
+    let ldx = String::from_utf8(ldx).unwrap();
+
+    assert_eq!(logger.laps().len(), 1);
+    assert_eq!(logger.laps()[0].end_time, 0.75);
+    assert!(ldx.contains(r#"Name="Manual.1" Flags="77" Time="750000"/>"#));
+    assert!(ldx.contains(r#"<String Id="Fastest Time" Value="1:33.456"/>"#));
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "mqtt")]
+
+use f1_game_packet_parser::mqtt::{MqttField, MqttMapping, MqttPublisher, PayloadFormat};
+use f1_game_packet_parser::parse;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+type Broker = JoinHandle<(Vec<u8>, Vec<(String, String)>, usize)>;
+
+fn read_packet(stream: &mut TcpStream) -> Option<(u8, Vec<u8>)> {
+    let mut byte = [0u8; 1];
+    stream.read_exact(&mut byte).ok()?;
+
+    let packet_type = byte[0];
+    let mut len = 0;
+    let mut shift = 0;
+
+    loop {
+        stream.read_exact(&mut byte).ok()?;
+        len |= usize::from(byte[0] & 0x7f) << shift;
+        shift += 7;
+
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body).ok()?;
+    Some((packet_type, body))
+}
+
+/// Accepts a single client, replies to its CONNECT packet with the given return code
+/// and collects published (topic, payload) pairs and counts answered pings
+/// until the client disconnects.
+fn broker(return_code: u8) -> (SocketAddr, Broker) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let (_, connect) = read_packet(&mut stream).unwrap();
+        let mut published = Vec::new();
+        let mut pings = 0;
+
+        stream.write_all(&[0x20, 2, 0, return_code]).unwrap();
+
+        while let Some((packet_type, body)) = read_packet(&mut stream) {
+            match packet_type & 0xf0 {
+                0x30 => {
+                    let topic_len = usize::from(u16::from_be_bytes([body[0], body[1]]));
+                    let topic = String::from_utf8(body[2..2 + topic_len].to_vec());
+                    let payload = String::from_utf8(body[2 + topic_len..].to_vec());
+
+                    published.push((topic.unwrap(), payload.unwrap()));
+                }
+                0xc0 => {
+                    stream.write_all(&[0xd0, 0]).unwrap();
+                    pings += 1;
+                }
+                0xe0 => break,
+                _ => (),
+            }
+        }
+
+        (connect, published, pings)
+    });
+
+    (addr, handle)
+}
+
+#[test]
+fn publishes_mapped_fields_to_broker() {
+    let (addr, broker) = broker(0);
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let motion = parse(include_bytes!("data/2024-00.bin")).unwrap();
+    let collision = parse(include_bytes!("data/2024-03-COLL.bin")).unwrap();
+    let mapping = MqttMapping::new()
+        .with_topic_prefix("rig/")
+        .with_topic(MqttField::RevLightsPercent, "rig/leds/rev")
+        .without_field(MqttField::GForceLongitudinal);
+    let mut publisher = MqttPublisher::new("f1-rig")
+        .with_mapping(mapping)
+        .with_credentials("sim", Some("secret"))
+        .connect(addr)
+        .unwrap();
+
+    assert_eq!(publisher.publish(&telemetry).unwrap(), 2);
+    assert_eq!(publisher.publish(&motion).unwrap(), 1);
+    assert_eq!(publisher.publish(&collision).unwrap(), 1);
+    publisher.disconnect().unwrap();
+
+    let (connect, published, _) = broker.join().unwrap();
+    let player_telemetry = &telemetry.car_telemetry.as_ref().unwrap().data[19];
+    let player_motion = &motion.motion.as_ref().unwrap().data[19];
+
+    assert_eq!(&connect[..7], b"\x00\x04MQTT\x04");
+    assert_eq!(connect[7], 0xc2);
+    assert!(connect.ends_with(b"\x00\x03sim\x00\x06secret"));
+    assert_eq!(
+        published,
+        [
+            ("rig/speed".to_owned(), player_telemetry.speed.to_string()),
+            ("rig/leds/rev".to_owned(), player_telemetry.rev_lights_percent.to_string()),
+            ("rig/g_force_lateral".to_owned(), player_motion.g_force_lateral.to_string()),
+            ("rig/event".to_owned(), "COLL".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn json_payloads_and_refused_connections() {
+    let collision = parse(include_bytes!("data/2024-03-COLL.bin")).unwrap();
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let mapping = MqttMapping::new()
+        .with_format(PayloadFormat::Json)
+        .with_field(MqttField::Gear)
+        .with_vehicle_index(1);
+    let messages = mapping.messages(&telemetry);
+    let gear = telemetry.car_telemetry.as_ref().unwrap().data[1].gear;
+
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[1].topic, "f1/gear");
+    assert_eq!(
+        messages[1].payload,
+        format!(r#"{{"value":{},"vehicle_index":1,"session_time":0.0}}"#, gear)
+    );
+
+    let event = &mapping.messages(&collision)[0];
+
+    assert_eq!(event.topic, "f1/event");
+    assert!(event.payload.starts_with(
+        r#"{"code":"COLL","details":{"Collision":{"vehicle_index":19,"other_vehicle_index":1}}"#
+    ));
+
+    let (addr, broker) = broker(5);
+    let error = MqttPublisher::new("f1-rig").connect(addr).unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::ConnectionRefused);
+    assert!(broker.join().unwrap().1.is_empty());
+}
+
+#[test]
+fn sends_pings_while_idle() {
+    let (addr, broker) = broker(0);
+    let publisher =
+        MqttPublisher::new("f1-rig").with_keep_alive(1).connect(addr).unwrap();
+
+    // Nothing gets published, e.g. because the game is paused.
+    thread::sleep(Duration::from_millis(2500));
+    publisher.disconnect().unwrap();
+
+    assert!(broker.join().unwrap().2 >= 2);
+}
+
+#[test]
+fn keeps_reporting_lost_connections() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let broker = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        read_packet(&mut stream).unwrap();
+        stream.write_all(&[0x20, 2, 0, 0]).unwrap();
+        // The broker goes away right after accepting the connection.
+    });
+    let mut publisher = MqttPublisher::new("f1-rig").connect(addr).unwrap();
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+
+    broker.join().unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let first = publisher.publish(&telemetry).unwrap_err();
+    let second = publisher.publish(&telemetry).unwrap_err();
+
+    assert_ne!(first.kind(), ErrorKind::InvalidInput);
+    assert_eq!(second.kind(), first.kind());
+    assert_eq!(second.to_string(), first.to_string());
+}
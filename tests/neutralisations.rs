@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::SafetyCarStatus;
+use f1_game_packet_parser::neutralisations::{
+    NeutralisationChange, NeutralisationKind, NeutralisationTracker,
+};
+
+mod common;
+
+use common::{laps, packet, session};
+
+#[test]
+fn safety_car_and_red_flag_from_events() {
+    let session_data = include_bytes!("data/2024-01.bin");
+    let laps_data = include_bytes!("data/2024-02.bin");
+    let mut tracker = NeutralisationTracker::new();
+
+    tracker.update(&session(session_data, SafetyCarStatus::None, 90.0));
+    tracker.update(&laps(laps_data, 3));
+
+    let changes = tracker.update(&packet(include_bytes!("data/2024-03-SCAR.bin")));
+
+    assert!(matches!(
+        changes[..],
+        [NeutralisationChange::Started(period)]
+            if period.kind == NeutralisationKind::SafetyCar && period.start_lap == 3
+    ));
+
+    // Session packets reporting the same deployment don't start another period.
+    assert_eq!(tracker.update(&session(session_data, SafetyCarStatus::Full, 95.0)), []);
+
+    tracker.update(&laps(laps_data, 5));
+
+    let mut safety_car_in = session(session_data, SafetyCarStatus::None, 150.0);
+
+    // A counter increase arriving with the status already cleared
+    // mustn't start another safety car period.
+    safety_car_in.session.as_mut().unwrap().num_safety_car_periods += 1;
+    tracker.update(&safety_car_in);
+
+    let mut red_flag = session(session_data, SafetyCarStatus::None, 160.0);
+
+    red_flag.session.as_mut().unwrap().num_red_flag_periods = 1;
+    tracker.update(&packet(include_bytes!("data/2024-03-RDFL.bin")));
+    assert_eq!(tracker.update(&red_flag), []);
+
+    let mut lights_out = packet(include_bytes!("data/2024-03-LGOT.bin"));
+
+    lights_out.header.session_time = 400.0;
+    tracker.update(&lights_out);
+
+    let periods = tracker.periods();
+
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].end_lap, Some(5));
+    assert_eq!(periods[0].duration(), Some(150.0 - 94.151405));
+    assert_eq!(periods[1].kind, NeutralisationKind::RedFlag);
+    assert_eq!(periods[1].end_time, Some(400.0));
+    assert_eq!(tracker.active().count(), 0);
+    assert_eq!(
+        tracker.neutralised_laps().keys().copied().collect::<Vec<u8>>(),
+        [3, 4, 5]
+    );
+    assert_eq!(
+        tracker.lap_neutralisations(5).into_iter().collect::<Vec<_>>(),
+        [NeutralisationKind::SafetyCar, NeutralisationKind::RedFlag]
+    );
+    assert!(tracker.lap_neutralisations(2).is_empty());
+}
+
+#[test]
+fn periods_from_session_status() {
+    let session_data = include_bytes!("data/2022-01.bin");
+    let laps_data = include_bytes!("data/2022-02.bin");
+    let mut tracker = NeutralisationTracker::new();
+
+    tracker.update(&session(session_data, SafetyCarStatus::FormationLap, 0.0));
+    tracker.update(&laps(laps_data, 1));
+    tracker.update(&session(session_data, SafetyCarStatus::None, 80.0));
+    tracker.update(&laps(laps_data, 7));
+    tracker.update(&session(session_data, SafetyCarStatus::Virtual, 600.0));
+
+    let periods = tracker.periods();
+
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].kind, NeutralisationKind::FormationLap);
+    assert_eq!(periods[0].to_string(), "Formation lap from lap 0 to lap 1 (80.0s)");
+    assert_eq!(periods[1].kind, NeutralisationKind::VirtualSafetyCar);
+    assert_eq!(periods[1].to_string(), "Virtual safety car from lap 7 (ongoing)");
+    assert!(tracker
+        .lap_neutralisations(9)
+        .contains(&NeutralisationKind::VirtualSafetyCar));
+}
@@ -0,0 +1,46 @@
+use f1_game_packet_parser::constants::driver_id::{driver_name, ESTEBAN_OCON};
+use f1_game_packet_parser::parse;
+
+// Offset of the first participant's name in the 2024 participants packet.
+const NAME_OFFSET: usize = 37;
+
+#[test]
+fn decodes_invalid_names_lossily() {
+    let mut data = include_bytes!("data/2024-04.bin").to_vec();
+
+    data[NAME_OFFSET + 1] = 0xC3;
+    data[NAME_OFFSET + 2] = b'(';
+
+    let participants = parse(&data).unwrap().participants.unwrap();
+
+    assert_eq!(participants.data[0].name, "O\u{FFFD}(N");
+    assert!(participants.data[0].name_lossy);
+    assert_eq!(participants.data[1].name, "SAINZ");
+    assert!(!participants.data[1].name_lossy);
+}
+
+#[test]
+fn resolves_display_names() {
+    let packet = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let mut participant = packet.participants.unwrap().data[0].clone();
+
+    assert_eq!(participant.display_name(2024), "OCON");
+
+    participant.name = "Driver 12".to_owned();
+    assert!(participant.has_placeholder_name());
+    assert_eq!(participant.driver_id, ESTEBAN_OCON);
+    assert_eq!(participant.display_name(2024), "Esteban Ocon");
+
+    participant.ai_controlled = false;
+    assert_eq!(participant.display_name(2024), "Driver 12");
+
+    participant.name.clear();
+    assert_eq!(
+        participant.display_name(2024),
+        format!("Driver {}", participant.race_number)
+    );
+
+    assert_eq!(driver_name(127, 2022), Some("Jake Hughes"));
+    assert_eq!(driver_name(127, 2024), Some("Callie Mayer"));
+    assert_eq!(driver_name(255, 2024), None);
+}
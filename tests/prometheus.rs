@@ -0,0 +1,122 @@
+#![cfg(feature = "prometheus")]
+
+use f1_game_packet_parser::parse;
+use f1_game_packet_parser::prometheus::PrometheusExporter;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+fn get(addr: SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut response = String::new();
+
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn player_gauges_and_packet_counters() {
+    let exporter = PrometheusExporter::new();
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+    let car_status = parse(include_bytes!("data/2024-07.bin")).unwrap();
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let car_damage = include_bytes!("data/2024-10.bin");
+
+    exporter.update(&telemetry);
+    exporter.update(&telemetry);
+    exporter.update(&car_status);
+    exporter.update(&laps);
+    exporter.update(&parse(car_damage).unwrap());
+    exporter.record_failure(&car_damage[..100]);
+    exporter.record_failure(&[0; 4]);
+
+    let metrics = exporter.render();
+    let player = &telemetry.car_telemetry.as_ref().unwrap().data[19];
+    let fuel_in_tank = car_status.car_status.as_ref().unwrap().data[19].fuel_in_tank;
+
+    assert!(metrics.contains(&format!("\nf1_speed_kmh {}\n", player.speed)));
+    assert!(metrics.contains(&format!("\nf1_fuel_in_tank_kg {}\n", fuel_in_tank)));
+    assert!(metrics.contains("\nf1_car_position 1\n"));
+    assert!(metrics.contains("\nf1_tyre_wear_percent{wheel=\"front_left\"} 0\n"));
+    assert!(
+        metrics.contains("\nf1_packets_received_total{packet_id=\"CarTelemetry\"} 2\n")
+    );
+    assert!(metrics.contains("\nf1_packets_failed_total{packet_id=\"CarDamage\"} 1\n"));
+    assert!(metrics.contains("\nf1_packets_failed_total{packet_id=\"Unknown\"} 1\n"));
+}
+
+#[test]
+fn serves_metrics_over_http() {
+    let exporter = PrometheusExporter::new();
+    let server = exporter.serve("127.0.0.1:0").unwrap();
+
+    exporter.update(&parse(include_bytes!("data/2024-06.bin")).unwrap());
+
+    let response = get(server.local_addr(), "/metrics");
+    let with_query = get(server.local_addr(), "/metrics?name%5B%5D=f1_speed_kmh");
+    let not_found = get(server.local_addr(), "/");
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with(&exporter.render()));
+    assert!(with_query.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(not_found.starts_with("HTTP/1.1 404 Not Found\r\n"));
+}
+
+#[test]
+fn idle_clients_dont_block_scrapes() {
+    let exporter = PrometheusExporter::new();
+    let server = exporter.serve("127.0.0.1:0").unwrap();
+    let _idle = TcpStream::connect(server.local_addr()).unwrap();
+    let start = Instant::now();
+
+    std::thread::sleep(Duration::from_millis(100));
+
+    let response = get(server.local_addr(), "/metrics");
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn limits_concurrent_scrapes() {
+    let exporter = PrometheusExporter::new();
+    let server = exporter.serve("127.0.0.1:0").unwrap();
+    let idle: Vec<TcpStream> =
+        (0..8).map(|_| TcpStream::connect(server.local_addr()).unwrap()).collect();
+
+    std::thread::sleep(Duration::from_millis(100));
+
+    let mut rejected = TcpStream::connect(server.local_addr()).unwrap();
+    let mut response = String::new();
+
+    // The connection gets closed, possibly before the request is written.
+    let _ = write!(rejected, "GET /metrics HTTP/1.1\r\n\r\n");
+    let _ = rejected.read_to_string(&mut response);
+
+    assert_eq!(response, "");
+
+    drop(idle);
+    std::thread::sleep(Duration::from_millis(100));
+
+    let response = get(server.local_addr(), "/metrics");
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+}
+
+#[test]
+fn rejects_oversized_requests() {
+    let exporter = PrometheusExporter::new();
+    let server = exporter.serve("127.0.0.1:0").unwrap();
+    let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+    let mut response = String::new();
+
+    // Exactly as long as the limit, so the server reads all of it before replying.
+    let request = format!("GET /metrics?{}", "a".repeat(8 * 1024 - 13));
+
+    stream.write_all(request.as_bytes()).unwrap();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large\r\n"));
+    assert!(get(server.local_addr(), "/metrics").starts_with("HTTP/1.1 200 OK\r\n"));
+}
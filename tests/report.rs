@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::SafetyCarStatus;
+use f1_game_packet_parser::neutralisations::NeutralisationKind;
+use f1_game_packet_parser::report::RaceReportBuilder;
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet;
+
+fn session(safety_car_status: SafetyCarStatus, session_time: f32) -> F1Packet {
+    common::session(include_bytes!("data/2024-01.bin"), safety_car_status, session_time)
+}
+
+fn laps(lap_num: u8, swap: bool) -> F1Packet {
+    let mut packet = common::laps(include_bytes!("data/2024-02.bin"), lap_num);
+
+    for (index, lap_data) in packet.laps.as_mut().unwrap().data.iter_mut().enumerate() {
+        lap_data.car_position = index as u8 + 1;
+    }
+
+    if swap {
+        let data = &mut packet.laps.as_mut().unwrap().data;
+
+        data[0].car_position = 2;
+        data[1].car_position = 1;
+    }
+
+    packet
+}
+
+fn race() -> RaceReportBuilder {
+    let mut builder = RaceReportBuilder::new();
+
+    builder.update(&session(SafetyCarStatus::None, 0.0));
+    builder.update(&packet(include_bytes!("data/2024-04.bin")));
+    builder.update(&laps(1, false));
+    builder.update(&session(SafetyCarStatus::Full, 10.0));
+    builder.update(&laps(2, false));
+    builder.update(&session(SafetyCarStatus::None, 40.5));
+    builder.update(&laps(3, true));
+    builder.update(&packet(include_bytes!("data/2024-03-OVTK.bin")));
+    builder.update(&packet(include_bytes!("data/2024-03-PENA.bin")));
+    builder.update(&packet(include_bytes!("data/2024-03-RDFL.bin")));
+    builder.update(&packet(include_bytes!("data/2024-08.bin")));
+    builder
+}
+
+#[test]
+fn builds_race_report() {
+    let report = race().build();
+
+    assert_eq!(report.results.len(), 20);
+    assert_eq!(report.results[0].name, "SAINZ");
+    assert!(!report.results[0].stints.is_empty());
+    assert_eq!(
+        report.results[0].stints.last().unwrap().end_lap,
+        report.results[0].num_laps
+    );
+    assert_eq!(report.lap_chart[0].name, "SAINZ");
+    assert_eq!(report.lap_chart[0].positions[1..], [2, 1]);
+    assert_eq!(report.overtakes.len(), 1);
+    assert_eq!(report.neutralisations.len(), 2);
+    assert_eq!(report.neutralisations[0].kind, NeutralisationKind::SafetyCar);
+    assert_eq!(report.neutralisations[0].start_lap, 1);
+    assert_eq!(report.neutralisations[0].end_lap, Some(2));
+    assert_eq!(report.neutralisations[0].end_time, Some(40.5));
+    assert_eq!(report.neutralisations[1].kind, NeutralisationKind::RedFlag);
+    assert_eq!(report.neutralisations[1].start_lap, 3);
+    assert!(report.fastest_lap.is_some());
+}
+
+#[test]
+fn renders_markdown_and_html() {
+    let mut participants = packet(include_bytes!("data/2024-04.bin"));
+
+    participants.participants.as_mut().unwrap().data[1].name = "<Sainz | 55>".to_owned();
+
+    let mut builder = race().with_title("Round 1");
+
+    builder.update(&participants);
+
+    let report = builder.build();
+    let markdown = report.to_markdown();
+    let html = report.to_html();
+
+    assert!(markdown.starts_with("# Round 1\n\n## Results\n"));
+    assert!(markdown.contains("| 1 | &lt;Sainz \\| 55&gt; | 2 |"));
+    assert!(markdown.contains("## Lap chart"));
+    assert!(markdown.contains("## Tyre stints"));
+    assert!(markdown.contains("| Safety car | 1-2 | 30.5s |"));
+    assert!(markdown.contains("| Red flag | 3- | - |"));
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<td>&lt;Sainz | 55&gt;</td>"));
+    assert!(html.contains("class=\"stint "));
+    assert!(html.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn markdown_escapes_hostile_names() {
+    let mut participants = packet(include_bytes!("data/2024-04.bin"));
+
+    participants.participants.as_mut().unwrap().data[1].name =
+        "<img src=x onerror=alert(1)> **[x](y)** `#_\\\r\n| 9 |".to_owned();
+
+    let mut builder = race();
+
+    builder.update(&participants);
+
+    let markdown = builder.build().to_markdown();
+    let row = markdown.lines().find(|line| line.starts_with("| 1 | ")).unwrap();
+
+    assert_eq!(
+        row.split(" | ").nth(1).unwrap(),
+        "&lt;img src=x onerror=alert(1)&gt; \\*\\*\\[x\\](y)\\*\\* \\`\\#\\_\\\\  \\| 9 \\|"
+    );
+    assert!(!markdown.contains("<img"));
+}
+
+#[test]
+fn lap_chart_includes_final_lap() {
+    let mut builder = race();
+    let mut classification = packet(include_bytes!("data/2024-08.bin"));
+
+    // Lap data for the final lap is never sent, since the car's lap number
+    // stays at 3 once it takes the chequered flag.
+    classification.final_classification.as_mut().unwrap().data[1].num_laps = 3;
+    builder.update(&classification);
+
+    let report = builder.build();
+
+    assert_eq!(report.lap_chart[0].name, "SAINZ");
+    assert_eq!(report.lap_chart[0].positions[1..], [2, 1, 1]);
+}
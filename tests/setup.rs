@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::parse;
+use f1_game_packet_parser::setup::{diff, SetupField, SetupTracker};
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet_at;
+
+fn car_setups(session_time: f32) -> F1Packet {
+    packet_at(include_bytes!("data/2024-05.bin"), session_time)
+}
+
+#[test]
+fn diffs_setups_field_by_field() {
+    let packet = car_setups(0.0);
+    let from = packet.car_setups.unwrap().data[19];
+    let mut to = from;
+
+    assert!(diff(&from, &to).is_empty());
+
+    to.front_wing = from.front_wing + 2;
+    to.brake_bias = from.brake_bias - 1;
+
+    let changes = diff(&from, &to);
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].field, SetupField::FrontWing);
+    assert_eq!(
+        changes[0].to_string(),
+        format!("Front wing: {} -> {} (+2)", from.front_wing, to.front_wing)
+    );
+    assert_eq!(
+        changes[1].to_string(),
+        format!("Brake bias: {}% -> {}% (-1)", from.brake_bias, to.brake_bias)
+    );
+}
+
+#[test]
+fn detects_setup_changes_during_session() {
+    let mut tracker = SetupTracker::new();
+    let mut packet = car_setups(10.0);
+
+    tracker.update(&parse(include_bytes!("data/2024-01.bin")).unwrap());
+    assert_eq!(tracker.update(&packet), None);
+
+    let car_setups = packet.car_setups.as_mut().unwrap();
+    let front_wing = car_setups.data[19].front_wing;
+
+    car_setups.next_front_wing_value = f32::from(front_wing + 3);
+    packet.header.session_time = 20.0;
+
+    let event = tracker.update(&packet).unwrap();
+
+    assert_eq!(event.session_time, 20.0);
+    assert_eq!(event.changes.len(), 1);
+    assert_eq!(event.changes[0].field, SetupField::NextFrontWing);
+    assert_eq!(event.changes[0].to, f32::from(front_wing + 3));
+    assert_eq!(tracker.pending_front_wing(), Some(f32::from(front_wing + 3)));
+
+    let saved = tracker.snapshot("Race").unwrap();
+    let session = parse(include_bytes!("data/2024-01.bin")).unwrap().session.unwrap();
+
+    assert_eq!(saved.name, "Race");
+    assert_eq!(saved.track_id, Some(session.track_id));
+    assert_eq!(saved.weather, Some(session.weather));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn saves_and_loads_setups() {
+    use f1_game_packet_parser::setup::SavedSetup;
+
+    let mut tracker = SetupTracker::new();
+
+    tracker.update(&parse(include_bytes!("data/2024-01.bin")).unwrap());
+    tracker.update(&car_setups(0.0));
+
+    let saved = tracker.snapshot("Qualifying").unwrap();
+    let path = std::env::temp_dir().join("f1-game-packet-parser-setup-test.toml");
+
+    saved.save(&path).unwrap();
+
+    let loaded = SavedSetup::load(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, saved);
+    assert!(loaded.diff(&saved).is_empty());
+}
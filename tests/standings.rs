@@ -0,0 +1,180 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::team_id;
+use f1_game_packet_parser::packets::{F1PacketFinalClassification, F1PacketParticipants};
+use f1_game_packet_parser::parse;
+use f1_game_packet_parser::standings::{PointsSystem, Standings};
+use f1_game_packet_parser::weekend::SessionKind;
+
+fn session() -> (F1PacketParticipants, F1PacketFinalClassification) {
+    let participants = parse(include_bytes!("data/2024-04.bin")).unwrap();
+    let classification = parse(include_bytes!("data/2024-08.bin")).unwrap();
+
+    (participants.participants.unwrap(), classification.final_classification.unwrap())
+}
+
+#[test]
+fn applies_points_systems() {
+    let (participants, classification) = session();
+    let fastest = classification
+        .data
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, data)| data.best_lap_time_ms)
+        .map(|(index, _)| index)
+        .unwrap();
+    let mut standings = Standings::new(PointsSystem::f1_2024());
+
+    let scores =
+        standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    assert_eq!(scores.len(), 20);
+    assert_eq!(scores[0].name, "SAINZ");
+    assert_eq!(scores[0].points, 25 + u32::from(scores[0].fastest_lap));
+    assert_eq!(
+        scores.iter().filter(|score| score.fastest_lap).count(),
+        usize::from(classification.data[fastest].position <= 10)
+    );
+
+    standings.add_session(SessionKind::Sprint, 2024, &participants, &classification);
+    standings.add_session(SessionKind::Qualifying, 2024, &participants, &classification);
+
+    let drivers = standings.drivers();
+
+    assert_eq!(drivers[0].name, "SAINZ");
+    assert_eq!(drivers[0].sprint_points, 8);
+    assert_eq!(drivers[0].starts, 1);
+    assert_eq!(drivers[0].wins, 1);
+    assert_eq!(drivers[1].name, "PIASTRI");
+
+    let mut standings = Standings::new(PointsSystem::game());
+
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    let teams = standings.teams();
+    let ferrari = teams.iter().find(|team| team.team_id == team_id::FERRARI).unwrap();
+
+    // Sainz won with 26 points, Leclerc finished 5th with 10.
+    assert_eq!(standings.drivers()[0].points, 26);
+    assert_eq!(ferrari.points, 36);
+}
+
+#[test]
+fn breaks_ties_by_count_back() {
+    let (participants, mut classification) = session();
+    let system = PointsSystem::f1_2024()
+        .with_race_points([10, 6, 2])
+        .with_sprint_points([])
+        .with_fastest_lap(0, 10);
+    let mut standings = Standings::new(system);
+
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    // Hamilton wins after finishing 3rd, Sainz drops to 3rd, Piastri stays 2nd.
+    classification.data[15].position = 1;
+    classification.data[1].position = 3;
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    let drivers = standings.drivers();
+    let names: Vec<&str> =
+        drivers[..3].iter().map(|driver| driver.name.as_str()).collect();
+
+    assert!(drivers[..3].iter().all(|driver| driver.points == 12));
+    assert_eq!(names, ["HAMILTON", "SAINZ", "PIASTRI"]);
+    assert_eq!(drivers[1].finishes, [1, 0, 1]);
+    assert_eq!(drivers[2].finishes, [0, 2]);
+}
+
+#[test]
+fn keys_drivers_by_id() {
+    let (mut participants, classification) = session();
+    let mut standings = Standings::new(PointsSystem::game());
+
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+    participants.data[1].name = "Carlos".to_owned();
+    participants.data[2].name = "Carlos".to_owned();
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    let drivers = standings.drivers();
+
+    // Renamed drivers keep their points, drivers sharing a name don't merge.
+    assert_eq!(drivers.len(), 20);
+    assert_eq!(drivers[0].name, "Carlos");
+    assert_eq!(drivers[0].points, 52);
+    assert_eq!(drivers.iter().filter(|driver| driver.name == "Carlos").count(), 2);
+}
+
+#[test]
+fn keys_humans_by_online_name() {
+    let (mut participants, classification) = session();
+    let mut standings = Standings::new(PointsSystem::game());
+
+    for (index, name) in [(1, "Carlos"), (2, "Lando")] {
+        let participant = &mut participants.data[index];
+
+        participant.ai_controlled = false;
+        participant.driver_id = 255;
+        participant.network_id = index as u8;
+        participant.name = name.to_owned();
+    }
+
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    // Both humans join the next race in each other's lobby slot.
+    participants.data[1].network_id = 2;
+    participants.data[2].network_id = 1;
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+
+    let drivers = standings.drivers();
+    let lando = drivers.iter().find(|driver| driver.name == "Lando").unwrap();
+
+    assert_eq!(drivers.len(), 20);
+    assert_eq!(drivers[0].name, "Carlos");
+    assert_eq!(drivers[0].points, 52);
+    assert_eq!(drivers[0].starts, 2);
+    assert_eq!(lando.points, 2 * u32::from(classification.data[2].points));
+    assert_eq!(lando.starts, 2);
+}
+
+#[test]
+fn game_points_credit_fastest_lap() {
+    let (participants, mut classification) = session();
+    let mut standings = Standings::new(PointsSystem::game().with_fastest_lap(0, 1));
+
+    // Zhou finished 20th with the fastest lap.
+    classification.data[7].best_lap_time_ms = 1;
+
+    let scores =
+        standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+    let fastest: Vec<_> = scores.iter().filter(|score| score.fastest_lap).collect();
+    let zhou = standings.drivers().into_iter().find(|driver| driver.name == "ZHOU");
+
+    assert_eq!(fastest.len(), 1);
+    assert_eq!(fastest[0].position, classification.data[7].position);
+    assert_eq!(zhou.unwrap().fastest_laps, 1);
+}
+
+#[test]
+fn exports_csv() {
+    let (mut participants, classification) = session();
+
+    participants.data[1].name = "Sainz, Carlos".to_owned();
+
+    let mut standings = Standings::new(PointsSystem::game());
+    let mut csv = Vec::new();
+
+    standings.add_session(SessionKind::Race, 2024, &participants, &classification);
+    standings.write_drivers_csv(&mut csv).unwrap();
+
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+
+    assert_eq!(
+        lines.next(),
+        Some(
+            "position,name,team_id,points,sprint_points,starts,wins,podiums,fastest_laps"
+        )
+    );
+    assert_eq!(lines.next(), Some("1,\"Sainz, Carlos\",1,26,0,1,1,1,1"));
+    assert_eq!(lines.count(), 19);
+}
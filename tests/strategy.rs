@@ -0,0 +1,167 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::VisualTyreCompound;
+use f1_game_packet_parser::strategy::TyreStrategy;
+
+mod common;
+
+use common::packet;
+
+fn strategy_with_soft_wear(
+    wear_per_lap: f32,
+    total_laps: u8,
+    window: (u8, u8),
+) -> TyreStrategy {
+    let mut session = packet(include_bytes!("data/2024-01.bin"));
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut car_damage = packet(include_bytes!("data/2024-10.bin"));
+    let tyre_sets = packet(include_bytes!("data/2024-12.bin"));
+    let mut strategy = TyreStrategy::new();
+
+    let session_data = session.session.as_mut().unwrap();
+    session_data.total_laps = total_laps;
+    session_data.pit_stop_window_ideal_lap = window.0;
+    session_data.pit_stop_window_latest_lap = window.1;
+
+    strategy.update(&session);
+    strategy.update(&car_status);
+    strategy.update(&tyre_sets);
+    strategy.update(&car_damage);
+
+    // The model gets attached during lap 1, so sampling starts with lap 2.
+    laps.laps.as_mut().unwrap().data[1].current_lap_num = 1;
+    strategy.update(&laps);
+    laps.laps.as_mut().unwrap().data[1].current_lap_num = 2;
+    strategy.update(&laps);
+
+    car_damage.car_damage.as_mut().unwrap().data[1].tyres_wear =
+        [wear_per_lap - 1.0, wear_per_lap - 1.0, wear_per_lap, wear_per_lap - 0.5];
+    laps.laps.as_mut().unwrap().data[1].current_lap_num = 3;
+
+    strategy.update(&car_damage);
+    strategy.update(&laps);
+    strategy
+}
+
+#[test]
+fn degradation_and_projection() {
+    let strategy = strategy_with_soft_wear(3.0, 5, (0, 0));
+    let rates = strategy.degradation_rates();
+    let fitted = strategy.projections(1)[6];
+
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].visual_tyre_compound, VisualTyreCompound::F1Soft);
+    assert_eq!(rates[0].laps_sampled, 1);
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Soft), Some(3.0));
+    assert_eq!(fitted.game_laps_left, 18);
+    assert!((fitted.projected_laps_left.unwrap() - 70.0 / 3.0).abs() < 1e-3);
+    assert_eq!(strategy.projections(2), vec![]);
+}
+
+#[test]
+fn skips_partial_laps_after_compound_changes() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let mut car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut car_damage = packet(include_bytes!("data/2024-10.bin"));
+    let mut strategy = TyreStrategy::new();
+
+    let mut lap = |strategy: &mut TyreStrategy, lap_num: u8, wear: f32| {
+        car_damage.car_damage.as_mut().unwrap().data[1].tyres_wear = [wear; 4];
+        laps.laps.as_mut().unwrap().data[1].current_lap_num = lap_num;
+        strategy.update(&car_damage);
+        strategy.update(&laps);
+    };
+
+    strategy.update(&car_status);
+    lap(&mut strategy, 1, 0.0);
+    lap(&mut strategy, 2, 3.0);
+
+    // Mediums get fitted halfway through lap 2 without a pit stop being counted.
+    car_status.car_status.as_mut().unwrap().data[1].visual_tyre_compound =
+        VisualTyreCompound::F1Medium;
+    strategy.update(&car_status);
+    lap(&mut strategy, 2, 0.0);
+    lap(&mut strategy, 3, 1.0);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Medium), None);
+
+    lap(&mut strategy, 4, 3.0);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Medium), Some(2.0));
+}
+
+#[test]
+fn skips_laps_whose_start_was_not_seen() {
+    let mut laps = packet(include_bytes!("data/2024-02.bin"));
+    let car_status = packet(include_bytes!("data/2024-07.bin"));
+    let mut car_damage = packet(include_bytes!("data/2024-10.bin"));
+    let mut strategy = TyreStrategy::new();
+
+    let mut lap = |strategy: &mut TyreStrategy, lap_num: u8, wear: f32| {
+        car_damage.car_damage.as_mut().unwrap().data[1].tyres_wear = [wear; 4];
+        laps.laps.as_mut().unwrap().data[1].current_lap_num = lap_num;
+        strategy.update(&car_damage);
+        strategy.update(&laps);
+    };
+
+    // Attached halfway through lap 5.
+    lap(&mut strategy, 5, 10.0);
+    strategy.update(&car_status);
+    lap(&mut strategy, 6, 11.0);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Soft), None);
+
+    lap(&mut strategy, 7, 13.0);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Soft), Some(2.0));
+
+    // A flashback to the middle of lap 6.
+    lap(&mut strategy, 6, 12.0);
+    lap(&mut strategy, 7, 12.5);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Soft), Some(2.0));
+
+    lap(&mut strategy, 8, 14.5);
+
+    assert_eq!(strategy.degradation_rate(VisualTyreCompound::F1Soft), Some(2.0));
+    assert_eq!(strategy.degradation_rates()[0].laps_sampled, 2);
+}
+
+#[test]
+fn plans_cover_the_race_distance() {
+    let strategy = strategy_with_soft_wear(3.0, 30, (0, 0));
+    let plans = strategy.suggest_plans(1);
+
+    assert!(!plans.is_empty());
+    assert!(plans
+        .windows(2)
+        .all(|w| w[0].estimated_time_loss <= w[1].estimated_time_loss));
+
+    for plan in &plans {
+        assert_eq!(plan.stints.len(), plan.num_pit_stops + 1);
+        assert_eq!(plan.stints[0].set_index, 6);
+        assert_eq!(plan.stints[0].start_lap, 3);
+        assert_eq!(plan.stints.last().unwrap().end_lap, 30);
+        assert!(plan
+            .stints
+            .iter()
+            .any(|stint| stint.visual_tyre_compound != VisualTyreCompound::F1Soft));
+    }
+}
+
+#[test]
+fn first_stop_respects_pit_window() {
+    let strategy = strategy_with_soft_wear(3.0, 30, (10, 12));
+    let plans = strategy.suggest_plans(1);
+
+    assert!(!plans.is_empty());
+    assert!(plans.iter().all(|plan| (10..=12).contains(&plan.stints[0].end_lap)));
+}
+
+#[test]
+fn no_plan_when_tyres_cannot_last() {
+    let strategy = strategy_with_soft_wear(30.0, 30, (25, 28));
+
+    assert_eq!(strategy.suggest_plans(1), vec![]);
+}
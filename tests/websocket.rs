@@ -0,0 +1,147 @@
+#![cfg(feature = "websocket")]
+
+use f1_game_packet_parser::parse;
+use f1_game_packet_parser::websocket::WebSocketServer;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+use tungstenite::{Message, WebSocket};
+
+fn connect(server: &WebSocketServer, query: &str) -> WebSocket<TcpStream> {
+    let addr = server.local_addr().unwrap();
+    let clients = server.client_count();
+    let stream = TcpStream::connect(addr).unwrap();
+    let (socket, _) =
+        tungstenite::client(format!("ws://{}/?{}", addr, query), stream).unwrap();
+
+    while server.client_count() == clients {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    socket.get_ref().set_read_timeout(Some(Duration::from_millis(300))).unwrap();
+    socket
+}
+
+fn read_json(socket: &mut WebSocket<TcpStream>) -> Option<serde_json::Value> {
+    match socket.read() {
+        Ok(Message::Text(text)) => Some(serde_json::from_str(&text).unwrap()),
+        _ => None,
+    }
+}
+
+#[test]
+fn raw_packets_with_subscriptions_and_throttling() {
+    let server =
+        WebSocketServer::new().with_max_rate("motion", 1.0).bind("127.0.0.1:0").unwrap();
+    let mut client = connect(&server, "packets=motion,car_telemetry");
+    let motion = parse(include_bytes!("data/2024-00.bin")).unwrap();
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+
+    server.broadcast(&motion);
+    server.broadcast(&laps);
+    server.broadcast(&motion);
+    server.broadcast(&telemetry);
+
+    let first = read_json(&mut client).unwrap();
+    let second = read_json(&mut client).unwrap();
+    let speed = telemetry.car_telemetry.as_ref().unwrap().data[19].speed;
+
+    assert_eq!(first["type"], "motion");
+    assert_eq!(first["header"]["packet_format"], 2024);
+    assert_eq!(second["type"], "car_telemetry");
+    assert_eq!(second["data"]["data"][19]["speed"], speed);
+    assert_eq!(read_json(&mut client), None);
+}
+
+#[test]
+fn compact_state_and_runtime_subscription() {
+    let server = WebSocketServer::new().bind("127.0.0.1:0").unwrap();
+    let mut client = connect(&server, "mode=compact&packets=car_status");
+    let car_status = parse(include_bytes!("data/2024-07.bin")).unwrap();
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+
+    server.broadcast(&laps);
+    server.broadcast(&car_status);
+
+    let state = read_json(&mut client).unwrap();
+
+    assert_eq!(state["type"], "state");
+    assert_eq!(state["state"]["player_car_index"], 19);
+    assert_eq!(state["state"]["car_position"], 1);
+    assert_eq!(state["state"]["visual_tyre_compound"], "F1Soft");
+
+    client.send(Message::text(r#"{"packets": ["laps"]}"#)).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    server.broadcast(&car_status);
+    server.broadcast(&laps);
+
+    let raw = read_json(&mut client).unwrap();
+
+    assert_eq!(raw["type"], "laps");
+    assert_eq!(read_json(&mut client), None);
+
+    client.close(None).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    server.broadcast(&laps);
+
+    assert_eq!(server.client_count(), 0);
+}
+
+#[test]
+fn slow_clients_dont_block_or_grow_buffers() {
+    let server = WebSocketServer::new()
+        .with_max_write_buffer(64 * 1024)
+        .bind("127.0.0.1:0")
+        .unwrap();
+
+    // A connection that never completes its handshake mustn't hold up other clients.
+    let _idle = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    let _stalled = connect(&server, "packets=motion");
+    let motion = parse(include_bytes!("data/2024-00.bin")).unwrap();
+    let mut broadcasts = 0;
+
+    // The stalled client never reads, so its buffer eventually fills up.
+    while server.client_count() > 0 && broadcasts < 10_000 {
+        server.broadcast(&motion);
+        broadcasts += 1;
+    }
+
+    assert_eq!(server.client_count(), 0);
+}
+
+#[test]
+fn percent_encoded_queries() {
+    let server = WebSocketServer::new().bind("127.0.0.1:0").unwrap();
+    let mut client = connect(&server, "packets=laps%2Ccar_telemetry&rate%2Elaps=1");
+    let laps = parse(include_bytes!("data/2024-02.bin")).unwrap();
+    let telemetry = parse(include_bytes!("data/2024-06.bin")).unwrap();
+
+    server.broadcast(&laps);
+    server.broadcast(&laps);
+    server.broadcast(&telemetry);
+
+    assert_eq!(read_json(&mut client).unwrap()["type"], "laps");
+    assert_eq!(read_json(&mut client).unwrap()["type"], "car_telemetry");
+    assert_eq!(read_json(&mut client), None);
+}
+
+#[test]
+fn limits_pending_handshakes() {
+    let server = WebSocketServer::new().bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    let idle: Vec<TcpStream> =
+        (0..8).map(|_| TcpStream::connect(addr).unwrap()).collect();
+
+    thread::sleep(Duration::from_millis(100));
+
+    let stream = TcpStream::connect(addr).unwrap();
+
+    assert!(tungstenite::client(format!("ws://{}/", addr), stream).is_err());
+
+    drop(idle);
+    thread::sleep(Duration::from_millis(100));
+    connect(&server, "");
+
+    assert_eq!(server.client_count(), 1);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "std")]
+
+use f1_game_packet_parser::constants::session_type;
+use f1_game_packet_parser::weekend::{SessionKind, WeekendTracker};
+use f1_game_packet_parser::F1Packet;
+
+mod common;
+
+use common::packet;
+
+const WEEKEND: u32 = 7;
+
+fn session(session_uid: u64, session_link: u32, session_type: u8) -> F1Packet {
+    let mut packet = packet(include_bytes!("data/2024-01.bin"));
+    let session = packet.session.as_mut().unwrap();
+
+    packet.header.session_uid = session_uid;
+    session.session_type = session_type;
+    session.weekend_link_identifier = WEEKEND;
+    session.session_link_identifier = session_link;
+    session.weekend_structure = vec![
+        session_type::PRACTICE_1,
+        session_type::SPRINT_1,
+        session_type::RACE_2024,
+        session_type::QUALIFYING_1,
+        session_type::RACE_2_2024,
+    ];
+    session.num_sessions_in_weekend = session.weekend_structure.len();
+    packet
+}
+
+fn classify(tracker: &mut WeekendTracker, session_uid: u64) -> Option<u32> {
+    let mut participants = packet(include_bytes!("data/2024-04.bin"));
+    let mut final_classification = packet(include_bytes!("data/2024-08.bin"));
+
+    participants.header.session_uid = session_uid;
+    final_classification.header.session_uid = session_uid;
+    tracker.update(&participants);
+    tracker.update(&final_classification)
+}
+
+#[test]
+fn summarises_sprint_weekend() {
+    let mut tracker = WeekendTracker::new();
+
+    for (session_uid, session_type) in [
+        (1, session_type::SPRINT_1),
+        (2, session_type::RACE_2024),
+        (3, session_type::QUALIFYING_1),
+    ] {
+        tracker.update(&session(session_uid, session_uid as u32, session_type));
+        assert_eq!(classify(&mut tracker, session_uid), Some(WEEKEND));
+    }
+
+    tracker.update(&session(4, 4, session_type::RACE_2_2024));
+
+    let weekend = tracker.weekend(WEEKEND).unwrap();
+    let kinds: Vec<SessionKind> =
+        weekend.sessions().iter().map(|session| weekend.session_kind(session)).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            SessionKind::SprintQualifying,
+            SessionKind::Sprint,
+            SessionKind::Qualifying,
+            SessionKind::Race
+        ]
+    );
+    assert!(!weekend.is_complete());
+
+    let summary = weekend.summary();
+
+    assert_eq!(summary.sprint_grid[0].name, "SAINZ");
+    assert_eq!(summary.grid.len(), 20);
+    assert_eq!(summary.grid[0].name, "SAINZ");
+    assert_eq!(summary.grid[1].name, "PIASTRI");
+    assert_eq!(summary.sprint_results[0].name, "SAINZ");
+    assert!(summary.race_results.is_empty());
+    assert_eq!(summary.points[0].name, "SAINZ");
+    assert_eq!(summary.points[0].sprint_points, 26);
+    assert_eq!(summary.points[0].total(), 26);
+
+    classify(&mut tracker, 4);
+
+    let summary = tracker.weekend(WEEKEND).unwrap().summary();
+
+    assert_eq!(summary.race_results[0].name, "SAINZ");
+    assert_eq!(summary.points[0].total(), 52);
+    assert_eq!(summary.points[1].name, "PIASTRI");
+}
+
+#[test]
+fn restarted_session_replaces_previous_attempt() {
+    let mut tracker = WeekendTracker::new();
+
+    tracker.update(&session(1, 1, session_type::QUALIFYING_1));
+    classify(&mut tracker, 1);
+    tracker.update(&session(2, 1, session_type::QUALIFYING_1));
+
+    let weekend = tracker.weekend(WEEKEND).unwrap();
+
+    assert_eq!(weekend.sessions().len(), 1);
+    assert_eq!(weekend.sessions()[0].session_uid, 2);
+    assert_eq!(weekend.sessions()[0].results, None);
+    assert!(weekend.summary().grid.is_empty());
+
+    // Packets of the previous attempt are ignored.
+    assert_eq!(classify(&mut tracker, 1), None);
+    assert_eq!(classify(&mut tracker, 2), Some(WEEKEND));
+}
+
+#[test]
+fn points_are_keyed_by_driver_not_name() {
+    let mut tracker = WeekendTracker::new();
+    let mut participants = packet(include_bytes!("data/2024-04.bin"));
+    let mut final_classification = packet(include_bytes!("data/2024-08.bin"));
+
+    tracker.update(&session(1, 1, session_type::RACE_2024));
+
+    // Two drivers with the same name.
+    for participant in &mut participants.participants.as_mut().unwrap().data[..2] {
+        participant.name = "Alex".to_owned();
+    }
+
+    participants.header.session_uid = 1;
+    final_classification.header.session_uid = 1;
+    tracker.update(&participants);
+    tracker.update(&final_classification);
+
+    let summary = tracker.weekend(WEEKEND).unwrap().summary();
+    let named_alex = summary.points.iter().filter(|points| points.name == "Alex");
+
+    assert_eq!(summary.points.len(), 20);
+    assert_eq!(named_alex.count(), 2);
+}